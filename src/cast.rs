@@ -1,50 +1,178 @@
 use crate::{BigFloat, BigFloatCtx};
-use astro_float::Sign;
+use astro_float::{Exponent, RoundingMode, Sign, Word};
+use core::cmp::Ordering;
+use core::fmt::{self, Display, Formatter};
+
+pub(crate) const WORD_BITS: i64 = Word::BITS as i64;
 
 impl<CTX: BigFloatCtx> BigFloat<CTX> {
-    /// Returns the closest [`f64`] to this BigFloat.
+    /// Returns the closest [`f64`] to this BigFloat, rounding ties to even.
     ///
     /// If self is `NaN`, returns [`f64::NAN`]
     ///
     /// If self is `Inf`, returns [`f64::INFINITY`] or [`f64::NEG_INFINITY`]
     ///
-    /// Otherwise, returns the closest `f64` value to this BigFloat.
-    ///
     /// **NOTE:** If the value is above [`f64::MAX`], this function will overflow into `f64::INFINITY`.
     ///
-    /// **NOTE:** This is function is defined within this package because `astro_float`'s
-    /// implementation is private, so it is not an officially supported function.'
+    /// This is equivalent to `self.to_f64_round(RoundingMode::ToEven)`, see
+    /// [`BigFloat::to_f64_round`].
+    #[inline]
     pub fn as_f64(&self) -> f64 {
+        self.to_f64_round(RoundingMode::ToEven)
+    }
+    /// Returns this BigFloat as an [`f64`], correctly rounded with the given [`RoundingMode`].
+    ///
+    /// `RoundingMode::ToEven` and `RoundingMode::ToOdd` round to the nearest `f64`, breaking ties
+    /// to an even or odd mantissa respectively. `Up`, `Down`, `ToZero` and `FromZero` are directed
+    /// roundings, and `None` truncates like `ToZero`.
+    ///
+    /// Values below the normal range are rounded to subnormals, zero keeps its sign, and values
+    /// above the range of `f64` become infinite, unless the rounding direction is towards zero,
+    /// in which case they become [`f64::MAX`] (or `-f64::MAX`).
+    ///
+    /// ## Example
+    /// ```rust
+    /// use astro_nalgebra::{BigFloat, ConstCtx, RoundingMode};
+    ///
+    /// let third: BigFloat<ConstCtx<256>> = "0.33333333333333333333333333333".parse().unwrap();
+    /// let down = third.to_f64_round(RoundingMode::Down);
+    /// let up = third.to_f64_round(RoundingMode::Up);
+    /// assert_eq!(up, down.next_up());
+    /// assert_eq!(third.to_f64_round(RoundingMode::ToEven), 1.0 / 3.0);
+    /// ```
+    pub fn to_f64_round(&self, rm: RoundingMode) -> f64 {
+        f64::from_bits(self.to_ieee_bits(52, 11, rm))
+    }
+    /// Returns this BigFloat as an [`f32`], correctly rounded with the given [`RoundingMode`].
+    ///
+    /// The value is rounded only once, so it does not suffer from the double rounding of
+    /// converting to `f64` first. See [`BigFloat::to_f64_round`] for the handling of rounding
+    /// modes, subnormals and overflow.
+    pub fn to_f32_round(&self, rm: RoundingMode) -> f32 {
+        f32::from_bits(self.to_ieee_bits(23, 8, rm) as u32)
+    }
+    /// Returns the exact value of an [`f64`], without rounding it to the context.
+    ///
+    /// The result has the precision of the context or 64 bits, whichever is larger, so every
+    /// finite `f64` is represented exactly, including subnormals and negative zero, and
+    /// [`BigFloat::as_f64`] gives back the same `f64`. `NaN` and the infinities are kept.
+    /// Arithmetic on the result rounds to the context as usual.
+    ///
+    /// This is also the [`From<f64>`] implementation. [`FromPrimitive::from_f64`] and the
+    /// [`SupersetOf<f64>`](simba::scalar::SupersetOf) conversion used by nalgebra round the value
+    /// to the context instead.
+    ///
+    /// [`FromPrimitive::from_f64`]: num_traits::FromPrimitive::from_f64
+    ///
+    /// ## Example
+    /// ```rust
+    /// use astro_nalgebra::{BigFloat, ConstCtx};
+    ///
+    /// type BF16 = BigFloat<ConstCtx<16>>;
+    ///
+    /// assert_eq!(BF16::from_f64_exact(0.1).as_f64(), 0.1);
+    /// assert_eq!(BF16::from(5e-324).as_f64(), 5e-324);
+    /// assert!(BF16::from(-0.0).as_f64().is_sign_negative());
+    /// ```
+    pub fn from_f64_exact(val: f64) -> Self {
+        let mut num = exact_f64(val);
+        // Increasing the precision is exact
+        if let Err(err) = num.set_precision(CTX::get_prec().max(64), RoundingMode::None) {
+            num = astro_float::BigFloat::nan(Some(err));
+        }
+        BigFloat::from(num)
+    }
+    /// Returns the exact value of an [`f32`], without rounding it to the context.
+    ///
+    /// See [`BigFloat::from_f64_exact`].
+    #[inline]
+    pub fn from_f32_exact(val: f32) -> Self {
+        Self::from_f64_exact(val as f64)
+    }
+    /// Rounds this BigFloat to the bit pattern of an IEEE 754 binary float with `mant_bits`
+    /// explicit mantissa bits and `exp_bits` exponent bits.
+    fn to_ieee_bits(&self, mant_bits: u32, exp_bits: u32, rm: RoundingMode) -> u64 {
+        let sign_bit = 1u64 << (mant_bits + exp_bits);
+        let inf_bits = ((1u64 << exp_bits) - 1) << mant_bits;
         if self.num.is_nan() {
-            return f64::NAN;
+            return inf_bits | (1 << (mant_bits - 1));
         }
+        let sign = if self.num.is_negative() { sign_bit } else { 0 };
         if self.num.is_inf() {
-            return if self.num.is_inf_pos() {
-                f64::INFINITY
-            } else {
-                f64::NEG_INFINITY
-            };
+            return sign | inf_bits;
         }
         // We can safely unwrap here because it always succeeds when it is not nan or inf
-        let raw_parts = self.num.as_raw_parts().unwrap();
-        let exp = raw_parts.3;
-        let mantissa = raw_parts.0;
-        match mantissa.last() {
-            Some(val) => {
-                let mut val = (*val as f64) / (u64::MAX as f64 + 1.) * 2.0;
-                val *= 2.0_f64.powi(exp - 1);
-                if self.num.is_negative() {
-                    val *= -1.;
-                }
-                val
-            }
-            None => 0.0,
+        let (mantissa, _, _, exp, _) = self.num.as_raw_parts().unwrap();
+        // The mantissa words form an integer W with value = W * 2^(exp - WORD_BITS * len)
+        let top = match mantissa.iter().rposition(|&word| word != 0) {
+            Some(top) => top,
+            None => return sign,
+        };
+        let lead = WORD_BITS * (top as i64 + 1) - 1 - mantissa[top].leading_zeros() as i64;
+        let low_exp = exp as i64 - WORD_BITS * mantissa.len() as i64;
+        // value is in [2^high_exp, 2^(high_exp + 1))
+        let high_exp = lead + low_exp;
+        let bias = (1i64 << (exp_bits - 1)) - 1;
+        let min_scale = 1 - bias - mant_bits as i64;
+        // The result is an integer of at most mant_bits + 1 bits multiplied by 2^scale
+        let scale = (high_exp - mant_bits as i64).max(min_scale);
+        let shift = scale - low_exp;
+        if high_exp > bias + 1 {
+            return sign | overflow_bits(inf_bits, sign != 0, rm);
         }
+        let int = bits_at(mantissa, shift);
+        let half = shift > 0 && bit_at(mantissa, shift - 1);
+        let sticky = shift > 1 && any_below(mantissa, shift - 1);
+        let discarded = match (half, sticky) {
+            (false, _) => Ordering::Less,
+            (true, false) => Ordering::Equal,
+            (true, true) => Ordering::Greater,
+        };
+        let round_away = rounds_away(rm, sign != 0, int & 1 == 1, discarded, half || sticky);
+        // A carry out of the mantissa correctly increments the exponent, and subnormals are
+        // encoded by a biased exponent of zero
+        let bits = (((scale - min_scale) as u64) << mant_bits) + int + round_away as u64;
+        if bits >= inf_bits {
+            return sign | overflow_bits(inf_bits, sign != 0, rm);
+        }
+        sign | bits
+    }
+    /// Converts this BigFloat to a BigFloat with a different context.
+    ///
+    /// The value is rounded to the precision of the target context using the rounding mode of
+    /// the target context. Increasing the precision is always exact.
+    ///
+    /// A `From` implementation is not possible because it would conflict with the reflexive
+    /// `From<T> for T` implementation. Whole matrices and vectors can be converted with
+    /// nalgebra's [`Matrix::cast`](nalgebra::Matrix::cast) method.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use astro_nalgebra::{BigFloat, ConstCtx};
+    /// use nalgebra::{DMatrix, RealField};
+    ///
+    /// type BF128 = BigFloat<ConstCtx<128>>;
+    /// type BF512 = BigFloat<ConstCtx<512>>;
+    ///
+    /// let pi: BF512 = BF512::pi();
+    /// assert_eq!(pi.cast::<ConstCtx<128>>(), BF128::pi());
+    ///
+    /// let mat: DMatrix<BF128> = DMatrix::from_element(2, 2, BF128::pi());
+    /// let promoted: DMatrix<BF512> = mat.cast::<BF512>();
+    /// assert_eq!(promoted[(0, 1)], BF128::pi().cast::<ConstCtx<512>>());
+    /// ```
+    pub fn cast<B: BigFloatCtx>(&self) -> BigFloat<B> {
+        let mut num = self.num.clone();
+        if let Err(err) = num.set_precision(B::get_prec(), B::get_rm()) {
+            num = astro_float::BigFloat::nan(Some(err));
+        }
+        BigFloat::from(num)
     }
     /// Returns sign and integer as u128.
     /// If the absolute value is greater than u128::MAX, returns None.
     ///
-    /// **NOTE:** it is much more idiomatic to use the [`BigFloat::try_into`] method.
+    /// **NOTE:** it is much more idiomatic to use the `TryFrom` implementations of the primitive
+    /// integer types, such as `u64::try_from(float)`, which return a [`BigFloatConversionError`].
     ///
     /// **NOTE:** This will truncate anything below 1 **without** giving a warning or error
     /// You can check if a number is an integer by ensuring that it is equivalent to its truncated
@@ -73,50 +201,302 @@ impl<CTX: BigFloatCtx> BigFloat<CTX> {
         if exp > 128 {
             return None;
         }
-        let mut val = 0u128;
-        if mantissa.is_empty() {
-            return Some((Sign::Pos, 0));
+        if mantissa.is_empty() || exp <= 0 {
+            return Some((sign, 0));
         }
-        if exp < 64 {
-            val += (*mantissa.last().unwrap() as u128) >> (64 - exp);
-        } else {
-            val += (*mantissa.last().unwrap() as u128) << (exp - 64);
+        // The integer part only depends on the top 128 bits of the mantissa
+        let high = *mantissa.last().unwrap() as u128;
+        let low = match mantissa.len() {
+            1 => 0,
+            len => mantissa[len - 2] as u128,
+        };
+        let val = ((high << 64) | low) >> (128 - exp);
+        Some((sign, val))
+    }
+}
+
+/// Returns true if a value that was truncated towards zero has to be rounded away from zero.
+///
+/// `odd` is the parity of the truncated value, `discarded` compares the discarded part with half
+/// a unit in the last place and `inexact` is true if the discarded part is not zero.
+pub(crate) fn rounds_away(
+    rm: RoundingMode,
+    negative: bool,
+    odd: bool,
+    discarded: Ordering,
+    inexact: bool,
+) -> bool {
+    match rm {
+        RoundingMode::None | RoundingMode::ToZero => false,
+        RoundingMode::FromZero => inexact,
+        RoundingMode::Up => inexact && !negative,
+        RoundingMode::Down => inexact && negative,
+        RoundingMode::ToEven => {
+            discarded == Ordering::Greater || (discarded == Ordering::Equal && odd)
         }
-        if mantissa.len() >= 2 && exp > 64 {
-            let second_to_last = mantissa[mantissa.len() - 2];
-            val += (second_to_last as u128) >> (exp - 128);
+        RoundingMode::ToOdd => {
+            discarded == Ordering::Greater || (discarded == Ordering::Equal && !odd)
         }
-        Some((sign, val))
     }
 }
 
+/// Returns the bit pattern of an overflowing value: infinity, or the largest finite value when
+/// rounding towards zero.
+fn overflow_bits(inf_bits: u64, negative: bool, rm: RoundingMode) -> u64 {
+    let to_zero = match rm {
+        RoundingMode::None | RoundingMode::ToZero => true,
+        RoundingMode::Up => negative,
+        RoundingMode::Down => !negative,
+        RoundingMode::FromZero | RoundingMode::ToEven | RoundingMode::ToOdd => false,
+    };
+    if to_zero {
+        inf_bits - 1
+    } else {
+        inf_bits
+    }
+}
+
+/// Returns the exact value of an `f64` with a precision of 64 bits.
+pub(crate) fn exact_f64(val: f64) -> astro_float::BigFloat {
+    let sign = match val.is_sign_negative() {
+        true => Sign::Neg,
+        false => Sign::Pos,
+    };
+    if val.is_nan() {
+        return astro_float::BigFloat::nan(None);
+    }
+    if val.is_infinite() {
+        return match sign {
+            Sign::Pos => astro_float::INF_POS,
+            Sign::Neg => astro_float::INF_NEG,
+        };
+    }
+    // val = int * 2^scale, subnormals have no implicit leading bit
+    let bits = val.to_bits();
+    let biased = ((bits >> 52) & 0x7ff) as i64;
+    let frac = bits & ((1 << 52) - 1);
+    let (int, scale) = match biased {
+        0 => (frac, -1074),
+        _ => (frac | (1 << 52), biased - 1075),
+    };
+    if int == 0 {
+        let zero = astro_float::BigFloat::from_word(0, 64);
+        return match sign {
+            Sign::Pos => zero,
+            Sign::Neg => -zero,
+        };
+    }
+    // The leading bit is moved to the top of the words, so value = 0.words * 2^exp
+    let lz = int.leading_zeros();
+    let top = int << lz;
+    let per_word = (u64::BITS / Word::BITS) as usize;
+    let mut words = [0 as Word; 2];
+    for (idx, word) in words.iter_mut().take(per_word).enumerate() {
+        *word = (top >> (Word::BITS as usize * idx)) as Word;
+    }
+    let exp = scale + 64 - lz as i64;
+    astro_float::BigFloat::from_words(&words[..per_word], sign, exp as Exponent)
+}
+
+/// Returns the word at index `idx` of a little-endian mantissa, or zero when out of range.
+pub(crate) fn word_at(words: &[Word], idx: i64) -> Word {
+    if idx >= 0 && (idx as usize) < words.len() {
+        words[idx as usize]
+    } else {
+        0
+    }
+}
+
+/// Returns the 64 bits of a little-endian mantissa starting at bit `pos`.
+pub(crate) fn bits_at(words: &[Word], pos: i64) -> u64 {
+    let idx = pos.div_euclid(WORD_BITS);
+    let offset = pos.rem_euclid(WORD_BITS);
+    let mut bits = (word_at(words, idx) >> offset) as u64;
+    let mut filled = WORD_BITS - offset;
+    let mut next = idx + 1;
+    while filled < 64 {
+        bits |= (word_at(words, next) as u64) << filled;
+        filled += WORD_BITS;
+        next += 1;
+    }
+    bits
+}
+
+pub(crate) fn bit_at(words: &[Word], pos: i64) -> bool {
+    bits_at(words, pos) & 1 == 1
+}
+
+/// Returns true if any bit below `pos` is set.
+pub(crate) fn any_below(words: &[Word], pos: i64) -> bool {
+    let idx = pos.div_euclid(WORD_BITS);
+    let offset = pos.rem_euclid(WORD_BITS);
+    let partial = offset != 0 && word_at(words, idx) << (WORD_BITS - offset) != 0;
+    partial
+        || words
+            .iter()
+            .take(idx.clamp(0, words.len() as i64) as usize)
+            .any(|&word| word != 0)
+}
+
 macro_rules! cast_float {
-    ($type:ty) => {
+    ($type:ty, $func:ident) => {
+        /// Rounds to the nearest value, with ties to even.
         impl<CTX: BigFloatCtx> From<BigFloat<CTX>> for $type {
             #[inline]
             fn from(x: BigFloat<CTX>) -> Self {
-                x.as_f64() as Self
+                x.$func(RoundingMode::ToEven)
             }
         }
     };
 }
 
-cast_float!(f64);
-cast_float!(f32);
+cast_float!(f64, to_f64_round);
+cast_float!(f32, to_f32_round);
+
+/// Exact conversion that keeps every bit of the `f64`, see [`BigFloat::from_f64_exact`].
+impl<CTX: BigFloatCtx> From<f64> for BigFloat<CTX> {
+    #[inline]
+    fn from(val: f64) -> Self {
+        Self::from_f64_exact(val)
+    }
+}
+
+/// Exact conversion that keeps every bit of the `f32`, see [`BigFloat::from_f32_exact`].
+impl<CTX: BigFloatCtx> From<f32> for BigFloat<CTX> {
+    #[inline]
+    fn from(val: f32) -> Self {
+        Self::from_f32_exact(val)
+    }
+}
+
+/// Error returned when converting a [`BigFloat`] into a primitive integer fails.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BigFloatConversionError {
+    /// The value is `NaN`.
+    NaN,
+    /// The value is positive or negative infinity.
+    Infinite,
+    /// The value has a fractional part.
+    NotInteger,
+    /// The value is negative and the target type is unsigned.
+    Negative,
+    /// The value does not fit in the target type.
+    Overflow,
+}
+
+impl Display for BigFloatConversionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            Self::NaN => "cannot convert NaN to an integer",
+            Self::Infinite => "cannot convert an infinite value to an integer",
+            Self::NotInteger => "value is not an integer",
+            Self::Negative => "cannot convert a negative value to an unsigned integer",
+            Self::Overflow => "value is out of range of the integer type",
+        };
+        f.write_str(message)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BigFloatConversionError {}
+
+impl<CTX: BigFloatCtx> BigFloat<CTX> {
+    /// Returns the sign and magnitude of self if it is an integer whose magnitude fits in a u128.
+    fn exact_int(&self) -> Result<(Sign, u128), BigFloatConversionError> {
+        if self.num.is_nan() {
+            return Err(BigFloatConversionError::NaN);
+        }
+        if self.num.is_inf() {
+            return Err(BigFloatConversionError::Infinite);
+        }
+        if !self.num.is_int() {
+            return Err(BigFloatConversionError::NotInteger);
+        }
+        self.as_int().ok_or(BigFloatConversionError::Overflow)
+    }
+}
+
+impl<CTX: BigFloatCtx> BigFloat<CTX> {
+    /// Rounds self to an integer with the given [`RoundingMode`] and converts it to a primitive
+    /// integer type.
+    ///
+    /// `RoundingMode::ToEven` and `RoundingMode::ToOdd` round to the nearest integer, breaking
+    /// ties to an even or odd integer respectively. `Up`, `Down`, `ToZero` and `FromZero` are
+    /// directed roundings, and `None` truncates like `ToZero`.
+    ///
+    /// Returns an error if self is `NaN` or infinite, or if the rounded value does not fit in
+    /// the target type.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use astro_nalgebra::{BigFloat, ConstCtx, RoundingMode};
+    ///
+    /// let x: BigFloat<ConstCtx<128>> = "-2.5".parse().unwrap();
+    /// assert_eq!(x.to_integer::<i32>(RoundingMode::ToEven), Ok(-2));
+    /// assert_eq!(x.to_integer::<i32>(RoundingMode::Down), Ok(-3));
+    /// assert_eq!(x.to_integer::<i64>(RoundingMode::FromZero), Ok(-3));
+    /// assert!(x.to_integer::<u8>(RoundingMode::ToZero).is_err());
+    /// ```
+    pub fn to_integer<T>(&self, rm: RoundingMode) -> Result<T, BigFloatConversionError>
+    where
+        T: TryFrom<Self, Error = BigFloatConversionError>,
+    {
+        T::try_from(self.round_to_integer(rm))
+    }
+    /// Converts self to a primitive integer type, returning
+    /// [`BigFloatConversionError::NotInteger`] if self has a fractional part.
+    ///
+    /// This is the same as the `TryFrom` implementations, but does not consume self.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use astro_nalgebra::{BigFloat, BigFloatConversionError, ConstCtx};
+    ///
+    /// let x: BigFloat<ConstCtx<128>> = "120".parse().unwrap();
+    /// assert_eq!(x.to_int_checked::<u8>(), Ok(120));
+    /// assert_eq!(x.to_int_checked::<i64>(), Ok(120));
+    /// let y: BigFloat<ConstCtx<128>> = "120.5".parse().unwrap();
+    /// assert_eq!(y.to_int_checked::<u8>(), Err(BigFloatConversionError::NotInteger));
+    /// ```
+    pub fn to_int_checked<T>(&self) -> Result<T, BigFloatConversionError>
+    where
+        T: TryFrom<Self, Error = BigFloatConversionError>,
+    {
+        T::try_from(self.clone())
+    }
+    /// Rounds self to an integer value with the given rounding mode.
+    fn round_to_integer(&self, rm: RoundingMode) -> Self {
+        if self.num.is_nan() || self.num.is_inf() || self.num.is_int() {
+            return self.clone();
+        }
+        let trunc = Self::from(self.num.int());
+        let negative = self.num.is_negative();
+        let frac = (self - &trunc).num.abs();
+        let half = frac
+            .partial_cmp(&astro_float::BigFloat::from_f64(0.5, 64))
+            .unwrap_or(Ordering::Equal);
+        let odd = !(&trunc / 2u8).num.is_int();
+        match (rounds_away(rm, negative, odd, half, true), negative) {
+            (false, _) => trunc,
+            (true, false) => trunc + 1u8,
+            (true, true) => trunc - 1u8,
+        }
+    }
+}
 
 macro_rules! cast_unsigned {
     ($type:ty) => {
         impl<CTX: BigFloatCtx> TryFrom<BigFloat<CTX>> for $type {
-            type Error = ();
+            type Error = BigFloatConversionError;
             fn try_from(x: BigFloat<CTX>) -> Result<Self, Self::Error> {
-                let out = match x.as_int() {
-                    Some(val) => val,
-                    None => return Err(()),
-                };
-                if out.0 == Sign::Neg {
-                    return Err(());
+                match x.exact_int()? {
+                    (Sign::Neg, magnitude) if magnitude != 0 => {
+                        Err(BigFloatConversionError::Negative)
+                    }
+                    (_, magnitude) => {
+                        <$type>::try_from(magnitude).map_err(|_| BigFloatConversionError::Overflow)
+                    }
                 }
-                <$type as TryFrom<u128>>::try_from(out.1).ok().ok_or(())
             }
         }
     };
@@ -127,23 +507,23 @@ cast_unsigned!(u64);
 cast_unsigned!(u32);
 cast_unsigned!(u16);
 cast_unsigned!(u8);
+cast_unsigned!(usize);
 
 macro_rules! cast_signed {
     ($type:ty) => {
-        impl<CTX: BigFloatCtx> TryInto<$type> for BigFloat<CTX> {
-            type Error = ();
-            fn try_into(self) -> Result<$type, Self::Error> {
-                let out = self.as_int().ok_or(())?;
-                let sign = match out.0 {
-                    Sign::Neg => {
-                        if out.1 == <$type>::MAX as u128 + 1 {
-                            return Ok(<$type>::MIN);
-                        }
-                        -1
-                    }
-                    Sign::Pos => 1,
-                };
-                Ok(sign * <u128 as TryInto<$type>>::try_into(out.1).ok().ok_or(())?)
+        impl<CTX: BigFloatCtx> TryFrom<BigFloat<CTX>> for $type {
+            type Error = BigFloatConversionError;
+            fn try_from(x: BigFloat<CTX>) -> Result<Self, Self::Error> {
+                let (sign, magnitude) = x.exact_int()?;
+                if sign == Sign::Neg && magnitude == <$type>::MIN.unsigned_abs() as u128 {
+                    return Ok(<$type>::MIN);
+                }
+                let val =
+                    <$type>::try_from(magnitude).map_err(|_| BigFloatConversionError::Overflow)?;
+                Ok(match sign {
+                    Sign::Neg => -val,
+                    Sign::Pos => val,
+                })
             }
         }
     };
@@ -154,3 +534,4 @@ cast_signed!(i64);
 cast_signed!(i32);
 cast_signed!(i16);
 cast_signed!(i8);
+cast_signed!(isize);
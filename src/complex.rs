@@ -0,0 +1,324 @@
+// This file contains the BigComplex type along with its constructors, arithmetic operators,
+// formatting and parsing. Trait implementations for nalgebra, simba, num_traits and approx are in
+// impls/complex.rs
+extern crate alloc;
+use crate::{BigFloat, BigFloatCtx, ParseBigFloatError};
+use core::borrow::Borrow;
+use core::fmt::{Debug, Display};
+use core::ops::Neg;
+use core::ops::{Add, AddAssign};
+use core::ops::{Div, DivAssign};
+use core::ops::{Mul, MulAssign};
+use core::ops::{Rem, RemAssign};
+use core::ops::{Sub, SubAssign};
+use core::str::FromStr;
+use nalgebra::ComplexField;
+use num_traits::{Num, One, Zero};
+
+/// Arbitrary precision complex number made of two [`BigFloat`]s that share the same context.
+///
+/// This type implements [`ComplexField`](nalgebra::ComplexField) with
+/// `RealField = BigFloat<CTX>`, so it can be used for nalgebra decompositions that need complex
+/// scalars such as [`Schur`](nalgebra::linalg::Schur) or
+/// [`complex_eigenvalues`](nalgebra::Matrix::complex_eigenvalues).
+///
+/// All elementary functions are computed from the real [`RealField`](nalgebra::RealField)
+/// implementation of [`BigFloat`], so their accuracy scales with the precision of `CTX`.
+///
+/// **NOTE:** astro_float does not keep track of the sign of zero, so on branch cuts a zero
+/// imaginary part is always treated as positive zero. For example `sqrt(-4 - 0i)` is `2i`.
+///
+/// ## Example
+/// ```rust
+/// use astro_nalgebra::{BigComplex, ConstCtx};
+/// use nalgebra::ComplexField;
+///
+/// type BC256 = BigComplex<ConstCtx<256>>;
+///
+/// let z: BC256 = "-4".parse().unwrap();
+/// assert_eq!(z.sqrt(), "2i".parse().unwrap());
+///
+/// let w: BC256 = "3+4i".parse().unwrap();
+/// assert_eq!(w.modulus(), "5".parse().unwrap());
+/// ```
+pub struct BigComplex<CTX: BigFloatCtx> {
+    /// Real part
+    pub re: BigFloat<CTX>,
+    /// Imaginary part
+    pub im: BigFloat<CTX>,
+}
+
+impl<CTX: BigFloatCtx> BigComplex<CTX> {
+    /// Creates a complex number from its real and imaginary parts.
+    #[inline]
+    pub fn new(re: BigFloat<CTX>, im: BigFloat<CTX>) -> Self {
+        BigComplex { re, im }
+    }
+    /// Returns the imaginary unit `i`.
+    #[inline]
+    pub fn i() -> Self {
+        BigComplex::new(BigFloat::zero(), BigFloat::one())
+    }
+    /// Creates a complex number from polar coordinates, `r * e^(i * theta)`.
+    pub fn from_polar(r: BigFloat<CTX>, theta: BigFloat<CTX>) -> Self
+    where
+        CTX: 'static,
+    {
+        let (sin, cos) = theta.sin_cos();
+        BigComplex::new(&r * &cos, &r * &sin)
+    }
+    /// Casts both parts to a different context. See [`BigFloat::cast`].
+    pub fn cast<B: BigFloatCtx>(&self) -> BigComplex<B> {
+        BigComplex::new(self.re.cast(), self.im.cast())
+    }
+    /// Returns true if either part is NaN.
+    #[inline]
+    pub fn is_nan(&self) -> bool {
+        self.re.num.is_nan() || self.im.num.is_nan()
+    }
+    /// Returns `re^2 + im^2`
+    pub(crate) fn norm_sqr(&self) -> BigFloat<CTX> {
+        &self.re * &self.re + &self.im * &self.im
+    }
+    /// Multiplies by `i`
+    #[inline]
+    pub(crate) fn mul_i(self) -> Self {
+        BigComplex::new(-self.im, self.re)
+    }
+    /// Multiplies by `-i`
+    #[inline]
+    pub(crate) fn mul_neg_i(self) -> Self {
+        BigComplex::new(self.im, -self.re)
+    }
+    fn add_ref(&self, rhs: &Self) -> Self {
+        BigComplex::new(&self.re + &rhs.re, &self.im + &rhs.im)
+    }
+    fn sub_ref(&self, rhs: &Self) -> Self {
+        BigComplex::new(&self.re - &rhs.re, &self.im - &rhs.im)
+    }
+    fn mul_ref(&self, rhs: &Self) -> Self {
+        BigComplex::new(
+            self.re.fma(&rhs.re, &-(&self.im * &rhs.im)),
+            self.re.fma(&rhs.im, &(&self.im * &rhs.re)),
+        )
+    }
+    fn div_ref(&self, rhs: &Self) -> Self {
+        let denom = rhs.norm_sqr();
+        let re = self.re.fma(&rhs.re, &(&self.im * &rhs.im));
+        let im = self.im.fma(&rhs.re, &-(&self.re * &rhs.im));
+        BigComplex::new(re / &denom, im / denom)
+    }
+    fn rem_ref(&self, rhs: &Self) -> Self {
+        // Same definition as num_complex: subtract the gaussian integer multiple of the modulus
+        // that is closest to the ratio, rounded towards zero.
+        let ratio = self.div_ref(rhs);
+        let gaussian = BigComplex::new(
+            BigFloat::from(ratio.re.num.int()),
+            BigFloat::from(ratio.im.num.int()),
+        );
+        self.sub_ref(&rhs.mul_ref(&gaussian))
+    }
+}
+
+impl<CTX: BigFloatCtx> From<BigFloat<CTX>> for BigComplex<CTX> {
+    #[inline]
+    fn from(re: BigFloat<CTX>) -> Self {
+        BigComplex::new(re, BigFloat::zero())
+    }
+}
+
+impl<CTX: BigFloatCtx> Clone for BigComplex<CTX> {
+    #[inline]
+    fn clone(&self) -> Self {
+        BigComplex::new(self.re.clone(), self.im.clone())
+    }
+}
+impl<CTX: BigFloatCtx> PartialEq for BigComplex<CTX> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.re == other.re && self.im == other.im
+    }
+}
+impl<CTX: BigFloatCtx> Eq for BigComplex<CTX> {}
+
+impl<CTX: BigFloatCtx> Neg for BigComplex<CTX> {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        BigComplex::new(-self.re, -self.im)
+    }
+}
+
+impl<CTX: BigFloatCtx> Neg for &BigComplex<CTX> {
+    type Output = BigComplex<CTX>;
+    #[inline]
+    fn neg(self) -> BigComplex<CTX> {
+        BigComplex::new(-&self.re, -&self.im)
+    }
+}
+
+// Implements the operator for every combination of owned and borrowed operands
+macro_rules! binary_op {
+    ($name:ident, $func:ident, $calls:ident, $name_assign:ident, $func_assign:ident) => {
+        binary_op!(@impl $name, $func, $calls, BigComplex<CTX>, BigComplex<CTX>);
+        binary_op!(@impl $name, $func, $calls, BigComplex<CTX>, &BigComplex<CTX>);
+        binary_op!(@impl $name, $func, $calls, &BigComplex<CTX>, BigComplex<CTX>);
+        binary_op!(@impl $name, $func, $calls, &BigComplex<CTX>, &BigComplex<CTX>);
+        binary_op!(@assign $name_assign, $func_assign, $calls, BigComplex<CTX>);
+        binary_op!(@assign $name_assign, $func_assign, $calls, &BigComplex<CTX>);
+    };
+    (@impl $name:ident, $func:ident, $calls:ident, $lhs:ty, $rhs:ty) => {
+        impl<CTX: BigFloatCtx> $name<$rhs> for $lhs {
+            type Output = BigComplex<CTX>;
+            #[inline]
+            fn $func(self, rhs: $rhs) -> BigComplex<CTX> {
+                let lhs: &BigComplex<CTX> = self.borrow();
+                lhs.$calls(rhs.borrow())
+            }
+        }
+    };
+    (@assign $name:ident, $func:ident, $calls:ident, $rhs:ty) => {
+        impl<CTX: BigFloatCtx> $name<$rhs> for BigComplex<CTX> {
+            #[inline]
+            fn $func(&mut self, rhs: $rhs) {
+                *self = self.$calls(rhs.borrow());
+            }
+        }
+    };
+}
+
+binary_op!(Add, add, add_ref, AddAssign, add_assign);
+binary_op!(Sub, sub, sub_ref, SubAssign, sub_assign);
+binary_op!(Mul, mul, mul_ref, MulAssign, mul_assign);
+binary_op!(Div, div, div_ref, DivAssign, div_assign);
+binary_op!(Rem, rem, rem_ref, RemAssign, rem_assign);
+
+// Operations with a real scalar
+macro_rules! real_op {
+    ($name:ident, $func:ident, $name_assign:ident, $func_assign:ident, $($part:ident),*) => {
+        impl<CTX: BigFloatCtx> $name<BigFloat<CTX>> for BigComplex<CTX> {
+            type Output = BigComplex<CTX>;
+            #[inline]
+            fn $func(mut self, rhs: BigFloat<CTX>) -> BigComplex<CTX> {
+                $(self.$part = self.$part.$func(&rhs);)*
+                self
+            }
+        }
+        impl<CTX: BigFloatCtx> $name<&BigFloat<CTX>> for BigComplex<CTX> {
+            type Output = BigComplex<CTX>;
+            #[inline]
+            fn $func(mut self, rhs: &BigFloat<CTX>) -> BigComplex<CTX> {
+                $(self.$part = self.$part.$func(rhs);)*
+                self
+            }
+        }
+        impl<CTX: BigFloatCtx> $name_assign<BigFloat<CTX>> for BigComplex<CTX> {
+            #[inline]
+            fn $func_assign(&mut self, rhs: BigFloat<CTX>) {
+                $(self.$part.$func_assign(&rhs);)*
+            }
+        }
+    };
+}
+
+real_op!(Add, add, AddAssign, add_assign, re);
+real_op!(Sub, sub, SubAssign, sub_assign, re);
+real_op!(Mul, mul, MulAssign, mul_assign, re, im);
+real_op!(Div, div, DivAssign, div_assign, re, im);
+
+/// Prints `re+imi`. The precision applies to both parts, while the width, alignment and sign flags
+/// apply to the whole number.
+impl<CTX: BigFloatCtx> Display for BigComplex<CTX> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let part = |x: &BigFloat<CTX>| match f.precision() {
+            Some(prec) => alloc::format!("{:.*}", prec, x),
+            None => alloc::format!("{}", x),
+        };
+        let re = part(&self.re);
+        let body = match self.im.num.is_negative() {
+            true => alloc::format!("{}-{}i", re.trim_start_matches('-'), part(&-&self.im)),
+            false => alloc::format!("{}+{}i", re.trim_start_matches('-'), part(&self.im)),
+        };
+        f.pad_integral(!self.re.num.is_negative(), "", &body)
+    }
+}
+
+impl<CTX: BigFloatCtx> Debug for BigComplex<CTX> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("BigComplex")
+            .field("re", &self.re)
+            .field("im", &self.im)
+            .finish()
+    }
+}
+
+/// Returns true if the sign at byte `idx` of `s` belongs to an exponent rather than separating
+/// the real and imaginary parts.
+fn is_exponent_sign(s: &str, idx: usize, radix: u32) -> bool {
+    let before = &s.as_bytes()[..idx];
+    let after = |marker: &[u8]| {
+        before.len() >= marker.len()
+            && before[before.len() - marker.len()..].eq_ignore_ascii_case(marker)
+    };
+    // `p` is only an exponent in hexadecimal float literals, which are accepted in radix 10 and 16
+    after(b"@")
+        || match radix {
+            16 => after(b"_e") || after(b"p"),
+            10 => after(b"e") || after(b"p"),
+            _ => radix < 10 && after(b"e"),
+        }
+}
+
+/// Parses an imaginary term without the trailing `i`. An empty term or lone sign means one.
+fn parse_imaginary<CTX: BigFloatCtx>(
+    s: &str,
+    radix: u32,
+) -> Result<BigFloat<CTX>, ParseBigFloatError> {
+    match s {
+        "" | "+" => Ok(BigFloat::one()),
+        "-" => Ok(-BigFloat::one()),
+        _ => BigFloat::from_str_radix(s, radix),
+    }
+}
+
+impl<CTX: BigFloatCtx> Num for BigComplex<CTX> {
+    type FromStrRadixErr = ParseBigFloatError;
+    /// Parses strings of the form `a`, `bi`, `a+bi` or `a-bi`, where `a` and `b` are parsed with
+    /// [`BigFloat::from_str_radix`]. The imaginary unit can be written as `i` or `j`.
+    fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        let s = str.trim();
+        // Error positions are relative to the untrimmed string
+        let start = str.len() - str.trim_start().len();
+        let imag = s.strip_suffix('i').or_else(|| s.strip_suffix('j'));
+        let Some(rest) = imag else {
+            return match BigFloat::from_str_radix(s, radix) {
+                Ok(re) => Ok(BigComplex::from(re)),
+                Err(err) => Err(err.offset(start)),
+            };
+        };
+        let split = rest
+            .char_indices()
+            .skip(1)
+            .filter(|(idx, ch)| (*ch == '+' || *ch == '-') && !is_exponent_sign(rest, *idx, radix))
+            .map(|(idx, _)| idx)
+            .last();
+        match split {
+            Some(idx) => Ok(BigComplex::new(
+                BigFloat::from_str_radix(&rest[..idx], radix).map_err(|err| err.offset(start))?,
+                parse_imaginary(&rest[idx..], radix).map_err(|err| err.offset(start + idx))?,
+            )),
+            None => Ok(BigComplex::new(
+                BigFloat::zero(),
+                parse_imaginary(rest, radix).map_err(|err| err.offset(start))?,
+            )),
+        }
+    }
+}
+
+impl<CTX: BigFloatCtx> FromStr for BigComplex<CTX> {
+    type Err = ParseBigFloatError;
+    #[inline(always)]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_str_radix(s, 10)
+    }
+}
@@ -1,17 +1,73 @@
 extern crate alloc;
-use alloc::collections::btree_map::{BTreeMap, Entry};
+use alloc::collections::btree_map::BTreeMap;
+use alloc::vec::Vec;
 use astro_float::{ctx::Context, Consts, RoundingMode, EXPONENT_MAX, EXPONENT_MIN};
-#[cfg(std)]
+#[cfg(feature = "std")]
 use core::cell::RefCell;
 use core::fmt::Debug;
 
-#[cfg(std)]
+#[cfg(feature = "std")]
 std::thread_local! {
 /// Global constant that stores a constants cache for each context.
-    pub(crate) static CONTEXTS: RefCell<BTreeMap<(usize, u8), Context>> = RefCell::new(BTreeMap::new());
+    pub(crate) static CONTEXTS: RefCell<BTreeMap<(usize, u8), CtxCache>> = const { RefCell::new(BTreeMap::new()) };
+}
+#[cfg(not(feature = "std"))]
+pub(crate) static mut CONTEXTS: BTreeMap<(usize, u8), CtxCache> = BTreeMap::new();
+
+/// Cached values of a context: the [`Context`] with its constants cache, and the Bernoulli
+/// numbers used by the special functions.
+pub(crate) struct CtxCache {
+    pub(crate) ctx: Context,
+    pub(crate) bernoulli: BernoulliCache,
+}
+
+/// Bernoulli numbers `B_2, B_4, ...` rounded to `prec` bits.
+#[derive(Default)]
+pub(crate) struct BernoulliCache {
+    pub(crate) prec: usize,
+    pub(crate) values: Vec<astro_float::BigFloat>,
+}
+
+impl CtxCache {
+    fn new(p: usize, rm: RoundingMode) -> Self {
+        CtxCache {
+            ctx: Context::new(p, rm, Consts::new().unwrap(), EXPONENT_MIN, EXPONENT_MAX),
+            bernoulli: BernoulliCache::default(),
+        }
+    }
+}
+
+/// Runs `f` with the cache of the context with precision `p` and rounding mode `rm`, creating it
+/// on first use.
+#[cfg(feature = "std")]
+pub(crate) fn with_cache<F, R>(p: usize, rm: RoundingMode, f: F) -> R
+where
+    F: FnOnce(&mut CtxCache) -> R,
+{
+    // We can run borrow_mut without panicking because the variable is thread_local
+    CONTEXTS.with(|ctxs| {
+        let mut ctxs = ctxs.borrow_mut();
+        f(ctxs
+            .entry((p, rm as u8))
+            .or_insert_with(|| CtxCache::new(p, rm)))
+    })
+}
+
+/// Runs `f` with the cache of the context with precision `p` and rounding mode `rm`, creating it
+/// on first use.
+#[cfg(not(feature = "std"))]
+pub(crate) fn with_cache<F, R>(p: usize, rm: RoundingMode, f: F) -> R
+where
+    F: FnOnce(&mut CtxCache) -> R,
+{
+    // We need an unsafe block because it is a global static in the no_std environment
+    // This is, however, okay because it is going to be single threaded
+    unsafe {
+        f(CONTEXTS
+            .entry((p, rm as u8))
+            .or_insert_with(|| CtxCache::new(p, rm)))
+    }
 }
-#[cfg(not(std))]
-pub(crate) static mut CONTEXTS: BTreeMap<(usize, u8), Context> = BTreeMap::new();
 
 /// This trait specifies a type that has zero-argument methods that return a precision and a
 /// rounding mode
@@ -30,42 +86,11 @@ pub trait BigFloatCtx {
     fn get_rm() -> RoundingMode;
 
     /// Run the associated function, passing in an [`astro_float::ctx::Context`] as a mutable reference
-    #[cfg(std)]
     fn run<F, R>(f: F) -> R
     where
         F: FnOnce(&mut astro_float::ctx::Context) -> R,
     {
-        let p = Self::get_prec();
-        let rm = Self::get_rm();
-        // We can run borrow_mut without panicking because the variable is thread_local
-        return CONTEXTS.with(|ctxs| match ctxs.borrow_mut().entry((p, rm as u8)) {
-            Entry::Vacant(v) => {
-                let context =
-                    Context::new(p, rm, Consts::new().unwrap(), EXPONENT_MIN, EXPONENT_MAX);
-                f(v.insert(context))
-            }
-            Entry::Occupied(mut o) => f(o.get_mut()),
-        });
-    }
-    #[cfg(not(std))]
-    fn run<F, R>(f: F) -> R
-    where
-        F: FnOnce(&mut astro_float::ctx::Context) -> R,
-    {
-        let p = Self::get_prec();
-        let rm = Self::get_rm();
-        // We need an unsafe block because it is a global static in the no_std environment
-        // This is, however, okay because it is going to be single threaded
-        unsafe {
-            match CONTEXTS.entry((p, rm as u8)) {
-                Entry::Vacant(v) => {
-                    let context =
-                        Context::new(p, rm, Consts::new().unwrap(), EXPONENT_MIN, EXPONENT_MAX);
-                    f(v.insert(context))
-                }
-                Entry::Occupied(mut o) => f(o.get_mut()),
-            }
-        }
+        with_cache(Self::get_prec(), Self::get_rm(), |cache| f(&mut cache.ctx))
     }
 }
 
@@ -119,6 +144,123 @@ impl<const P: usize, const RM: u8> BigFloatCtx for ConstCtx<P, RM> {
     }
 }
 
+#[cfg(feature = "std")]
+std::thread_local! {
+    /// Stack of precisions and rounding modes pushed onto [`ScopedCtx`] by the current thread.
+    static SCOPED_CTX_STACK: RefCell<Vec<(usize, RoundingMode)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Computation context for [`BigFloat`](crate::BigFloat) whose precision and rounding mode can be
+/// changed at run-time for the current thread.
+///
+/// Unlike [`make_dyn_ctx`](crate::make_dyn_ctx), which can only be set once, this context keeps a
+/// thread-local stack of settings. [`ScopedCtx::push`] returns a guard that restores the previous
+/// setting when it is dropped, and [`ScopedCtx::with_precision`] runs a closure with a setting.
+/// Constants such as pi are cached per precision and rounding mode like every other context.
+///
+/// Using a `BigFloat<ScopedCtx>` while no setting has been pushed on the current thread will
+/// cause a panic. Values computed in one scope keep their own mantissa length, so they remain
+/// usable after the scope ends but further operations use the active precision.
+///
+/// ## Example
+/// ```rust
+/// use astro_nalgebra::{BigFloat, BigFloatCtx, RoundingMode, ScopedCtx};
+/// use nalgebra::ComplexField;
+///
+/// type ScopedFloat = BigFloat<ScopedCtx>;
+///
+/// let low = ScopedCtx::with_precision(128, RoundingMode::ToEven, || {
+///     let num: ScopedFloat = "2".parse().unwrap();
+///     num.sqrt()
+/// });
+/// let _guard = ScopedCtx::push(512, RoundingMode::ToEven);
+/// assert_eq!(ScopedCtx::get_prec(), 512);
+/// let high: ScopedFloat = "2".parse::<ScopedFloat>().unwrap().sqrt();
+/// assert_ne!(low, high);
+/// ```
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct ScopedCtx {
+    _private: (),
+}
+
+#[cfg(feature = "std")]
+impl ScopedCtx {
+    /// Sets the precision and rounding mode for the current thread until the returned guard is
+    /// dropped. Guards must be dropped in the reverse order they were created.
+    pub fn push(prec: usize, rm: RoundingMode) -> ScopedCtxGuard {
+        let depth = SCOPED_CTX_STACK.with(|stack| {
+            let mut stack = stack.borrow_mut();
+            stack.push((prec, rm));
+            stack.len()
+        });
+        ScopedCtxGuard {
+            depth,
+            _not_send: core::marker::PhantomData,
+        }
+    }
+    /// Runs `f` with the given precision and rounding mode, restoring the previous setting
+    /// afterwards (even if `f` panics).
+    pub fn with_precision<F, R>(prec: usize, rm: RoundingMode, f: F) -> R
+    where
+        F: FnOnce() -> R,
+    {
+        let _guard = Self::push(prec, rm);
+        f()
+    }
+    /// Returns true if a precision has been pushed on the current thread.
+    pub fn is_set() -> bool {
+        SCOPED_CTX_STACK.with(|stack| !stack.borrow().is_empty())
+    }
+    #[inline]
+    fn current() -> (usize, RoundingMode) {
+        SCOPED_CTX_STACK.with(|stack| {
+            *stack
+                .borrow()
+                .last()
+                .expect("No precision set for ScopedCtx on this thread")
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl BigFloatCtx for ScopedCtx {
+    #[inline]
+    fn get_prec() -> usize {
+        Self::current().0
+    }
+    #[inline]
+    fn get_rm() -> RoundingMode {
+        Self::current().1
+    }
+}
+
+/// Guard returned by [`ScopedCtx::push`] that restores the previous precision and rounding mode
+/// of the current thread when dropped.
+#[cfg(feature = "std")]
+#[must_use = "the precision is restored as soon as the guard is dropped"]
+#[derive(Debug)]
+pub struct ScopedCtxGuard {
+    depth: usize,
+    // The guard manipulates a thread-local stack, so it must not be sent to another thread
+    _not_send: core::marker::PhantomData<*const ()>,
+}
+
+#[cfg(feature = "std")]
+impl Drop for ScopedCtxGuard {
+    fn drop(&mut self) {
+        SCOPED_CTX_STACK.with(|stack| {
+            let mut stack = stack.borrow_mut();
+            debug_assert_eq!(
+                stack.len(),
+                self.depth,
+                "ScopedCtx guards must be dropped in reverse order"
+            );
+            stack.truncate(self.depth - 1);
+        });
+    }
+}
+
 /// Creates a dynamic context with a precision and rounding mode
 /// that can be set once at run-time.
 ///
@@ -149,7 +291,7 @@ impl<const P: usize, const RM: u8> BigFloatCtx for ConstCtx<P, RM> {
 ///     let num: DynFloat = "120".parse().unwrap();
 /// }
 /// ```
-#[cfg(std)]
+#[cfg(feature = "std")]
 #[macro_export]
 macro_rules! make_dyn_ctx {
     ($type_name:ident, $singleton_name:ident) => {
@@ -157,8 +299,8 @@ macro_rules! make_dyn_ctx {
         pub struct $type_name {
             _private: (),
         }
-        static $singleton_name: core::sync::OnceLock<(usize, astro_nalgebra::RoundingMode)> =
-            core::sync::OnceLock::new();
+        static $singleton_name: std::sync::OnceLock<(usize, astro_nalgebra::RoundingMode)> =
+            std::sync::OnceLock::new();
         impl $type_name {
             #[inline]
             fn set(prec: usize, rm: astro_nalgebra::RoundingMode) {
@@ -214,7 +356,7 @@ macro_rules! make_dyn_ctx {
 ///     let num: DynFloat = "120".parse().unwrap();
 /// }
 /// ```
-#[cfg(not(std))]
+#[cfg(not(feature = "std"))]
 #[macro_export]
 macro_rules! make_dyn_ctx {
     ($type_name:ident, $singleton_name:ident) => {
@@ -0,0 +1,806 @@
+// This file contains the Display, LowerExp, UpperExp, LowerHex and UpperHex implementations for
+// BigFloat and the digit generation they are built on. Digits are computed from the binary value
+// with enough bits to decide their rounding, and exactly when it lies on a rounding boundary, so
+// every printed digit is correctly rounded. The shortest digits that parse back are taken from the interval of
+// numbers that round to the value, scaled once to integers with a few digits more than needed.
+extern crate alloc;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt::{self, Display, Formatter, LowerExp, LowerHex, UpperExp, UpperHex};
+
+use crate::cast::{any_below, bit_at, bits_at, rounds_away, WORD_BITS};
+use crate::{BigFloat, BigFloatCtx};
+use astro_float::{Exponent, RoundingMode, Word, EXPONENT_MAX, EXPONENT_MIN};
+
+/// Returns an exact astro_float BigFloat holding a small integer.
+pub(crate) fn word(val: Word) -> astro_float::BigFloat {
+    astro_float::BigFloat::from_word(val, WORD_BITS as usize)
+}
+
+// The full precision operations of astro_float do not handle zero operands, so these exact
+// operations special case them.
+
+pub(crate) fn exact_add(
+    a: &astro_float::BigFloat,
+    b: &astro_float::BigFloat,
+) -> astro_float::BigFloat {
+    match (a.is_zero(), b.is_zero()) {
+        (true, _) => b.clone(),
+        (_, true) => a.clone(),
+        _ => a.add_full_prec(b),
+    }
+}
+
+fn exact_sub(a: &astro_float::BigFloat, b: &astro_float::BigFloat) -> astro_float::BigFloat {
+    match (a.is_zero(), b.is_zero()) {
+        (true, _) => -b,
+        (_, true) => a.clone(),
+        _ => a.sub_full_prec(b),
+    }
+}
+
+pub(crate) fn exact_mul(
+    a: &astro_float::BigFloat,
+    b: &astro_float::BigFloat,
+) -> astro_float::BigFloat {
+    match a.is_zero() || b.is_zero() {
+        true => word(0),
+        false => a.mul_full_prec(b),
+    }
+}
+
+/// Returns radix^n exactly.
+pub(crate) fn pow_radix(radix: u32, n: usize) -> astro_float::BigFloat {
+    let bits = n * (32 - radix.leading_zeros()) as usize + WORD_BITS as usize;
+    word(radix as Word).powi(n, bits, RoundingMode::None)
+}
+
+/// Returns the digits in `radix` of an integer valued, finite astro_float BigFloat, ignoring its
+/// sign.
+fn integer_digits(int: &astro_float::BigFloat, radix: u32) -> String {
+    let (mantissa, _, _, exp, _) = match int.as_raw_parts() {
+        Some(parts) if !int.is_zero() => parts,
+        _ => return String::from("0"),
+    };
+    // value = W * 2^shift, split into 32 bit limbs, least significant first
+    let shift = exp as i64 - WORD_BITS * mantissa.len() as i64;
+    let bit_len = exp.max(0) as i64 + 32;
+    let mut limbs: Vec<u32> = (0..bit_len / 32)
+        .map(|i| bits_at(mantissa, 32 * i - shift) as u32)
+        .collect();
+    while limbs.last() == Some(&0) {
+        limbs.pop();
+    }
+    // Repeatedly divide by the largest power of the radix that fits in a limb, collecting the
+    // remainders as groups of digits
+    let mut group_len = 1;
+    let mut divisor = radix as u64;
+    while divisor * radix as u64 <= u32::MAX as u64 {
+        divisor *= radix as u64;
+        group_len += 1;
+    }
+    let mut groups: Vec<u32> = Vec::new();
+    while !limbs.is_empty() {
+        let mut rem = 0u64;
+        for limb in limbs.iter_mut().rev() {
+            let cur = (rem << 32) | *limb as u64;
+            *limb = (cur / divisor) as u32;
+            rem = cur % divisor;
+        }
+        groups.push(rem as u32);
+        while limbs.last() == Some(&0) {
+            limbs.pop();
+        }
+    }
+    let mut digits = String::new();
+    for (i, group) in groups.iter().rev().enumerate() {
+        let mut group_digits: Vec<char> = Vec::with_capacity(group_len);
+        let mut val = *group;
+        while val > 0 || (i > 0 && group_digits.len() < group_len) {
+            group_digits.push(char::from_digit(val % radix, radix).unwrap());
+            val /= radix;
+        }
+        digits.extend(group_digits.iter().rev());
+    }
+    if digits.is_empty() {
+        digits.push('0');
+    }
+    digits
+}
+
+/// Returns the digits in `radix` of `|num| * radix^scale` rounded to an integer with `rm`. The
+/// rounding direction takes the sign of `num` into account. `num` must be finite.
+pub(crate) fn scaled_digits(
+    num: &astro_float::BigFloat,
+    radix: u32,
+    scale: i64,
+    rm: RoundingMode,
+) -> String {
+    let x = num.abs();
+    if x.is_zero() {
+        return String::from("0");
+    }
+    // Ziv's strategy: the scaled value is computed with a few more bits than its integer part
+    // until the rounding is decided, and only a value on a rounding boundary needs the exact
+    // computation, whose cost grows with the size of radix^scale
+    let limit = 4 * (x.precision().unwrap_or(0) + WORD_BITS as usize);
+    let mut extra = WORD_BITS as usize;
+    while extra <= limit {
+        if let Some(quot) = approx_scaled(&x, radix, scale, rm, num.is_negative(), extra) {
+            return integer_digits(&quot, radix);
+        }
+        extra *= 2;
+    }
+    integer_digits(
+        &exact_scaled(&x, radix, scale, rm, num.is_negative()),
+        radix,
+    )
+}
+
+/// Returns `x * radix^scale` rounded to an integer with `rm` for `x > 0`, computed with `extra`
+/// bits past its integer part, or `None` if the rounding error leaves the result undecided.
+fn approx_scaled(
+    x: &astro_float::BigFloat,
+    radix: u32,
+    scale: i64,
+    rm: RoundingMode,
+    negative: bool,
+    extra: usize,
+) -> Option<astro_float::BigFloat> {
+    let n = scale.unsigned_abs() as usize;
+    let n_bits = (usize::BITS - n.leading_zeros()) as i64;
+    let log_2 = (radix as f64).log2();
+    let int_bits = x.exponent().unwrap_or(0) as i64 + (scale as f64 * log_2).ceil() as i64;
+    let prec = int_bits.max(0) as usize + extra + n_bits as usize;
+    // The power is applied in two halves so that no intermediate value leaves the exponent
+    // range when x * radix^scale is in it
+    let rm_work = RoundingMode::ToEven;
+    let radix_word = word(radix as Word);
+    let mut y = x.clone();
+    y.set_inexact(false);
+    for half in [n / 2, n - n / 2] {
+        let pow = radix_word.powi(half, prec, rm_work);
+        y = match scale >= 0 {
+            true => y.mul(&pow, prec, rm_work),
+            false => y.div(&pow, prec, rm_work),
+        };
+    }
+    if y.is_inf() || y.is_nan() {
+        return None;
+    }
+    // Each power takes at most 2 n_bits roundings, each of at most half an ulp of prec bits
+    let err_exp = y.exponent().unwrap_or(0) as i64 + n_bits + 3 - prec as i64;
+    let quot = y.int();
+    let frac = exact_sub(&y, &quot);
+    let half = astro_float::BigFloat::from_f64(0.5, 64);
+    let near = |target: &astro_float::BigFloat| {
+        let dist = exact_sub(&frac, target).abs();
+        dist.is_zero() || dist.exponent().unwrap_or(0) as i64 <= err_exp
+    };
+    let nearest = matches!(rm, RoundingMode::ToEven | RoundingMode::ToOdd);
+    let undecided = match nearest {
+        // Whether the integer part is off by one does not change the nearest integer
+        true => near(&half),
+        false => near(&word(0)) || near(&word(1)),
+    };
+    // A scaled value that was computed exactly decides the rounding even on a boundary
+    if y.inexact() && undecided {
+        return None;
+    }
+    let discarded = frac.partial_cmp(&half).unwrap_or(Ordering::Equal);
+    let odd = !exact_mul(&quot, &half).is_int();
+    Some(
+        match rounds_away(rm, negative, odd, discarded, !frac.is_zero()) {
+            true => exact_add(&quot, &word(1)),
+            false => quot,
+        },
+    )
+}
+
+/// Returns `x * radix^scale` rounded to an integer with `rm` for `x > 0`, computed exactly.
+fn exact_scaled(
+    x: &astro_float::BigFloat,
+    radix: u32,
+    scale: i64,
+    rm: RoundingMode,
+    negative: bool,
+) -> astro_float::BigFloat {
+    // x * radix^scale = a / b exactly
+    let (a, b) = match scale >= 0 {
+        true => (exact_mul(x, &pow_radix(radix, scale as usize)), word(1)),
+        false => (x.clone(), pow_radix(radix, scale.unsigned_abs() as usize)),
+    };
+    // Estimate the quotient past its integer part, then correct it with the exact remainder
+    let int_bits = a.exponent().unwrap_or(0) as i64 - b.exponent().unwrap_or(0) as i64 + 1;
+    let prec = int_bits.max(0) as usize + WORD_BITS as usize;
+    let mut quot = a.div(&b, prec, RoundingMode::ToZero).int();
+    let mut rem = exact_sub(&a, &exact_mul(&quot, &b));
+    while rem.is_negative() && !rem.is_zero() {
+        quot = exact_sub(&quot, &word(1));
+        rem = exact_add(&rem, &b);
+    }
+    while rem >= b {
+        quot = exact_add(&quot, &word(1));
+        rem = exact_sub(&rem, &b);
+    }
+    let discarded = exact_add(&rem, &rem)
+        .partial_cmp(&b)
+        .unwrap_or(Ordering::Equal);
+    let odd = !exact_mul(&quot, &astro_float::BigFloat::from_f64(0.5, 64)).is_int();
+    match rounds_away(rm, negative, odd, discarded, !rem.is_zero()) {
+        true => exact_add(&quot, &word(1)),
+        false => quot,
+    }
+}
+
+/// Returns `sig` significant digits in `radix` of `num` rounded with `rm` and the exponent of the
+/// first digit. `num` must be finite.
+pub(crate) fn scientific_digits(
+    num: &astro_float::BigFloat,
+    radix: u32,
+    sig: usize,
+    rm: RoundingMode,
+) -> (String, i64) {
+    let sig = sig.max(1);
+    if num.is_zero() {
+        return ("0".repeat(sig), 0);
+    }
+    // value is in [2^(exp - 1), 2^exp), so the exponent is near (exp - 1) * log_radix(2)
+    let exp = num.exponent().unwrap_or(0) as i64;
+    let log_2 = core::f64::consts::LN_2 / (radix as f64).ln();
+    let mut exp_radix = ((exp - 1) as f64 * log_2).floor() as i64;
+    loop {
+        let digits = scaled_digits(num, radix, sig as i64 - 1 - exp_radix, rm);
+        match digits.len().cmp(&sig) {
+            Ordering::Greater => exp_radix += 1,
+            Ordering::Less => exp_radix -= 1,
+            Ordering::Equal => return (digits, exp_radix),
+        }
+    }
+}
+
+impl<CTX: BigFloatCtx> BigFloat<CTX> {
+    /// Returns self in base `radix` with `digits` significant digits, rounded with the given
+    /// [`RoundingMode`].
+    ///
+    /// The number is written in scientific notation as `d.ddd` followed by the exponent, which is
+    /// a decimal integer giving the power of `radix`. The exponent is separated by `e` in radix
+    /// 10 and by `@` otherwise, because the parser reads an exponent after `e` in the radix of
+    /// the number and `e` is a digit in larger bases. Digits above 9 are written as lowercase
+    /// letters. `NaN` and infinities are written as `NaN`, `Inf` and `-Inf`.
+    ///
+    /// The string can be parsed back with `from_str_radix` in the same radix.
+    ///
+    /// ## Panics
+    /// Panics if `radix` is not in the range from 2 to 36.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use astro_nalgebra::{BigFloat, ConstCtx, RoundingMode};
+    /// use nalgebra::RealField;
+    ///
+    /// let pi = BigFloat::<ConstCtx<256>>::pi();
+    /// assert_eq!(pi.to_string_radix(10, 6, RoundingMode::ToEven), "3.14159e0");
+    /// assert_eq!(pi.to_string_radix(10, 6, RoundingMode::Up), "3.14160e0");
+    /// assert_eq!(pi.to_string_radix(2, 8, RoundingMode::ToZero), "1.1001001@1");
+    /// assert_eq!(pi.to_string_radix(16, 4, RoundingMode::ToEven), "3.244@0");
+    /// ```
+    pub fn to_string_radix(&self, radix: u32, digits: usize, rm: RoundingMode) -> String {
+        assert!(
+            (2..=36).contains(&radix),
+            "radix must be in the range from 2 to 36"
+        );
+        let sign = if self.num.is_negative() { "-" } else { "" };
+        if let Some(text) = non_finite(&self.num) {
+            return alloc::format!("{}{}", sign, text);
+        }
+        let (mut mantissa, exp) = scientific_digits(&self.num, radix, digits, rm);
+        if mantissa.len() > 1 {
+            mantissa.insert(1, '.');
+        }
+        let separator = if radix == 10 { 'e' } else { '@' };
+        alloc::format!("{}{}{}{}", sign, mantissa, separator, exp)
+    }
+    /// Returns self in decimal scientific notation with `significant_digits` correctly rounded
+    /// digits, rounding to nearest with ties to even.
+    ///
+    /// This is the same as `format!("{:.*e}", significant_digits - 1, self)`, see
+    /// [`BigFloat::to_string_radix`] for the format.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use astro_nalgebra::{BigFloat, ConstCtx};
+    ///
+    /// let x: BigFloat<ConstCtx<128>> = "-1234.5678".parse().unwrap();
+    /// assert_eq!(x.to_decimal_string(3), "-1.23e3");
+    /// assert_eq!(x.to_decimal_string(6), "-1.23457e3");
+    /// ```
+    pub fn to_decimal_string(&self, significant_digits: usize) -> String {
+        self.to_string_radix(10, significant_digits, RoundingMode::ToEven)
+    }
+}
+
+impl<CTX: BigFloatCtx> BigFloat<CTX> {
+    /// Returns the shortest decimal string that parses back to exactly this number with
+    /// [`FromStr`](core::str::FromStr), at the precision and rounding mode of the context.
+    ///
+    /// Numbers with a decimal exponent from -7 to 20 are written in fixed notation, such as `0.1`
+    /// or `-123.25`, and other numbers in scientific notation, such as `1.5e-9`. `NaN` and
+    /// infinities are written as `NaN`, `Inf` and `-Inf`.
+    ///
+    /// With the `shortest-display` feature, this is also the output of [`Display`] when no
+    /// precision is given.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use astro_nalgebra::{BigFloat, ConstCtx, RoundingMode};
+    /// use num_traits::{FromPrimitive, One};
+    ///
+    /// type BF128 = BigFloat<ConstCtx<128, { RoundingMode::ToEven as u8 }>>;
+    ///
+    /// let x: BF128 = "0.1".parse().unwrap();
+    /// assert_eq!(x.to_shortest_string(), "0.1");
+    /// let y: BF128 = "-1.5e-30".parse().unwrap();
+    /// assert_eq!(y.to_shortest_string(), "-1.5e-30");
+    ///
+    /// let third: BF128 = BF128::one() / BF128::from_u8(3).unwrap();
+    /// let string = third.to_shortest_string();
+    /// assert_eq!(string.parse::<BF128>().unwrap(), third);
+    /// ```
+    pub fn to_shortest_string(&self) -> String {
+        let sign = if self.num.is_negative() { "-" } else { "" };
+        if let Some(text) = non_finite(&self.num) {
+            return alloc::format!("{}{}", sign, text);
+        }
+        if self.num.is_zero() {
+            return alloc::format!("{}0", sign);
+        }
+        let (digits, exp10) = self.shortest_digits();
+        alloc::format!("{}{}", sign, layout_decimal(&digits, exp10))
+    }
+    /// Returns the digits and decimal exponent of scientific notation with `frac` digits after the
+    /// decimal point, or the shortest digits that parse back to self. Self must be finite.
+    fn exponential_digits(&self, frac: Option<usize>) -> (String, i64) {
+        match frac {
+            Some(frac) => scientific_digits(&self.num, 10, frac + 1, RoundingMode::ToEven),
+            None if self.num.is_zero() => (String::from("0"), 0),
+            None => self.shortest_digits(),
+        }
+    }
+    /// Returns the fewest significant decimal digits that parse back to self, along with the
+    /// decimal exponent of the first digit. Self must be finite and not zero.
+    fn shortest_digits(&self) -> (String, i64) {
+        // The digits from the rounding interval are checked, because RoundingMode::None may
+        // truncate an inexact quotient and a number with more bits than the context never
+        // parses back
+        let prec = self.num.precision().unwrap_or(0).max(CTX::get_prec());
+        match interval_digits(&self.num, prec, CTX::get_rm()) {
+            Some((digits, exp10)) if self.parses_back(&digits, exp10) => (digits, exp10),
+            _ => self.searched_digits(),
+        }
+    }
+    /// Returns the shortest digits like [`BigFloat::shortest_digits`] by parsing candidates of
+    /// every length in a binary search.
+    fn searched_digits(&self) -> (String, i64) {
+        // Besides the nearest digits, the digits on either side of the value are tried because
+        // the parser may not round to nearest
+        let round_trips = |sig: usize| {
+            [
+                RoundingMode::ToEven,
+                RoundingMode::ToZero,
+                RoundingMode::FromZero,
+            ]
+            .into_iter()
+            .map(|rm| scientific_digits(&self.num, 10, sig, rm))
+            .find(|(digits, exp10)| self.parses_back(digits, *exp10))
+        };
+        let prec = self.num.precision().unwrap_or(0).max(CTX::get_prec());
+        let mut high = round_trip_digits(prec);
+        let mut best = round_trips(high);
+        // Binary search for the shortest length, the round trip digits almost always succeed
+        let mut low = 1;
+        while low < high {
+            let mid = (low + high) / 2;
+            match round_trips(mid) {
+                Some(found) => {
+                    best = Some(found);
+                    high = mid;
+                }
+                None => low = mid + 1,
+            }
+        }
+        let (mut digits, exp10) =
+            best.unwrap_or_else(|| scientific_digits(&self.num, 10, high, RoundingMode::ToEven));
+        digits.truncate(digits.trim_end_matches('0').len().max(1));
+        (digits, exp10)
+    }
+    /// Returns whether the significant digits with the decimal exponent of the first digit and
+    /// the sign of self parse back to self.
+    fn parses_back(&self, digits: &str, exp10: i64) -> bool {
+        let sign = if self.num.is_negative() { "-" } else { "" };
+        let exp = exp10 + 1 - digits.len() as i64;
+        alloc::format!("{}{}e{}", sign, digits, exp)
+            .parse::<Self>()
+            .is_ok_and(|parsed| parsed.num == self.num)
+    }
+}
+
+/// Returns the bounds of the magnitudes that round to `|num|` at `prec` bits with `rm`, each with
+/// whether it rounds to `|num|` itself, or None if they need powers of two outside the exponent
+/// range. `num` must be finite and not zero.
+fn rounding_interval(
+    num: &astro_float::BigFloat,
+    prec: usize,
+    rm: RoundingMode,
+) -> Option<[(astro_float::BigFloat, bool); 2]> {
+    let pow2 = |e: i64| {
+        let e = Exponent::try_from(e + 1)
+            .ok()
+            .filter(|e| (EXPONENT_MIN..=EXPONENT_MAX).contains(e))?;
+        let mut pow = word(1);
+        pow.set_exponent(e);
+        Some(pow)
+    };
+    let x = num.abs();
+    let (mantissa, _, _, exp, _) = x.as_raw_parts()?;
+    let exp = exp as i64;
+    // None when rounding to nearest, otherwise whether the magnitude is rounded down
+    let negative = num.is_negative();
+    let toward_zero = match rm {
+        RoundingMode::ToEven | RoundingMode::ToOdd => None,
+        RoundingMode::FromZero => Some(false),
+        RoundingMode::Up => Some(negative),
+        RoundingMode::Down => Some(!negative),
+        _ => Some(true),
+    };
+    // The distance to the neighbours above and below, or half of it when rounding to nearest,
+    // where the spacing below a power of two is half as large
+    let shift = prec as i64 + toward_zero.is_none() as i64;
+    let above = pow2(exp - shift)?;
+    let below = match exact_sub(&x, &pow2(exp - 1)?).is_zero() {
+        true => pow2(exp - 1 - shift)?,
+        false => above.clone(),
+    };
+    Some(match toward_zero {
+        None => {
+            // A tie rounds to |num| if its last bit is the one the rounding mode prefers
+            let odd = bit_at(mantissa, WORD_BITS * mantissa.len() as i64 - prec as i64);
+            let ties = odd == (rm == RoundingMode::ToOdd);
+            [(exact_sub(&x, &below), ties), (exact_add(&x, &above), ties)]
+        }
+        Some(true) => [(x.clone(), true), (exact_add(&x, &above), false)],
+        Some(false) => [(exact_sub(&x, &below), false), (x, true)],
+    })
+}
+
+/// Returns the fewest significant decimal digits of a number in the rounding interval of `num` at
+/// `prec` bits with `rm`, the nearest to `num` of that length, along with the decimal exponent of
+/// the first digit. Returns None if the interval could not be computed. `num` must be finite and
+/// not zero.
+fn interval_digits(
+    num: &astro_float::BigFloat,
+    prec: usize,
+    rm: RoundingMode,
+) -> Option<(String, i64)> {
+    let [(lo, lo_in), (hi, hi_in)] = rounding_interval(num, prec, rm)?;
+    // The value and the bounds are scaled once by the same power of ten, to integers with more
+    // digits than any number with prec bits needs, and every length is a prefix of them
+    let x = num.abs();
+    let exp = x.exponent().unwrap_or(0) as i64;
+    let scale = round_trip_digits(prec) as i64
+        - ((exp - 1) as f64 * core::f64::consts::LOG10_2).floor() as i64;
+    let scaled = |val: &astro_float::BigFloat, rm| scaled_digits(val, 10, scale, rm);
+    // The smallest and largest scaled integers that round to |num|
+    let lo = match lo_in {
+        true => scaled(&lo, RoundingMode::Up),
+        false => increment(&scaled(&lo, RoundingMode::Down)),
+    };
+    let hi = match hi_in {
+        true => scaled(&hi, RoundingMode::Down),
+        false => decrement(&scaled(&hi, RoundingMode::Up)),
+    };
+    // The value is truncated and kept apart from whether it was exact, so that rounding a
+    // prefix of it does not round twice
+    let mid = scaled(&x, RoundingMode::Down);
+    let sticky = scaled(&x, RoundingMode::Up) != mid;
+    let len = lo.len().max(hi.len()).max(mid.len());
+    let [lo, hi, mid] = [lo, hi, mid].map(|digits| alloc::format!("{:0>len$}", digits));
+    for sig in 1..=len {
+        let low = ceil_prefix(&lo, sig);
+        let high = &hi[..sig];
+        if cmp_digits(&low, high) == Ordering::Greater {
+            continue;
+        }
+        let near = round_prefix(&mid, sig, sticky);
+        let digits = match (cmp_digits(&near, &low), cmp_digits(&near, high)) {
+            (Ordering::Less, _) => low,
+            (_, Ordering::Greater) => String::from(high),
+            _ => near,
+        };
+        // The value is digits * 10^(len - sig - scale)
+        let digits = digits.trim_start_matches('0');
+        if digits.is_empty() {
+            continue;
+        }
+        let exp10 = digits.len() as i64 - 1 + (len - sig) as i64 - scale;
+        let digits = &digits[..digits.trim_end_matches('0').len()];
+        return Some((String::from(digits), exp10));
+    }
+    None
+}
+
+/// Compares two strings of decimal digits as integers.
+fn cmp_digits(a: &str, b: &str) -> Ordering {
+    let (a, b) = (a.trim_start_matches('0'), b.trim_start_matches('0'));
+    a.len().cmp(&b.len()).then_with(|| a.cmp(b))
+}
+
+/// Returns the decimal digits of the integer one more than `digits`.
+fn increment(digits: &str) -> String {
+    let mut bytes = digits.as_bytes().to_vec();
+    for byte in bytes.iter_mut().rev() {
+        if *byte == b'9' {
+            *byte = b'0';
+        } else {
+            *byte += 1;
+            return String::from_utf8(bytes).unwrap();
+        }
+    }
+    alloc::format!("1{}", String::from_utf8(bytes).unwrap())
+}
+
+/// Returns the decimal digits of the integer one less than `digits`, which must not be zero.
+fn decrement(digits: &str) -> String {
+    let mut bytes = digits.as_bytes().to_vec();
+    for byte in bytes.iter_mut().rev() {
+        if *byte == b'0' {
+            *byte = b'9';
+        } else {
+            *byte -= 1;
+            break;
+        }
+    }
+    String::from_utf8(bytes).unwrap()
+}
+
+/// Returns the first `sig` of the decimal `digits`, plus one if any of the rest is not zero.
+fn ceil_prefix(digits: &str, sig: usize) -> String {
+    let (prefix, rest) = digits.split_at(sig);
+    match rest.bytes().any(|digit| digit != b'0') {
+        true => increment(prefix),
+        false => String::from(prefix),
+    }
+}
+
+/// Returns the decimal `digits` rounded to the first `sig`, to nearest with ties to even, where
+/// `sticky` tells whether nonzero digits follow the given ones.
+fn round_prefix(digits: &str, sig: usize, sticky: bool) -> String {
+    let (prefix, rest) = digits.split_at(sig);
+    let odd = prefix.bytes().last().is_some_and(|digit| digit % 2 == 1);
+    let discarded = match rest.as_bytes().first() {
+        None => Ordering::Less,
+        Some(&first) => first.cmp(&b'5').then_with(|| {
+            match sticky || rest[1..].bytes().any(|digit| digit != b'0') {
+                true => Ordering::Greater,
+                false => Ordering::Equal,
+            }
+        }),
+    };
+    match discarded == Ordering::Greater || (discarded == Ordering::Equal && odd) {
+        true => increment(prefix),
+        false => String::from(prefix),
+    }
+}
+
+/// Writes significant digits with the decimal exponent of the first digit in fixed notation for
+/// moderate exponents and in scientific notation otherwise.
+fn layout_decimal(digits: &str, exp10: i64) -> String {
+    let len = digits.len() as i64;
+    match exp10 {
+        -7..=-1 => alloc::format!("0.{}{}", "0".repeat((-exp10 - 1) as usize), digits),
+        0..=20 if len <= exp10 + 1 => {
+            alloc::format!("{}{}", digits, "0".repeat((exp10 + 1 - len) as usize))
+        }
+        0..=20 => {
+            let (int, frac) = digits.split_at(exp10 as usize + 1);
+            alloc::format!("{}.{}", int, frac)
+        }
+        _ if len == 1 => alloc::format!("{}e{}", digits, exp10),
+        _ => alloc::format!("{}.{}e{}", &digits[..1], &digits[1..], exp10),
+    }
+}
+
+/// Returns the number of significant decimal digits needed to distinguish every number with
+/// `prec` bits of precision.
+pub(crate) fn round_trip_digits(prec: usize) -> usize {
+    (prec as f64 * core::f64::consts::LOG10_2).ceil() as usize + 1
+}
+
+/// Returns the text printed for NaN and infinities, or None if `num` is finite.
+fn non_finite(num: &astro_float::BigFloat) -> Option<&'static str> {
+    if num.is_nan() {
+        Some("NaN")
+    } else if num.is_inf() {
+        Some("Inf")
+    } else {
+        None
+    }
+}
+
+/// Returns `|num|` in fixed notation with `frac` digits after the decimal point.
+fn fixed(num: &astro_float::BigFloat, frac: usize, point: bool) -> String {
+    let mut digits = scaled_digits(num, 10, frac as i64, RoundingMode::ToEven);
+    if digits.len() <= frac {
+        digits.insert_str(0, &"0".repeat(frac + 1 - digits.len()));
+    }
+    if frac > 0 || point {
+        digits.insert(digits.len() - frac, '.');
+    }
+    digits
+}
+
+/// Returns `|digits| x 10^exp10` in scientific notation, with a decimal point after the first digit
+/// if there are more digits or `point` is set.
+fn exponential(mut digits: String, exp10: i64, point: bool, e: char) -> String {
+    if digits.len() > 1 || point {
+        digits.insert(1, '.');
+    }
+    alloc::format!("{}{}{}", digits, e, exp10)
+}
+
+/// Formats a number with the sign, width, fill, alignment and zero padding flags of `f`.
+fn pad(
+    f: &mut Formatter<'_>,
+    num: &astro_float::BigFloat,
+    prefix: &str,
+    body: &str,
+) -> fmt::Result {
+    f.pad_integral(!num.is_negative() || num.is_nan(), prefix, body)
+}
+
+/// Without a precision, the number is printed with all of its digits in the same format as
+/// [`astro_float::BigFloat`], or with [`BigFloat::to_shortest_string`] if the `shortest-display`
+/// feature is enabled. With a precision, such as `{:.30}`, it is printed in fixed notation with
+/// that many correctly rounded digits after the decimal point.
+///
+/// The width, fill, alignment, sign (`+`) and zero padding (`0`) flags are supported. The
+/// alternate flag (`#`) always prints a decimal point.
+impl<CTX: BigFloatCtx> Display for BigFloat<CTX> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if let Some(text) = non_finite(&self.num) {
+            return pad(f, &self.num, "", text);
+        }
+        let body = match f.precision() {
+            Some(frac) => fixed(&self.num, frac, f.alternate()),
+            #[cfg(feature = "shortest-display")]
+            None => String::from(self.to_shortest_string().trim_start_matches('-')),
+            #[cfg(not(feature = "shortest-display"))]
+            None => {
+                let text = alloc::format!("{}", self.num.abs());
+                String::from(text.trim_start_matches('-'))
+            }
+        };
+        pad(f, &self.num, "", &body)
+    }
+}
+
+/// Scientific notation in the same style as [`f64`], for example `1.5e-7`. Without a precision
+/// the shortest digits that parse back to the number are printed, the same digits as
+/// [`BigFloat::to_shortest_string`].
+///
+/// The width, fill, alignment, sign (`+`) and zero padding (`0`) flags are supported. The
+/// alternate flag (`#`) always prints a decimal point.
+impl<CTX: BigFloatCtx> LowerExp for BigFloat<CTX> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if let Some(text) = non_finite(&self.num) {
+            return pad(f, &self.num, "", text);
+        }
+        let (digits, exp10) = self.exponential_digits(f.precision());
+        let body = exponential(digits, exp10, f.alternate(), 'e');
+        pad(f, &self.num, "", &body)
+    }
+}
+
+/// Scientific notation in the same style as [`f64`], for example `1.5E-7`. See the [`LowerExp`]
+/// implementation.
+impl<CTX: BigFloatCtx> UpperExp for BigFloat<CTX> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if let Some(text) = non_finite(&self.num) {
+            return pad(f, &self.num, "", text);
+        }
+        let (digits, exp10) = self.exponential_digits(f.precision());
+        let body = exponential(digits, exp10, f.alternate(), 'E');
+        pad(f, &self.num, "", &body)
+    }
+}
+
+/// Hexadecimal floating point notation as in C99, for example `1.921fb54442d18p+1` for pi. The
+/// precision sets the number of hexadecimal digits after the point, otherwise the mantissa is
+/// printed exactly with trailing zeros removed.
+///
+/// The alternate flag (`#`) adds the `0x` prefix. The width, fill, alignment, sign (`+`) and zero
+/// padding (`0`) flags are supported.
+impl<CTX: BigFloatCtx> LowerHex for BigFloat<CTX> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if let Some(text) = non_finite(&self.num) {
+            return pad(f, &self.num, "", text);
+        }
+        let body = hexadecimal(&self.num, f.precision());
+        pad(f, &self.num, "0x", &body)
+    }
+}
+
+/// Hexadecimal floating point notation with uppercase digits, `0X` prefix and `P` exponent, for
+/// example `1.921FB54442D18P+1` for pi. Takes the same flags as [`LowerHex`].
+impl<CTX: BigFloatCtx> UpperHex for BigFloat<CTX> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if let Some(text) = non_finite(&self.num) {
+            return pad(f, &self.num, "", text);
+        }
+        let body = hexadecimal(&self.num, f.precision()).to_uppercase();
+        pad(f, &self.num, "0X", &body)
+    }
+}
+
+/// Returns `|num|` as a C99 hexadecimal float without the `0x` prefix.
+fn hexadecimal(num: &astro_float::BigFloat, frac: Option<usize>) -> String {
+    let (mantissa, _, _, exp, _) = match num.as_raw_parts() {
+        Some(parts) if !num.is_zero() => parts,
+        _ => {
+            return match frac {
+                Some(frac) if frac > 0 => alloc::format!("0.{}p+0", "0".repeat(frac)),
+                _ => String::from("0p+0"),
+            }
+        }
+    };
+    let top = mantissa.iter().rposition(|&word| word != 0).unwrap_or(0);
+    let lead = WORD_BITS * (top as i64 + 1) - 1 - mantissa[top].leading_zeros() as i64;
+    let mut bin_exp = lead + exp as i64 - WORD_BITS * mantissa.len() as i64;
+    let digit_count = frac.unwrap_or((lead as usize).div_ceil(4));
+    let mut digits: Vec<u8> = (1..=digit_count as i64)
+        .map(|i| (bits_at(mantissa, lead - 4 * i) & 0xf) as u8)
+        .collect();
+    let end = lead - 4 * digit_count as i64;
+    let half = bit_at(mantissa, end - 1);
+    let sticky = any_below(mantissa, end - 1);
+    let discarded = match (half, sticky) {
+        (false, _) => Ordering::Less,
+        (true, false) => Ordering::Equal,
+        (true, true) => Ordering::Greater,
+    };
+    let odd = match digits.last() {
+        Some(digit) => digit & 1 == 1,
+        None => true,
+    };
+    if rounds_away(RoundingMode::ToEven, false, odd, discarded, half || sticky) {
+        // Propagate the carry, an overflow of the leading digit renormalizes to 1.000...
+        let mut carry = true;
+        for digit in digits.iter_mut().rev() {
+            *digit = (*digit + 1) & 0xf;
+            carry = *digit == 0;
+            if !carry {
+                break;
+            }
+        }
+        if carry {
+            bin_exp += 1;
+        }
+    }
+    if frac.is_none() {
+        while digits.last() == Some(&0) {
+            digits.pop();
+        }
+    }
+    let mut body = String::from("1");
+    if !digits.is_empty() {
+        body.push('.');
+        body.extend(
+            digits
+                .iter()
+                .map(|&d| char::from_digit(d as u32, 16).unwrap()),
+        );
+    }
+    body.push_str(&alloc::format!("p{:+}", bin_exp));
+    body
+}
@@ -20,7 +20,7 @@ impl<CTX: BigFloatCtx + 'static> RelativeEq<Self> for BigFloat<CTX> {
         if self.num.is_inf() || other.num.is_inf() {
             return false;
         }
-        let abs_diff = (self.clone() - other.clone()).abs();
+        let abs_diff = (self - other).abs();
         if abs_diff <= epsilon {
             return true;
         }
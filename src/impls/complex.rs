@@ -0,0 +1,509 @@
+// This file contains the nalgebra, simba, num_traits and approx trait implementations for
+// BigComplex. The elementary functions are built on the real implementations in nalgebra.rs
+use crate::special::{round, run};
+use crate::{BigComplex, BigFloat, BigFloatCtx};
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+use core::cmp::Ordering;
+use nalgebra::{ComplexField, Field, RealField};
+use num_traits::{FromPrimitive, One, Zero};
+use simba::scalar::SubsetOf;
+use simba::simd::SimdValue;
+
+impl<CTX: BigFloatCtx> Field for BigComplex<CTX> {}
+
+/// Returns zero raised to the power `re + i im`. It is zero when the real part is positive,
+/// infinite when it is negative, and `NaN` otherwise, except for `0^0 = 1`.
+fn zero_pow<CTX: BigFloatCtx>(re: &BigFloat<CTX>, im: &BigFloat<CTX>) -> BigComplex<CTX> {
+    if re.is_zero() && im.is_zero() {
+        return BigComplex::one();
+    }
+    let modulus = match re.partial_cmp(&BigFloat::zero()) {
+        Some(Ordering::Greater) => return BigComplex::zero(),
+        Some(Ordering::Less) => astro_float::INF_POS,
+        _ => astro_float::BigFloat::nan(None),
+    };
+    BigComplex::from(BigFloat::from(modulus))
+}
+
+impl<CTX: BigFloatCtx> Zero for BigComplex<CTX> {
+    #[inline]
+    fn zero() -> Self {
+        BigComplex::new(BigFloat::zero(), BigFloat::zero())
+    }
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.re.is_zero() && self.im.is_zero()
+    }
+}
+
+impl<CTX: BigFloatCtx> One for BigComplex<CTX> {
+    #[inline]
+    fn one() -> Self {
+        BigComplex::new(BigFloat::one(), BigFloat::zero())
+    }
+    fn is_one(&self) -> bool {
+        self.re.is_one() && self.im.is_zero()
+    }
+}
+
+macro_rules! from_prim {
+    ($name: ident, $type:ty) => {
+        fn $name(prim: $type) -> Option<Self> {
+            BigFloat::$name(prim).map(BigComplex::from)
+        }
+    };
+}
+
+impl<CTX: BigFloatCtx> FromPrimitive for BigComplex<CTX> {
+    from_prim!(from_f64, f64);
+    from_prim!(from_f32, f32);
+
+    from_prim!(from_u8, u8);
+    from_prim!(from_i8, i8);
+    from_prim!(from_u16, u16);
+    from_prim!(from_i16, i16);
+    from_prim!(from_u32, u32);
+    from_prim!(from_i32, i32);
+    from_prim!(from_i64, i64);
+    from_prim!(from_u64, u64);
+    from_prim!(from_i128, i128);
+    from_prim!(from_u128, u128);
+}
+
+// Hidden because not important
+#[doc(hidden)]
+impl<A: BigFloatCtx, B: BigFloatCtx> SubsetOf<BigComplex<B>> for BigComplex<A> {
+    #[inline]
+    fn to_superset(&self) -> BigComplex<B> {
+        self.cast()
+    }
+    #[inline]
+    fn from_superset_unchecked(element: &BigComplex<B>) -> Self {
+        element.cast()
+    }
+    #[inline]
+    fn is_in_subset(element: &BigComplex<B>) -> bool {
+        <BigFloat<A> as SubsetOf<BigFloat<B>>>::is_in_subset(&element.re)
+            && <BigFloat<A> as SubsetOf<BigFloat<B>>>::is_in_subset(&element.im)
+    }
+}
+
+// Allows promoting real matrices to complex matrices with Matrix::cast
+// Hidden because not important
+#[doc(hidden)]
+impl<A: BigFloatCtx, B: BigFloatCtx> SubsetOf<BigComplex<B>> for BigFloat<A> {
+    #[inline]
+    fn to_superset(&self) -> BigComplex<B> {
+        BigComplex::from(self.cast())
+    }
+    #[inline]
+    fn from_superset_unchecked(element: &BigComplex<B>) -> Self {
+        element.re.cast()
+    }
+    #[inline]
+    fn is_in_subset(element: &BigComplex<B>) -> bool {
+        element.im.is_zero() && <Self as SubsetOf<BigFloat<B>>>::is_in_subset(&element.re)
+    }
+}
+
+// Hidden because not important
+#[doc(hidden)]
+impl<CTX: BigFloatCtx> SubsetOf<BigComplex<CTX>> for f64 {
+    #[inline]
+    fn to_superset(&self) -> BigComplex<CTX> {
+        BigComplex::from(BigFloat::from_f64(*self).unwrap())
+    }
+    #[inline]
+    fn from_superset_unchecked(element: &BigComplex<CTX>) -> Self {
+        element.re.as_f64()
+    }
+    #[inline]
+    fn is_in_subset(element: &BigComplex<CTX>) -> bool {
+        element.im.is_zero()
+    }
+}
+
+// Hidden because not relevant to end users
+#[doc(hidden)]
+impl<CTX: BigFloatCtx> SimdValue for BigComplex<CTX> {
+    type SimdBool = bool;
+    type Element = Self;
+    #[inline(always)]
+    fn lanes() -> usize {
+        1
+    }
+    #[inline(always)]
+    fn select(self, cond: Self::SimdBool, other: Self) -> Self {
+        if cond {
+            self
+        } else {
+            other
+        }
+    }
+    #[inline(always)]
+    fn splat(val: Self::Element) -> Self {
+        val
+    }
+    #[inline(always)]
+    fn extract(&self, i: usize) -> Self::Element {
+        if i != 0 {
+            panic!("Invalid lane");
+        }
+        self.clone()
+    }
+    #[inline(always)]
+    fn replace(&mut self, i: usize, val: Self::Element) {
+        if i != 0 {
+            panic!("Invalid lane");
+        }
+        *self = val;
+    }
+    #[inline(always)]
+    unsafe fn replace_unchecked(&mut self, _i: usize, val: Self::Element) {
+        *self = val;
+    }
+    #[inline(always)]
+    unsafe fn extract_unchecked(&self, _i: usize) -> Self::Element {
+        self.clone()
+    }
+}
+
+macro_rules! componentwise {
+    ($name:ident) => {
+        #[inline]
+        fn $name(self) -> Self {
+            BigComplex::new(self.re.$name(), self.im.$name())
+        }
+    };
+}
+
+impl<CTX: BigFloatCtx + 'static> ComplexField for BigComplex<CTX> {
+    type RealField = BigFloat<CTX>;
+
+    #[inline]
+    fn is_finite(&self) -> bool {
+        self.re.is_finite() && self.im.is_finite()
+    }
+
+    // Basic operations
+    #[inline]
+    fn scale(self, factor: Self::RealField) -> Self {
+        self * factor
+    }
+    #[inline]
+    fn unscale(self, factor: Self::RealField) -> Self {
+        self / factor
+    }
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        // Each component is two fused multiply-adds on the real parts, so neither product is
+        // rounded on its own
+        let re = (-&self.im).fma(&a.im, &b.re);
+        let im = self.im.fma(&a.re, &b.im);
+        BigComplex::new(self.re.fma(&a.re, &re), self.re.fma(&a.im, &im))
+    }
+    fn hypot(self, other: Self) -> Self::RealField {
+        (self.norm_sqr() + other.norm_sqr()).sqrt()
+    }
+    fn recip(self) -> Self {
+        let denom = self.norm_sqr();
+        BigComplex::new(self.re / &denom, -self.im / denom)
+    }
+
+    // Imaginary number functions
+    #[inline]
+    fn from_real(re: Self::RealField) -> Self {
+        BigComplex::from(re)
+    }
+    #[inline]
+    fn real(self) -> Self::RealField {
+        self.re
+    }
+    #[inline]
+    fn imaginary(self) -> Self::RealField {
+        self.im
+    }
+    #[inline]
+    fn modulus_squared(self) -> Self::RealField {
+        self.norm_sqr()
+    }
+    #[inline]
+    fn modulus(self) -> Self::RealField {
+        self.re.hypot(self.im)
+    }
+    #[inline]
+    fn abs(self) -> Self::RealField {
+        self.modulus()
+    }
+    fn argument(self) -> Self::RealField {
+        // astro_float does not preserve the sign of zero through arithmetic, so a zero imaginary
+        // part is always treated as positive zero and the negative real axis maps to pi
+        if self.im.is_zero() {
+            self.re.argument()
+        } else {
+            self.im.atan2(self.re)
+        }
+    }
+    #[inline]
+    fn norm1(self) -> Self::RealField {
+        self.re.abs() + self.im.abs()
+    }
+    #[inline]
+    fn conjugate(self) -> Self {
+        BigComplex::new(self.re, -self.im)
+    }
+    fn signum(self) -> Self {
+        if self.is_zero() {
+            self
+        } else {
+            let modulus = self.clone().modulus();
+            self / modulus
+        }
+    }
+
+    // Logarithmic
+    fn ln(self) -> Self {
+        // ln|z| is computed from |z|^2 to avoid rounding the square root
+        let two = BigFloat::from_u8(2).unwrap();
+        let re = self.norm_sqr().ln() / two;
+        BigComplex::new(re, self.argument())
+    }
+    fn log(self, base: Self::RealField) -> Self {
+        self.ln() / base.ln()
+    }
+    fn log2(self) -> Self {
+        self.ln() / BigFloat::ln_2()
+    }
+    fn log10(self) -> Self {
+        self.ln() / BigFloat::ln_10()
+    }
+    fn ln_1p(self) -> Self {
+        let (re, im) = (&self.re.num, &self.im.num);
+        if re.is_inf() || re.is_nan() || im.is_inf() || im.is_nan() {
+            return (self + BigFloat::one()).ln();
+        }
+        // ln|1 + z| = ln_1p(2 re + |z|^2) / 2, where the squares are exact at twice the precision
+        let ln_modulus = run::<CTX, _>(CTX::get_prec(), |w| {
+            let sum = w.add(&w.add(re, re), &w.add(&w.mul(re, re), &w.mul(im, im)));
+            let ln = w.ln_1p(&sum);
+            w.mul(&ln, &w.half())
+        });
+        let argument = BigComplex::new(&self.re + BigFloat::one(), self.im).argument();
+        BigComplex::new(round(ln_modulus), argument)
+    }
+
+    // Exponential
+    fn exp(self) -> Self {
+        let (sin, cos) = self.im.sin_cos();
+        let magnitude = self.re.exp();
+        BigComplex::new(&magnitude * cos, magnitude * sin)
+    }
+    fn exp2(self) -> Self {
+        (self * BigFloat::ln_2()).exp()
+    }
+    fn exp_m1(self) -> Self {
+        let (re, im) = (&self.re.num, &self.im.num);
+        if re.is_inf() || re.is_nan() || im.is_inf() || im.is_nan() {
+            return self.exp() - BigComplex::one();
+        }
+        // e^re cos(im) - 1 = expm1(re) cos(im) - 2 sin(im/2)^2, at twice the precision for the
+        // cancellation between the two terms
+        let real = run::<CTX, _>(CTX::get_prec(), |w| {
+            let exp_m1 = w.exp_m1(re);
+            let sin = w.sin(&w.mul(im, &w.half()));
+            let cos = w.cos(im);
+            w.sub(&w.mul(&exp_m1, &cos), &w.mul(&w.int(2), &w.mul(&sin, &sin)))
+        });
+        let imag = self.re.exp() * self.im.sin();
+        BigComplex::new(round(real), imag)
+    }
+    fn powi(self, n: i32) -> Self {
+        // Exponentiation by squaring keeps integer powers of gaussian integers exact
+        let mut base = self;
+        let mut exp = n.unsigned_abs();
+        let mut out = BigComplex::one();
+        while exp > 0 {
+            if exp & 1 == 1 {
+                out *= &base;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = &base * &base;
+            }
+        }
+        if n < 0 {
+            out.recip()
+        } else {
+            out
+        }
+    }
+    fn powf(self, n: Self::RealField) -> Self {
+        if self.is_zero() {
+            return zero_pow(&n, &BigFloat::zero());
+        }
+        let (r, theta) = self.to_polar();
+        BigComplex::from_polar(r.powf(n.clone()), theta * n)
+    }
+    fn powc(self, n: Self) -> Self {
+        if self.is_zero() {
+            return zero_pow(&n.re, &n.im);
+        }
+        (self.ln() * n).exp()
+    }
+    fn sqrt(self) -> Self {
+        // Uses the formula that avoids cancellation so that results such as sqrt(-4) = 2i are exact
+        if self.is_zero() {
+            return self;
+        }
+        let two = BigFloat::from_u8(2).unwrap();
+        let modulus = self.clone().modulus();
+        let t = ((self.re.clone().abs() + modulus) / &two).sqrt();
+        if self.re.is_sign_negative() {
+            // A zero imaginary part is treated as positive zero, see argument()
+            let re = self.im.clone().abs() / (&two * &t);
+            let im = if self.im.is_sign_negative() && !self.im.is_zero() {
+                -t
+            } else {
+                t
+            };
+            BigComplex::new(re, im)
+        } else {
+            let im = self.im / (two * &t);
+            BigComplex::new(t, im)
+        }
+    }
+    #[inline]
+    fn try_sqrt(self) -> Option<Self> {
+        Some(self.sqrt())
+    }
+    fn cbrt(self) -> Self {
+        if self.is_zero() {
+            return self;
+        }
+        let (r, theta) = self.to_polar();
+        BigComplex::from_polar(r.cbrt(), theta / BigFloat::from_u8(3).unwrap())
+    }
+
+    // Rounding
+    componentwise!(floor);
+    componentwise!(ceil);
+    componentwise!(round);
+    componentwise!(trunc);
+    componentwise!(fract);
+
+    // Trigonometric
+    fn sin(self) -> Self {
+        let (sin, cos) = self.re.sin_cos();
+        BigComplex::new(sin * self.im.clone().cosh(), cos * self.im.sinh())
+    }
+    fn cos(self) -> Self {
+        let (sin, cos) = self.re.sin_cos();
+        BigComplex::new(cos * self.im.clone().cosh(), -(sin * self.im.sinh()))
+    }
+    fn sin_cos(self) -> (Self, Self) {
+        let (sin, cos) = self.re.sin_cos();
+        let sinh = self.im.clone().sinh();
+        let cosh = self.im.cosh();
+        (
+            BigComplex::new(&sin * &cosh, &cos * &sinh),
+            BigComplex::new(cos * cosh, -(sin * sinh)),
+        )
+    }
+    fn tan(self) -> Self {
+        // tan(a + bi) = (sin 2a + i sinh 2b) / (cos 2a + cosh 2b)
+        let two_re = self.re * BigFloat::from_u8(2).unwrap();
+        let two_im = self.im * BigFloat::from_u8(2).unwrap();
+        let (sin, cos) = two_re.sin_cos();
+        let denom = cos + two_im.clone().cosh();
+        BigComplex::new(sin / &denom, two_im.sinh() / denom)
+    }
+    fn asin(self) -> Self {
+        // asin(z) = -i ln(iz + sqrt(1 - z^2))
+        let root = (BigComplex::one() - &self * &self).sqrt();
+        (self.mul_i() + root).ln().mul_neg_i()
+    }
+    fn acos(self) -> Self {
+        // acos(z) = -i ln(z + i sqrt(1 - z^2))
+        let root = (BigComplex::one() - &self * &self).sqrt();
+        (self + root.mul_i()).ln().mul_neg_i()
+    }
+    fn atan(self) -> Self {
+        // atan(z) = (ln(1 + iz) - ln(1 - iz)) / 2i
+        let iz = self.mul_i();
+        let diff = (BigComplex::one() + &iz).ln() - (BigComplex::one() - iz).ln();
+        diff.mul_neg_i() / BigFloat::from_u8(2).unwrap()
+    }
+    fn sinh(self) -> Self {
+        let (sin, cos) = self.im.sin_cos();
+        BigComplex::new(self.re.clone().sinh() * cos, self.re.cosh() * sin)
+    }
+    fn cosh(self) -> Self {
+        let (sin, cos) = self.im.sin_cos();
+        BigComplex::new(self.re.clone().cosh() * cos, self.re.sinh() * sin)
+    }
+    fn tanh(self) -> Self {
+        // tanh(a + bi) = (sinh 2a + i sin 2b) / (cosh 2a + cos 2b)
+        let two_re = self.re * BigFloat::from_u8(2).unwrap();
+        let two_im = self.im * BigFloat::from_u8(2).unwrap();
+        let (sin, cos) = two_im.sin_cos();
+        let denom = two_re.clone().cosh() + cos;
+        BigComplex::new(two_re.sinh() / &denom, sin / denom)
+    }
+    fn asinh(self) -> Self {
+        // asinh(z) = ln(z + sqrt(1 + z^2))
+        let root = (BigComplex::one() + &self * &self).sqrt();
+        (self + root).ln()
+    }
+    fn acosh(self) -> Self {
+        // acosh(z) = 2 ln(sqrt((z + 1) / 2) + sqrt((z - 1) / 2))
+        let two = BigFloat::from_u8(2).unwrap();
+        let plus = ((self.clone() + BigFloat::one()) / &two).sqrt();
+        let minus = ((self - BigFloat::one()) / &two).sqrt();
+        (plus + minus).ln() * two
+    }
+    fn atanh(self) -> Self {
+        // atanh(z) = (ln(1 + z) - ln(1 - z)) / 2
+        let diff = (BigComplex::one() + &self).ln() - (BigComplex::one() - self).ln();
+        diff / BigFloat::from_u8(2).unwrap()
+    }
+}
+
+impl<CTX: BigFloatCtx + 'static> AbsDiffEq<Self> for BigComplex<CTX> {
+    type Epsilon = BigFloat<CTX>;
+    fn default_epsilon() -> Self::Epsilon {
+        BigFloat::default_epsilon()
+    }
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.re.abs_diff_eq(&other.re, epsilon.clone()) && self.im.abs_diff_eq(&other.im, epsilon)
+    }
+}
+
+impl<CTX: BigFloatCtx + 'static> RelativeEq<Self> for BigComplex<CTX> {
+    fn default_max_relative() -> Self::Epsilon {
+        BigFloat::default_max_relative()
+    }
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        if self == other {
+            return true;
+        }
+        let abs_diff = (self - other).modulus();
+        if abs_diff <= epsilon {
+            return true;
+        }
+        let largest = self.clone().modulus().max(other.clone().modulus());
+        abs_diff <= largest * max_relative
+    }
+}
+
+impl<CTX: BigFloatCtx + 'static> UlpsEq for BigComplex<CTX> {
+    fn default_max_ulps() -> u32 {
+        4
+    }
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, _max_ulps: u32) -> bool {
+        self.abs_diff_eq(other, epsilon)
+    }
+}
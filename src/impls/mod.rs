@@ -1,4 +1,15 @@
 pub(crate) mod approx;
+pub(crate) mod complex;
 pub(crate) mod nalgebra;
+#[cfg(feature = "num-bigint")]
+pub(crate) mod num_bigint;
 pub(crate) mod num_traits;
+/// Serde implementations for [`BigFloat`](crate::BigFloat) and modules for
+/// `#[serde(with = "...")]` that pick its representation.
+///
+/// By default, human-readable formats such as JSON use the shortest decimal string and binary
+/// formats use the [`compact`](self::serde::compact) tuple. Deserialized values are rounded to
+/// the precision and rounding mode of the context.
+#[cfg(feature = "serde")]
+pub mod serde;
 pub(crate) mod simba;
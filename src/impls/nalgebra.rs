@@ -1,3 +1,4 @@
+use crate::special::{round, run};
 use crate::{BigFloat, BigFloatCtx};
 use nalgebra::ComplexField;
 use nalgebra::Field;
@@ -173,7 +174,7 @@ impl<CTX: BigFloatCtx + 'static> ComplexField for BigFloat<CTX> {
     }
     #[inline(always)]
     fn mul_add(self, a: Self, b: Self) -> Self {
-        self * a + b
+        self.fma(&a, &b)
     }
     fn hypot(self, other: Self) -> Self::RealField {
         let p = CTX::get_prec();
@@ -217,14 +218,15 @@ impl<CTX: BigFloatCtx + 'static> ComplexField for BigFloat<CTX> {
     fn log(self, base: Self::RealField) -> Self {
         self.ln() / base.ln()
     }
-    unary!(ln_1p, self -> (self + Self::one()).ln());
+    fn ln_1p(self) -> Self {
+        round(run::<CTX, _>(0, |w| w.ln_1p(&self.num)))
+    }
 
     // Exponential
     unary!(exp);
     unary!(exp2, self -> Self::from_f64(2.).unwrap().powf(self));
-    #[inline(always)]
     fn exp_m1(self) -> Self {
-        self.exp() - Self::one()
+        round(run::<CTX, _>(0, |w| w.exp_m1(&self.num)))
     }
     fn powi(self, n: i32) -> Self {
         if n >= 0 {
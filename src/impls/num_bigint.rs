@@ -0,0 +1,166 @@
+// This file contains conversions between BigFloat and the arbitrary precision integer and
+// rational types of num_bigint and num_rational. It is only compiled with the num-bigint feature.
+extern crate alloc;
+use alloc::vec::Vec;
+
+use crate::{BigFloat, BigFloatConversionError, BigFloatCtx};
+use astro_float::{Exponent, Sign, Word, EXPONENT_MAX};
+use num_bigint::{BigInt, BigUint};
+use num_rational::BigRational;
+use num_traits::{One, Zero};
+
+const WORD_BITS: u64 = Word::BITS as u64;
+
+/// Converts an integer into an astro_float BigFloat without rounding.
+fn exact_from_biguint(int: &BigUint, sign: Sign) -> astro_float::BigFloat {
+    if int.is_zero() {
+        return astro_float::BigFloat::from_word(0, WORD_BITS as usize);
+    }
+    // Pack the 32 bit digits into words, least significant first
+    let words: Vec<Word> = int
+        .to_u32_digits()
+        .chunks((WORD_BITS / 32) as usize)
+        .map(|chunk| {
+            chunk
+                .iter()
+                .rev()
+                .fold(0u128, |acc, &digit| (acc << 32) | digit as u128) as Word
+        })
+        .collect();
+    // value = 0.m * 2^exp
+    let exp = words.len() as u64 * WORD_BITS;
+    if exp > EXPONENT_MAX as u64 {
+        return match sign {
+            Sign::Pos => astro_float::INF_POS,
+            Sign::Neg => astro_float::INF_NEG,
+        };
+    }
+    astro_float::BigFloat::from_words(&words, sign, exp as Exponent)
+}
+
+/// Returns the mantissa of a finite number as an integer and the power of two it is scaled by.
+fn mantissa_and_exponent(num: &astro_float::BigFloat) -> (BigInt, i64) {
+    let (mantissa, _, sign, exp, _) = match num.as_raw_parts() {
+        Some(parts) => parts,
+        None => return (BigInt::zero(), 0),
+    };
+    let digits: Vec<u32> = mantissa
+        .iter()
+        .flat_map(|&word| (0..WORD_BITS / 32).map(move |i| (word as u128 >> (32 * i)) as u32))
+        .collect();
+    let int = BigInt::from_biguint(
+        match sign {
+            Sign::Pos => num_bigint::Sign::Plus,
+            Sign::Neg => num_bigint::Sign::Minus,
+        },
+        BigUint::new(digits),
+    );
+    (int, exp as i64 - (mantissa.len() as u64 * WORD_BITS) as i64)
+}
+
+/// Rounds to the precision and rounding mode of the context.
+impl<CTX: BigFloatCtx> From<&BigInt> for BigFloat<CTX> {
+    fn from(int: &BigInt) -> Self {
+        let sign = match int.sign() {
+            num_bigint::Sign::Minus => Sign::Neg,
+            _ => Sign::Pos,
+        };
+        let mut num = exact_from_biguint(int.magnitude(), sign);
+        if let Err(err) = num.set_precision(CTX::get_prec(), CTX::get_rm()) {
+            num = astro_float::BigFloat::nan(Some(err));
+        }
+        BigFloat::from(num)
+    }
+}
+
+/// Rounds to the precision and rounding mode of the context.
+impl<CTX: BigFloatCtx> From<BigInt> for BigFloat<CTX> {
+    #[inline]
+    fn from(int: BigInt) -> Self {
+        Self::from(&int)
+    }
+}
+
+/// Fails if the value is `NaN`, infinite, or has a fractional part.
+impl<CTX: BigFloatCtx> TryFrom<&BigFloat<CTX>> for BigInt {
+    type Error = BigFloatConversionError;
+    fn try_from(x: &BigFloat<CTX>) -> Result<Self, Self::Error> {
+        if x.num.is_nan() {
+            return Err(BigFloatConversionError::NaN);
+        }
+        if x.num.is_inf() {
+            return Err(BigFloatConversionError::Infinite);
+        }
+        if !x.num.is_int() {
+            return Err(BigFloatConversionError::NotInteger);
+        }
+        let (int, exp) = mantissa_and_exponent(&x.num);
+        if exp >= 0 {
+            Ok(int << exp as usize)
+        } else {
+            // The shifted out bits are zero because the value is an integer
+            Ok(int >> (-exp) as usize)
+        }
+    }
+}
+
+/// Fails if the value is `NaN`, infinite, or has a fractional part.
+impl<CTX: BigFloatCtx> TryFrom<BigFloat<CTX>> for BigInt {
+    type Error = BigFloatConversionError;
+    #[inline]
+    fn try_from(x: BigFloat<CTX>) -> Result<Self, Self::Error> {
+        Self::try_from(&x)
+    }
+}
+
+/// Rounds the quotient to the precision and rounding mode of the context, so the result is the
+/// nearest BigFloat when the rounding mode is `ToEven` or `ToOdd`.
+impl<CTX: BigFloatCtx> From<&BigRational> for BigFloat<CTX> {
+    fn from(ratio: &BigRational) -> Self {
+        let sign = match ratio.numer().sign() == ratio.denom().sign() {
+            true => Sign::Pos,
+            false => Sign::Neg,
+        };
+        let numer = exact_from_biguint(ratio.numer().magnitude(), sign);
+        let denom = exact_from_biguint(ratio.denom().magnitude(), Sign::Pos);
+        // Both operands are exact, so the division is rounded only once
+        BigFloat::from(numer.div(&denom, CTX::get_prec(), CTX::get_rm()))
+    }
+}
+
+/// Rounds the quotient to the precision and rounding mode of the context, so the result is the
+/// nearest BigFloat when the rounding mode is `ToEven` or `ToOdd`.
+impl<CTX: BigFloatCtx> From<BigRational> for BigFloat<CTX> {
+    #[inline]
+    fn from(ratio: BigRational) -> Self {
+        Self::from(&ratio)
+    }
+}
+
+/// Exact conversion, every finite BigFloat is a rational number with a power of two denominator.
+impl<CTX: BigFloatCtx> TryFrom<&BigFloat<CTX>> for BigRational {
+    type Error = BigFloatConversionError;
+    fn try_from(x: &BigFloat<CTX>) -> Result<Self, Self::Error> {
+        if x.num.is_nan() {
+            return Err(BigFloatConversionError::NaN);
+        }
+        if x.num.is_inf() {
+            return Err(BigFloatConversionError::Infinite);
+        }
+        let (int, exp) = mantissa_and_exponent(&x.num);
+        if exp >= 0 {
+            Ok(BigRational::from_integer(int << exp as usize))
+        } else {
+            Ok(BigRational::new(int, BigInt::one() << (-exp) as usize))
+        }
+    }
+}
+
+/// Exact conversion, every finite BigFloat is a rational number with a power of two denominator.
+impl<CTX: BigFloatCtx> TryFrom<BigFloat<CTX>> for BigRational {
+    type Error = BigFloatConversionError;
+    #[inline]
+    fn try_from(x: BigFloat<CTX>) -> Result<Self, Self::Error> {
+        Self::try_from(&x)
+    }
+}
@@ -1,13 +1,21 @@
 // This file contains implementations for num_traits methods such as the FromPrimitive trait, One
 // trait, Zero trait, and Num trait (from_str_radix).
+//
+// num_traits::Float and num_traits::real::Real cannot be implemented because they require Copy,
+// which is impossible for a heap allocated mantissa. The remaining float related traits
+// (FloatConst, Bounded, ToPrimitive, NumCast, Inv, Pow, MulAdd, Euclid) are implemented below.
 use core::str::FromStr;
 
-use crate::{BigFloat, BigFloatCtx};
-use astro_float::Radix;
+use crate::cast::exact_f64;
+use crate::fmt::exact_add;
+use crate::parse::{self, Parsed};
+use crate::{BigFloat, BigFloatCtx, ParseBigFloatError};
+use astro_float::Sign;
 use astro_float::{self};
-use nalgebra::RealField;
+use nalgebra::{ComplexField, RealField};
 use num_traits::FromPrimitive;
 use num_traits::Num;
+use num_traits::{Bounded, Euclid, FloatConst, Inv, MulAdd, MulAddAssign, Pow, ToPrimitive};
 use num_traits::{One, Zero};
 
 macro_rules! from_prim {
@@ -21,9 +29,21 @@ macro_rules! from_prim {
     };
 }
 
+/// Integers are converted at the precision of the context, and floats are rounded to the
+/// precision and rounding mode of the context. See [`BigFloat::from_f64_exact`] for a conversion
+/// without rounding.
 impl<CTX: BigFloatCtx> FromPrimitive for BigFloat<CTX> {
-    from_prim!(from_f64, f64);
-    from_prim!(from_f32, f32);
+    fn from_f64(prim: f64) -> Option<Self> {
+        let mut num = exact_f64(prim);
+        if let Err(err) = num.set_precision(CTX::get_prec(), CTX::get_rm()) {
+            num = astro_float::BigFloat::nan(Some(err));
+        }
+        Some(Self::from(num))
+    }
+    #[inline]
+    fn from_f32(prim: f32) -> Option<Self> {
+        Self::from_f64(prim as f64)
+    }
 
     from_prim!(from_u8, u8);
     from_prim!(from_i8, i8);
@@ -61,37 +81,42 @@ impl<CTX: BigFloatCtx> One for BigFloat<CTX> {
     }
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-pub enum ParseBigFloatError {
-    InvalidRadix,
-    InvalidNumber,
-}
-
 impl<CTX: BigFloatCtx> Num for BigFloat<CTX> {
     type FromStrRadixErr = ParseBigFloatError;
+    /// Parses a number in any radix from 2 to 36, rounded to the precision and rounding mode of
+    /// the context.
+    ///
+    /// Numbers are written as `[+-]digits[.digits]` with an optional exponent, which gives the
+    /// power of the radix the number is multiplied by. The exponent is separated by `@` and
+    /// written in decimal, which is the format of [`BigFloat::to_string_radix`] in radices other
+    /// than 10. For radices up
+    /// to 10 the exponent can also be separated by `e`. In radix 2, 8 and 16 this exponent is
+    /// written in the radix of the number as in astro_float, so that `1e10` is 4 in radix 2,
+    /// and radix 16 separates it by `_e` because `e` is a digit.
+    ///
+    /// In radix 10 and 16, C99 hexadecimal float literals such as `0x1.921fb54442d18p+1` are
+    /// accepted as well, where the optional exponent after `p` is a decimal power of two.
+    ///
+    /// `NaN`, `Inf` and `Infinity` are accepted in any case and radix, even where they could be
+    /// read as digits. See [`ParseBigFloatError`] for the errors.
     fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
-        let radix_enum = match radix {
-            2 => Radix::Bin,
-            8 => Radix::Oct,
-            10 => Radix::Dec,
-            16 => Radix::Hex,
-            _ => return Err(ParseBigFloatError::InvalidRadix),
-        };
-
-        CTX::run(|ctx| {
-            let value = astro_float::BigFloat::parse(
-                str,
-                radix_enum,
-                ctx.precision(),
-                ctx.rounding_mode(),
-                ctx.consts(),
-            );
-            if value.is_nan() {
-                Err(ParseBigFloatError::InvalidNumber)
-            } else {
-                Ok(BigFloat::from(value))
-            }
-        })
+        match parse::check(str, radix)? {
+            Parsed::NaN => Ok(BigFloat::from(astro_float::BigFloat::nan(None))),
+            Parsed::Inf(Sign::Pos) => Ok(BigFloat::from(astro_float::INF_POS)),
+            Parsed::Inf(Sign::Neg) => Ok(BigFloat::from(astro_float::INF_NEG)),
+            Parsed::Astro(radix) => CTX::run(|ctx| {
+                Ok(BigFloat::from(astro_float::BigFloat::parse(
+                    str,
+                    radix,
+                    ctx.precision(),
+                    ctx.rounding_mode(),
+                    ctx.consts(),
+                )))
+            }),
+            Parsed::Number(number) => Ok(BigFloat::from(
+                number.to_float(CTX::get_prec(), CTX::get_rm()),
+            )),
+        }
     }
 }
 
@@ -126,6 +151,205 @@ impl<CTX: BigFloatCtx + 'static> num_traits::Signed for BigFloat<CTX> {
         self.num.is_negative()
     }
     fn abs_sub(&self, other: &Self) -> Self {
-        (self.clone() - other.clone()).max(Self::zero())
+        (self - other).max(Self::zero())
+    }
+}
+
+macro_rules! float_const {
+    ($name:ident, $func:ident) => {
+        #[inline]
+        fn $name() -> Self {
+            <Self as RealField>::$func()
+        }
+    };
+    ($name:ident, $selfname:ident -> $val:expr) => {
+        #[inline]
+        fn $name() -> Self {
+            $val
+        }
+    };
+}
+
+#[allow(non_snake_case)]
+impl<CTX: BigFloatCtx + 'static> FloatConst for BigFloat<CTX> {
+    float_const!(E, e);
+    float_const!(FRAC_1_PI, frac_1_pi);
+    float_const!(FRAC_1_SQRT_2, self -> Self::one() / Self::from_u8(2).unwrap().sqrt());
+    float_const!(FRAC_2_PI, frac_2_pi);
+    float_const!(FRAC_2_SQRT_PI, frac_2_sqrt_pi);
+    float_const!(FRAC_PI_2, frac_pi_2);
+    float_const!(FRAC_PI_3, frac_pi_3);
+    float_const!(FRAC_PI_4, frac_pi_4);
+    float_const!(FRAC_PI_6, frac_pi_6);
+    float_const!(FRAC_PI_8, frac_pi_8);
+    float_const!(LN_10, ln_10);
+    float_const!(LN_2, ln_2);
+    float_const!(LOG10_E, log10_e);
+    float_const!(LOG2_E, log2_e);
+    float_const!(PI, pi);
+    float_const!(SQRT_2, self -> Self::from_u8(2).unwrap().sqrt());
+    float_const!(TAU, two_pi);
+    float_const!(LOG10_2, self -> Self::ln_2() / Self::ln_10());
+    float_const!(LOG2_10, self -> Self::ln_10() / Self::ln_2());
+}
+
+impl<CTX: BigFloatCtx> Bounded for BigFloat<CTX> {
+    #[inline]
+    fn min_value() -> Self {
+        Self::from(astro_float::BigFloat::min_value(CTX::get_prec()))
+    }
+    #[inline]
+    fn max_value() -> Self {
+        Self::from(astro_float::BigFloat::max_value(CTX::get_prec()))
+    }
+}
+
+// Like the primitive floats, values are truncated towards zero before the range check
+macro_rules! to_prim_int {
+    ($name:ident, $type:ty, signed) => {
+        #[inline]
+        fn $name(&self) -> Option<$type> {
+            self.to_i128().and_then(|val| <$type>::try_from(val).ok())
+        }
+    };
+    ($name:ident, $type:ty, unsigned) => {
+        #[inline]
+        fn $name(&self) -> Option<$type> {
+            self.to_u128().and_then(|val| <$type>::try_from(val).ok())
+        }
+    };
+}
+
+impl<CTX: BigFloatCtx> ToPrimitive for BigFloat<CTX> {
+    to_prim_int!(to_i8, i8, signed);
+    to_prim_int!(to_i16, i16, signed);
+    to_prim_int!(to_i32, i32, signed);
+    to_prim_int!(to_i64, i64, signed);
+    to_prim_int!(to_isize, isize, signed);
+    to_prim_int!(to_u8, u8, unsigned);
+    to_prim_int!(to_u16, u16, unsigned);
+    to_prim_int!(to_u32, u32, unsigned);
+    to_prim_int!(to_u64, u64, unsigned);
+    to_prim_int!(to_usize, usize, unsigned);
+    fn to_i128(&self) -> Option<i128> {
+        match self.as_int()? {
+            (Sign::Neg, magnitude) if magnitude == i128::MIN.unsigned_abs() => Some(i128::MIN),
+            (Sign::Neg, magnitude) => i128::try_from(magnitude).ok().map(|val| -val),
+            (Sign::Pos, magnitude) => i128::try_from(magnitude).ok(),
+        }
+    }
+    fn to_u128(&self) -> Option<u128> {
+        match self.as_int()? {
+            (Sign::Neg, 0) => Some(0),
+            (Sign::Neg, _) => None,
+            (Sign::Pos, magnitude) => Some(magnitude),
+        }
+    }
+    #[inline]
+    fn to_f64(&self) -> Option<f64> {
+        Some(self.as_f64())
+    }
+    #[inline]
+    fn to_f32(&self) -> Option<f32> {
+        Some(self.to_f32_round(astro_float::RoundingMode::ToEven))
+    }
+}
+
+// NumCast is not imported because its `from` method would be ambiguous with `From::from`
+impl<CTX: BigFloatCtx> num_traits::NumCast for BigFloat<CTX> {
+    fn from<T: ToPrimitive>(n: T) -> Option<Self> {
+        // Integers are converted directly so that values above 2^53 are not rounded through f64
+        match n.to_f64() {
+            Some(float) if !float.is_finite() || float.fract() != 0.0 => Self::from_f64(float),
+            float => n
+                .to_i128()
+                .and_then(Self::from_i128)
+                .or_else(|| n.to_u128().and_then(Self::from_u128))
+                .or_else(|| float.and_then(Self::from_f64)),
+        }
+    }
+}
+
+impl<CTX: BigFloatCtx> Inv for BigFloat<CTX> {
+    type Output = Self;
+    #[inline]
+    fn inv(self) -> Self {
+        Self::from(self.num.reciprocal(CTX::get_prec(), CTX::get_rm()))
+    }
+}
+
+impl<CTX: BigFloatCtx> Inv for &BigFloat<CTX> {
+    type Output = BigFloat<CTX>;
+    #[inline]
+    fn inv(self) -> BigFloat<CTX> {
+        BigFloat::from(self.num.reciprocal(CTX::get_prec(), CTX::get_rm()))
+    }
+}
+
+impl<CTX: BigFloatCtx + 'static> Pow<BigFloat<CTX>> for BigFloat<CTX> {
+    type Output = Self;
+    #[inline]
+    fn pow(self, rhs: Self) -> Self {
+        self.powf(rhs)
+    }
+}
+
+impl<CTX: BigFloatCtx + 'static> Pow<&BigFloat<CTX>> for BigFloat<CTX> {
+    type Output = Self;
+    #[inline]
+    fn pow(self, rhs: &Self) -> Self {
+        self.powf(rhs.clone())
+    }
+}
+
+impl<CTX: BigFloatCtx + 'static> Pow<i32> for BigFloat<CTX> {
+    type Output = Self;
+    #[inline]
+    fn pow(self, rhs: i32) -> Self {
+        self.powi(rhs)
+    }
+}
+
+impl<CTX: BigFloatCtx> Pow<u32> for BigFloat<CTX> {
+    type Output = Self;
+    #[inline]
+    fn pow(self, rhs: u32) -> Self {
+        Self::from(self.num.powi(rhs as usize, CTX::get_prec(), CTX::get_rm()))
+    }
+}
+
+impl<CTX: BigFloatCtx> MulAdd for BigFloat<CTX> {
+    type Output = Self;
+    #[inline]
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        self.fma(&a, &b)
+    }
+}
+
+impl<CTX: BigFloatCtx> MulAddAssign for BigFloat<CTX> {
+    #[inline]
+    fn mul_add_assign(&mut self, a: Self, b: Self) {
+        *self = self.fma(&a, &b);
+    }
+}
+
+impl<CTX: BigFloatCtx + 'static> Euclid for BigFloat<CTX> {
+    fn div_euclid(&self, v: &Self) -> Self {
+        let r = self.rem_euclid(v);
+        if r.num.is_nan() {
+            return (self / v).trunc();
+        }
+        // self - r is exactly q * v, so the only rounding is that of the quotient and q always
+        // agrees with rem_euclid
+        let multiple = exact_add(&self.num, &-r.num);
+        Self::from(multiple.div(&v.num, CTX::get_prec(), CTX::get_rm()))
+    }
+    fn rem_euclid(&self, v: &Self) -> Self {
+        let r = self % v;
+        if r.num.is_negative() && !r.num.is_zero() {
+            r + Self::from(v.num.abs())
+        } else {
+            r
+        }
     }
 }
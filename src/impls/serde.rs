@@ -0,0 +1,239 @@
+// This file contains the Serialize and Deserialize implementations for BigFloat and the helper
+// modules for `#[serde(with = ...)]`. It is only compiled with the serde feature.
+//
+// Human-readable formats such as JSON get a string and binary formats get a compact tuple of
+// the sign, exponent and mantissa words. Both are rounded to the precision and rounding mode of
+// the context when deserialized.
+extern crate alloc;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::fmt::{self, Formatter};
+use core::marker::PhantomData;
+
+use crate::{BigFloat, BigFloatCtx};
+use ::serde::de::{self, Deserializer, SeqAccess, Visitor};
+use ::serde::ser::Serializer;
+use ::serde::{Deserialize, Serialize};
+use astro_float::{Exponent, Sign, Word};
+
+/// The compact representation, see [`compact`].
+type Compact = (i8, Exponent, Vec<u64>);
+
+/// Returns the compact representation of a number.
+fn to_compact(num: &astro_float::BigFloat) -> Compact {
+    let sign = match num.is_negative() {
+        true => -1,
+        false => 1,
+    };
+    if num.is_nan() {
+        return (0, 0, Vec::new());
+    }
+    if num.is_inf() {
+        return (sign, 1, Vec::new());
+    }
+    let (mantissa, _, _, exp, _) = match num.as_raw_parts() {
+        Some(parts) if !num.is_zero() => parts,
+        _ => return (sign, 0, Vec::new()),
+    };
+    // Pairs of 32 bit words are packed into 64 bit words, padded with zeros at the least
+    // significant end. Zero words at that end are left out, they do not change the value.
+    let per_word = (u64::BITS / Word::BITS) as usize;
+    let padding = (per_word - mantissa.len() % per_word) % per_word;
+    let words: Vec<Word> = core::iter::repeat_n(0, padding)
+        .chain(mantissa.iter().copied())
+        .collect();
+    let words: Vec<u64> = words
+        .chunks(per_word)
+        .map(|chunk| {
+            chunk
+                .iter()
+                .rev()
+                .fold(0u128, |acc, &word| (acc << Word::BITS) | word as u128) as u64
+        })
+        .collect();
+    let low_zeros = words.iter().take_while(|&&word| word == 0).count();
+    (sign, exp, words[low_zeros..].to_vec())
+}
+
+/// Returns the number with the compact representation rounded to the context.
+fn from_compact<CTX: BigFloatCtx, E: de::Error>(
+    (sign, exp, words): Compact,
+) -> Result<BigFloat<CTX>, E> {
+    let sign = match sign {
+        1 => Sign::Pos,
+        -1 => Sign::Neg,
+        0 => return Ok(BigFloat::from(astro_float::BigFloat::nan(None))),
+        _ => {
+            return Err(E::invalid_value(
+                de::Unexpected::Signed(sign as i64),
+                &"a sign of -1, 0 or 1",
+            ))
+        }
+    };
+    if words.is_empty() {
+        return Ok(BigFloat::from(match (exp, sign) {
+            (0, Sign::Pos) => astro_float::BigFloat::from_word(0, CTX::get_prec()),
+            (0, Sign::Neg) => -astro_float::BigFloat::from_word(0, CTX::get_prec()),
+            (_, Sign::Pos) => astro_float::INF_POS,
+            (_, Sign::Neg) => astro_float::INF_NEG,
+        }));
+    }
+    let per_word = (u64::BITS / Word::BITS) as usize;
+    let words: Vec<Word> = words
+        .iter()
+        .flat_map(|&word| {
+            (0..per_word).map(move |i| (word as u128 >> (Word::BITS as usize * i)) as Word)
+        })
+        .collect();
+    let mut num = astro_float::BigFloat::from_words(&words, sign, exp);
+    if let Err(err) = num.set_precision(CTX::get_prec(), CTX::get_rm()) {
+        num = astro_float::BigFloat::nan(Some(err));
+    }
+    Ok(BigFloat::from(num))
+}
+
+/// Reads a number from a string, a primitive number or the compact tuple.
+struct BigFloatVisitor<CTX: BigFloatCtx>(PhantomData<fn() -> CTX>);
+
+impl<'de, CTX: BigFloatCtx> Visitor<'de> for BigFloatVisitor<CTX> {
+    type Value = BigFloat<CTX>;
+
+    fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("a number, a string containing a number or a (sign, exponent, words) tuple")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        v.parse().map_err(E::custom)
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+        self.visit_str(&v.to_string())
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        self.visit_str(&v.to_string())
+    }
+
+    fn visit_i128<E: de::Error>(self, v: i128) -> Result<Self::Value, E> {
+        self.visit_str(&v.to_string())
+    }
+
+    fn visit_u128<E: de::Error>(self, v: u128) -> Result<Self::Value, E> {
+        self.visit_str(&v.to_string())
+    }
+
+    /// Formats like JSON read numbers as f64, so the shortest decimal string of the f64 is
+    /// parsed to get back the number as it was written.
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<Self::Value, E> {
+        self.visit_str(&alloc::format!("{:e}", v))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let sign = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let exp = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        let words = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(2, &self))?;
+        from_compact((sign, exp, words))
+    }
+}
+
+/// Serializes to the shortest decimal string in human-readable formats and to the compact
+/// tuple in binary formats, see the [`decimal`] and [`compact`] modules.
+impl<CTX: BigFloatCtx> Serialize for BigFloat<CTX> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => decimal::serialize(self, serializer),
+            false => compact::serialize(self, serializer),
+        }
+    }
+}
+
+/// Accepts strings, numbers and the compact tuple in human-readable formats and the compact
+/// tuple in binary formats. The value is rounded to the precision and rounding mode of the
+/// context.
+impl<'de, CTX: BigFloatCtx> Deserialize<'de> for BigFloat<CTX> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => deserializer.deserialize_any(BigFloatVisitor(PhantomData)),
+            false => compact::deserialize(deserializer),
+        }
+    }
+}
+
+/// Serializes as the shortest decimal string that parses back to the same number, see
+/// [`BigFloat::to_shortest_string`].
+///
+/// ## Example
+/// ```rust
+/// use astro_nalgebra::{BigFloat, ConstCtx};
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Point {
+///     #[serde(with = "astro_nalgebra::serde::decimal")]
+///     x: BigFloat<ConstCtx<128>>,
+/// }
+/// ```
+pub mod decimal {
+    use super::*;
+
+    pub fn serialize<CTX: BigFloatCtx, S: Serializer>(
+        num: &BigFloat<CTX>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&num.to_shortest_string())
+    }
+
+    pub fn deserialize<'de, CTX: BigFloatCtx, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<BigFloat<CTX>, D::Error> {
+        deserializer.deserialize_str(BigFloatVisitor(PhantomData))
+    }
+}
+
+/// Serializes as an exact C99 hexadecimal float string such as `0x1.8p+3`, which keeps every
+/// bit when read with a different context.
+pub mod hex {
+    use super::*;
+
+    pub fn serialize<CTX: BigFloatCtx, S: Serializer>(
+        num: &BigFloat<CTX>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&alloc::format!("{:#x}", num))
+    }
+
+    pub fn deserialize<'de, CTX: BigFloatCtx, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<BigFloat<CTX>, D::Error> {
+        deserializer.deserialize_str(BigFloatVisitor(PhantomData))
+    }
+}
+
+/// Serializes as a `(sign, exponent, words)` tuple of an `i8`, an `i32` and a sequence of
+/// `u64`, even in human-readable formats.
+///
+/// The value is `0.words * 2^exponent` with the words least significant first, and the sign is
+/// 1 or -1. An empty sequence of words is zero when the exponent is 0 and infinity otherwise,
+/// and a sign of 0 is `NaN`.
+pub mod compact {
+    use super::*;
+
+    pub fn serialize<CTX: BigFloatCtx, S: Serializer>(
+        num: &BigFloat<CTX>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        to_compact(&num.num).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, CTX: BigFloatCtx, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<BigFloat<CTX>, D::Error> {
+        from_compact(Compact::deserialize(deserializer)?)
+    }
+}
@@ -4,28 +4,30 @@ use num_traits::FromPrimitive;
 use simba::scalar::{SubsetOf, SupersetOf};
 use simba::simd::SimdValue;
 
+// Allows casting between contexts, including nalgebra's Matrix::cast
 // Hidden because not important
 #[doc(hidden)]
-impl<CTX: BigFloatCtx> SubsetOf<Self> for BigFloat<CTX> {
+impl<A: BigFloatCtx, B: BigFloatCtx> SubsetOf<BigFloat<B>> for BigFloat<A> {
     #[inline(always)]
-    fn to_superset(&self) -> Self {
-        self.clone()
+    fn to_superset(&self) -> BigFloat<B> {
+        self.cast()
     }
     #[inline(always)]
-    fn from_superset_unchecked(element: &Self) -> Self {
-        element.clone()
+    fn from_superset_unchecked(element: &BigFloat<B>) -> Self {
+        element.cast()
     }
-    #[inline(always)]
-    fn is_in_subset(_element: &Self) -> bool {
-        true
+    #[inline]
+    fn is_in_subset(element: &BigFloat<B>) -> bool {
+        element.num.is_nan() || element.cast::<A>().num == element.num
     }
 }
 // Hidden because not important
 #[doc(hidden)]
 impl<CTX: BigFloatCtx> SupersetOf<f64> for BigFloat<CTX> {
-    #[inline(always)]
+    /// True when the value converts to an f64 without rounding. NaN is in the subset.
+    #[inline]
     fn is_in_subset(&self) -> bool {
-        true
+        self.num.is_nan() || BigFloat::<CTX>::from_f64_exact(self.as_f64()) == *self
     }
     #[inline(always)]
     fn to_subset_unchecked(&self) -> f64 {
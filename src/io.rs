@@ -0,0 +1,499 @@
+// This file contains readers and writers for matrices of BigFloats in the Matrix Market and CSV
+// formats. It is only compiled with the io feature.
+//
+// Entries are parsed with FromStr and written with to_shortest_string or LowerHex, so they are
+// rounded to the context exactly like numbers parsed in code.
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::io::{BufRead, Write};
+
+use crate::{BigFloat, BigFloatCtx, ParseBigFloatError};
+use nalgebra::{DMatrix, Dim, Matrix, RawStorage};
+use num_traits::{One, Zero};
+
+/// How entries are written, see [`write_matrix_market`] and [`write_csv`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EntryFormat {
+    /// The shortest decimal string that parses back to the same number in the same context,
+    /// see [`BigFloat::to_shortest_string`].
+    Decimal,
+    /// An exact C99 hexadecimal float such as `0x1.8p+3`, which keeps every bit in any context.
+    Hex,
+}
+
+/// The layout of a Matrix Market file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MatrixMarketLayout {
+    /// Every entry in column-major order, the `array` format.
+    Dense,
+    /// The row, column and value of each nonzero entry, the `coordinate` format.
+    Coordinate,
+}
+
+/// Error returned when reading a matrix fails, with the line and column where it was found.
+#[derive(Debug)]
+pub struct MatrixReadError {
+    kind: MatrixReadErrorKind,
+    line: usize,
+    column: usize,
+}
+
+/// The reason a matrix could not be read, see [`MatrixReadError::kind`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum MatrixReadErrorKind {
+    /// Reading from the underlying reader failed.
+    Io(std::io::Error),
+    /// The `%%MatrixMarket` header is missing, malformed or describes an unsupported matrix.
+    InvalidHeader,
+    /// The size line of a Matrix Market file is malformed.
+    InvalidSize,
+    /// An entry is not a valid number.
+    InvalidNumber(ParseBigFloatError),
+    /// A row or column index of a coordinate entry is not a number or out of range.
+    InvalidIndex,
+    /// There are too many or too few entries, or the rows of a CSV file have different lengths.
+    WrongEntryCount,
+}
+
+impl MatrixReadError {
+    fn new(kind: MatrixReadErrorKind, line: usize, column: usize) -> Self {
+        Self { kind, line, column }
+    }
+
+    /// Returns the reason reading failed.
+    #[inline]
+    pub fn kind(&self) -> &MatrixReadErrorKind {
+        &self.kind
+    }
+
+    /// Returns the line of the error, starting from 1. This is one past the last line if the
+    /// file ended too early.
+    #[inline]
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Returns the byte column of the error in its line, starting from 1. For an invalid number
+    /// this is the offending character of the entry.
+    #[inline]
+    pub fn column(&self) -> usize {
+        self.column
+    }
+}
+
+impl Display for MatrixReadError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            MatrixReadErrorKind::Io(err) => write!(f, "{}", err)?,
+            MatrixReadErrorKind::InvalidHeader => f.write_str("invalid Matrix Market header")?,
+            MatrixReadErrorKind::InvalidSize => f.write_str("invalid Matrix Market size line")?,
+            MatrixReadErrorKind::InvalidNumber(err) => write!(f, "invalid number: {}", err)?,
+            MatrixReadErrorKind::InvalidIndex => f.write_str("invalid index")?,
+            MatrixReadErrorKind::WrongEntryCount => f.write_str("wrong number of entries")?,
+        }
+        write!(f, " at line {}, column {}", self.line, self.column)
+    }
+}
+
+impl Error for MatrixReadError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match &self.kind {
+            MatrixReadErrorKind::Io(err) => Some(err),
+            MatrixReadErrorKind::InvalidNumber(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// A whitespace or comma separated field with its line and its 1-based byte column.
+struct Token {
+    text: String,
+    line: usize,
+    column: usize,
+}
+
+impl Token {
+    fn error(&self, kind: MatrixReadErrorKind) -> MatrixReadError {
+        MatrixReadError::new(kind, self.line, self.column)
+    }
+
+    fn number<CTX: BigFloatCtx>(&self) -> Result<BigFloat<CTX>, MatrixReadError> {
+        self.text.parse().map_err(|err: ParseBigFloatError| {
+            let column = self.column + err.position();
+            MatrixReadError::new(MatrixReadErrorKind::InvalidNumber(err), self.line, column)
+        })
+    }
+
+    /// Parses a 1-based index that must be at most `len` and returns it 0-based.
+    fn index(&self, len: usize) -> Result<usize, MatrixReadError> {
+        match self.text.parse::<usize>() {
+            Ok(idx) if (1..=len).contains(&idx) => Ok(idx - 1),
+            _ => Err(self.error(MatrixReadErrorKind::InvalidIndex)),
+        }
+    }
+}
+
+/// Splits a line into whitespace separated tokens.
+fn split_whitespace(line: &str, line_number: usize) -> impl Iterator<Item = Token> + '_ {
+    line.split(|ch: char| ch.is_ascii_whitespace())
+        .scan(0, |offset, text| {
+            let column = *offset + 1;
+            *offset += text.len() + 1;
+            Some((column, text))
+        })
+        .filter(|(_, text)| !text.is_empty())
+        .map(move |(column, text)| Token {
+            text: String::from(text),
+            line: line_number,
+            column,
+        })
+}
+
+/// Reads lines with their 1-based line numbers, turning read failures into errors.
+fn numbered_lines<R: BufRead>(
+    reader: R,
+) -> impl Iterator<Item = Result<(usize, String), MatrixReadError>> {
+    reader.lines().enumerate().map(|(idx, line)| {
+        line.map(|line| (idx + 1, line))
+            .map_err(|err| MatrixReadError::new(MatrixReadErrorKind::Io(err), idx + 1, 1))
+    })
+}
+
+/// The symmetry of a Matrix Market file, entries of symmetric matrices are only stored once.
+#[derive(Clone, Copy, PartialEq)]
+enum Symmetry {
+    General,
+    Symmetric,
+    SkewSymmetric,
+}
+
+/// Reads a matrix from a Matrix Market file in the `array` or `coordinate` format.
+///
+/// The field can be `real`, `double`, `integer` or `pattern`, where pattern entries are one,
+/// and the symmetry can be `general`, `symmetric`, `skew-symmetric` or `hermitian`. Entries
+/// are parsed with [`FromStr`](std::str::FromStr), so they can be written in decimal or as
+/// hexadecimal float literals and are rounded to the context.
+///
+/// ## Example
+/// ```rust
+/// use astro_nalgebra::io::read_matrix_market;
+/// use astro_nalgebra::{BigFloat, ConstCtx};
+/// use nalgebra::DMatrix;
+///
+/// let file = "%%MatrixMarket matrix coordinate real symmetric
+/// % A comment
+/// 2 2 2
+/// 1 1 0.1
+/// 2 1 0x1.8p1
+/// ";
+/// let mat: DMatrix<BigFloat<ConstCtx<256>>> = read_matrix_market(file.as_bytes()).unwrap();
+/// assert_eq!(mat[(0, 1)].as_f64(), 3.);
+/// ```
+pub fn read_matrix_market<CTX: BigFloatCtx + 'static, R: BufRead>(
+    reader: R,
+) -> Result<DMatrix<BigFloat<CTX>>, MatrixReadError> {
+    let mut lines = numbered_lines(reader);
+    let (line_number, header) = match lines.next() {
+        Some(line) => line?,
+        None => {
+            return Err(MatrixReadError::new(
+                MatrixReadErrorKind::InvalidHeader,
+                1,
+                1,
+            ))
+        }
+    };
+    let header: Vec<Token> = split_whitespace(&header, line_number).collect();
+    let word = |idx: usize, expected: &[&str]| match header.get(idx) {
+        Some(token)
+            if expected
+                .iter()
+                .any(|word| token.text.eq_ignore_ascii_case(word)) =>
+        {
+            Ok(token.text.to_ascii_lowercase())
+        }
+        Some(token) => Err(token.error(MatrixReadErrorKind::InvalidHeader)),
+        None => Err(MatrixReadError::new(
+            MatrixReadErrorKind::InvalidHeader,
+            line_number,
+            header
+                .last()
+                .map_or(1, |token| token.column + token.text.len()),
+        )),
+    };
+    word(0, &["%%MatrixMarket"])?;
+    word(1, &["matrix"])?;
+    let coordinate = word(2, &["array", "coordinate"])? == "coordinate";
+    let fields: &[&str] = match coordinate {
+        true => &["real", "double", "integer", "pattern"],
+        false => &["real", "double", "integer"],
+    };
+    let pattern = word(3, fields)? == "pattern";
+    let symmetry = match word(4, &["general", "symmetric", "skew-symmetric", "hermitian"])?.as_str()
+    {
+        "general" => Symmetry::General,
+        "skew-symmetric" => Symmetry::SkewSymmetric,
+        _ => Symmetry::Symmetric,
+    };
+    if let Some(token) = header.get(5) {
+        return Err(token.error(MatrixReadErrorKind::InvalidHeader));
+    }
+
+    // The remaining tokens, skipping comments and blank lines
+    let mut last_line = line_number;
+    let mut tokens = Vec::new();
+    for line in lines {
+        let (line_number, line) = line?;
+        last_line = line_number;
+        if !line.trim_start().starts_with('%') {
+            tokens.push(split_whitespace(&line, line_number).collect::<Vec<Token>>());
+        }
+    }
+    let end = MatrixReadError::new(MatrixReadErrorKind::WrongEntryCount, last_line + 1, 1);
+    let mut lines = tokens.into_iter().filter(|tokens| !tokens.is_empty());
+    let size = lines
+        .next()
+        .ok_or_else(|| MatrixReadError::new(MatrixReadErrorKind::InvalidSize, last_line + 1, 1))?;
+    let size_len = if coordinate { 3 } else { 2 };
+    if size.len() != size_len {
+        let token = size.get(size_len).unwrap_or(&size[size.len() - 1]);
+        return Err(token.error(MatrixReadErrorKind::InvalidSize));
+    }
+    let mut dims = [0; 3];
+    for (dim, token) in dims.iter_mut().zip(size.iter()) {
+        *dim = token
+            .text
+            .parse()
+            .map_err(|_| token.error(MatrixReadErrorKind::InvalidSize))?;
+    }
+    let [rows, cols, count] = dims;
+    if symmetry != Symmetry::General && rows != cols {
+        return Err(size[1].error(MatrixReadErrorKind::InvalidSize));
+    }
+    let mut mat = DMatrix::from_element(rows, cols, BigFloat::zero());
+    let mut set = |row: usize, col: usize, val: BigFloat<CTX>| {
+        if row != col {
+            match symmetry {
+                Symmetry::General => {}
+                Symmetry::Symmetric => mat[(col, row)] = val.clone(),
+                Symmetry::SkewSymmetric => mat[(col, row)] = -val.clone(),
+            }
+        }
+        mat[(row, col)] = val;
+    };
+
+    if coordinate {
+        let entry_len = if pattern { 2 } else { 3 };
+        let mut entries = 0;
+        for line in lines {
+            if entries == count || line.len() != entry_len {
+                let token = line.get(entry_len).unwrap_or(&line[0]);
+                return Err(token.error(MatrixReadErrorKind::WrongEntryCount));
+            }
+            let (row, col) = (line[0].index(rows)?, line[1].index(cols)?);
+            let val = match pattern {
+                true => BigFloat::one(),
+                false => line[2].number()?,
+            };
+            set(row, col, val);
+            entries += 1;
+        }
+        if entries != count {
+            return Err(end);
+        }
+    } else {
+        // Column-major order, symmetric matrices only store the lower triangle
+        let positions = (0..cols).flat_map(|col| {
+            let first_row = match symmetry {
+                Symmetry::General => 0,
+                Symmetry::Symmetric => col,
+                Symmetry::SkewSymmetric => col + 1,
+            };
+            (first_row..rows).map(move |row| (row, col))
+        });
+        let mut values = lines.flatten();
+        for (row, col) in positions {
+            let token = values.next().ok_or_else(|| {
+                MatrixReadError::new(MatrixReadErrorKind::WrongEntryCount, last_line + 1, 1)
+            })?;
+            set(row, col, token.number()?);
+        }
+        if let Some(token) = values.next() {
+            return Err(token.error(MatrixReadErrorKind::WrongEntryCount));
+        }
+    }
+    Ok(mat)
+}
+
+/// Writes a matrix as a general real Matrix Market file in the given layout. The coordinate
+/// layout only writes the nonzero entries.
+///
+/// ## Example
+/// ```rust
+/// use astro_nalgebra::io::{write_matrix_market, EntryFormat, MatrixMarketLayout};
+/// use astro_nalgebra::{bmat, BigFloat, ConstCtx};
+/// use nalgebra::Matrix2;
+///
+/// let mat: Matrix2<BigFloat<ConstCtx<256>>> = bmat![1.5, 0; 0, -2];
+/// let mut file = Vec::new();
+/// write_matrix_market(&mut file, &mat, MatrixMarketLayout::Coordinate, EntryFormat::Decimal)
+///     .unwrap();
+/// assert_eq!(
+///     String::from_utf8(file).unwrap(),
+///     "%%MatrixMarket matrix coordinate real general\n2 2 2\n1 1 1.5\n2 2 -2\n"
+/// );
+/// ```
+pub fn write_matrix_market<CTX, R, C, S, W>(
+    mut writer: W,
+    matrix: &Matrix<BigFloat<CTX>, R, C, S>,
+    layout: MatrixMarketLayout,
+    format: EntryFormat,
+) -> std::io::Result<()>
+where
+    CTX: BigFloatCtx + 'static,
+    R: Dim,
+    C: Dim,
+    S: RawStorage<BigFloat<CTX>, R, C>,
+    W: Write,
+{
+    let (rows, cols) = matrix.shape();
+    match layout {
+        MatrixMarketLayout::Dense => {
+            writeln!(writer, "%%MatrixMarket matrix array real general")?;
+            writeln!(writer, "{} {}", rows, cols)?;
+            for col in 0..cols {
+                for row in 0..rows {
+                    writeln!(writer, "{}", entry(&matrix[(row, col)], format))?;
+                }
+            }
+        }
+        MatrixMarketLayout::Coordinate => {
+            let nonzero = matrix.iter().filter(|val| !val.is_zero()).count();
+            writeln!(writer, "%%MatrixMarket matrix coordinate real general")?;
+            writeln!(writer, "{} {} {}", rows, cols, nonzero)?;
+            for col in 0..cols {
+                for row in 0..rows {
+                    let val = &matrix[(row, col)];
+                    if !val.is_zero() {
+                        writeln!(writer, "{} {} {}", row + 1, col + 1, entry(val, format))?;
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Reads a matrix from CSV, with one row per line and entries separated by commas. Entries can
+/// be surrounded by whitespace and double quotes, and blank lines are skipped. Entries are
+/// parsed with [`FromStr`](std::str::FromStr) and rounded to the context.
+///
+/// ## Example
+/// ```rust
+/// use astro_nalgebra::io::read_csv;
+/// use astro_nalgebra::{BigFloat, ConstCtx};
+/// use nalgebra::DMatrix;
+///
+/// let file = "1, 2.5, \"-3\"\n0x1p-1, 1e3, 0\n";
+/// let mat: DMatrix<BigFloat<ConstCtx<256>>> = read_csv(file.as_bytes()).unwrap();
+/// assert_eq!(mat.shape(), (2, 3));
+/// assert_eq!(mat[(1, 0)].as_f64(), 0.5);
+///
+/// let err = read_csv::<ConstCtx<256>, _>("1,2\n3,x\n".as_bytes()).unwrap_err();
+/// assert_eq!((err.line(), err.column()), (2, 3));
+/// ```
+pub fn read_csv<CTX: BigFloatCtx + 'static, R: BufRead>(
+    reader: R,
+) -> Result<DMatrix<BigFloat<CTX>>, MatrixReadError> {
+    let mut rows: Vec<Vec<BigFloat<CTX>>> = Vec::new();
+    for line in numbered_lines(reader) {
+        let (line_number, line) = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let mut column = 1;
+        let mut row = Vec::new();
+        for field in line.split(',') {
+            let leading = field.len() - field.trim_start().len();
+            let mut text = field.trim();
+            let mut start = column + leading;
+            if let Some(quoted) = text
+                .strip_prefix('"')
+                .and_then(|text| text.strip_suffix('"'))
+            {
+                text = quoted;
+                start += 1;
+            }
+            let token = Token {
+                text: String::from(text),
+                line: line_number,
+                column: start,
+            };
+            if rows.first().is_some_and(|first| row.len() == first.len()) {
+                return Err(token.error(MatrixReadErrorKind::WrongEntryCount));
+            }
+            row.push(token.number()?);
+            column += field.len() + 1;
+        }
+        if rows.first().is_some_and(|first| row.len() != first.len()) {
+            return Err(MatrixReadError::new(
+                MatrixReadErrorKind::WrongEntryCount,
+                line_number,
+                line.len() + 1,
+            ));
+        }
+        rows.push(row);
+    }
+    let cols = rows.first().map_or(0, |row| row.len());
+    Ok(DMatrix::from_row_iterator(
+        rows.len(),
+        cols,
+        rows.into_iter().flatten(),
+    ))
+}
+
+/// Writes a matrix as CSV, with one row per line and entries separated by commas.
+///
+/// ## Example
+/// ```rust
+/// use astro_nalgebra::io::{write_csv, EntryFormat};
+/// use astro_nalgebra::{bmat, BigFloat, ConstCtx};
+/// use nalgebra::Matrix2;
+///
+/// let mat: Matrix2<BigFloat<ConstCtx<256>>> = bmat![1.5, 0; 0.1, -2];
+/// let mut file = Vec::new();
+/// write_csv(&mut file, &mat, EntryFormat::Hex).unwrap();
+/// assert_eq!(
+///     String::from_utf8(file).unwrap().lines().next(),
+///     Some("0x1.8p+0,0x0p+0")
+/// );
+/// ```
+pub fn write_csv<CTX, R, C, S, W>(
+    mut writer: W,
+    matrix: &Matrix<BigFloat<CTX>, R, C, S>,
+    format: EntryFormat,
+) -> std::io::Result<()>
+where
+    CTX: BigFloatCtx + 'static,
+    R: Dim,
+    C: Dim,
+    S: RawStorage<BigFloat<CTX>, R, C>,
+    W: Write,
+{
+    for row in 0..matrix.nrows() {
+        let entries: Vec<String> = (0..matrix.ncols())
+            .map(|col| entry(&matrix[(row, col)], format))
+            .collect();
+        writeln!(writer, "{}", entries.join(","))?;
+    }
+    Ok(())
+}
+
+/// Formats an entry for writing.
+fn entry<CTX: BigFloatCtx>(val: &BigFloat<CTX>, format: EntryFormat) -> String {
+    match format {
+        EntryFormat::Decimal => val.to_shortest_string(),
+        EntryFormat::Hex => format!("{:#x}", val),
+    }
+}
@@ -1,17 +1,50 @@
 #![doc = include_str!("../README.md")]
 #![cfg_attr(not(feature = "std"), no_std)]
-use core::fmt::{Binary, Debug, Display, Octal, UpperHex};
+use core::fmt::{Binary, Debug, Octal};
 use core::marker::PhantomData;
 
 mod cast;
+mod complex;
 mod ctx;
+mod fmt;
 mod impls;
+/// Readers and writers for matrices of BigFloats in the Matrix Market and CSV formats, enabled
+/// by the `io` feature.
+///
+/// ## Example
+/// ```rust,no_run
+/// use astro_nalgebra::io::{read_matrix_market, write_csv, EntryFormat};
+/// use astro_nalgebra::{BigFloat, ConstCtx};
+/// use nalgebra::DMatrix;
+/// use std::{fs::File, io::BufReader};
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let file = BufReader::new(File::open("matrix.mtx")?);
+///     let mat: DMatrix<BigFloat<ConstCtx<256>>> = read_matrix_market(file)?;
+///     write_csv(File::create("matrix.csv")?, &mat.transpose(), EntryFormat::Hex)?;
+///     Ok(())
+/// }
+/// ```
+#[cfg(feature = "io")]
+pub mod io;
+mod macros;
 mod ops;
+mod parse;
+/// Special functions of [`BigFloat`]s as free functions, which are also available as methods.
+pub mod special;
 
 // Re-exports
+pub use crate::cast::BigFloatConversionError;
+pub use crate::complex::BigComplex;
 pub use crate::ctx::BigFloatCtx;
 pub use crate::ctx::ConstCtx;
-pub use crate::impls::num_traits::ParseBigFloatError;
+#[cfg(feature = "std")]
+pub use crate::ctx::{ScopedCtx, ScopedCtxGuard};
+#[cfg(feature = "serde")]
+pub use crate::impls::serde;
+#[doc(hidden)]
+pub use crate::macros::{__from_rows, __is_valid_literal, __parse_literal};
+pub use crate::parse::{ParseBigFloatError, ParseBigFloatErrorKind};
 pub use astro_float;
 pub use astro_float::RoundingMode;
 pub use astro_float::Sign;
@@ -30,7 +63,7 @@ pub use num_traits;
 ///
 /// One limitation with this library is that only BigFloats with the same CTX parameter are allowed
 /// to interop. This means that the precision of a computation system cannot be changed without
-/// explicitly casting all variables.
+/// explicitly casting all variables with [`BigFloat::cast`].
 ///
 /// **NOTE:** It is recommended to make a type alias such as the one below.
 ///
@@ -48,7 +81,6 @@ pub use num_traits;
 /// [nalgebra]: https://docs.rs/nalgebra
 /// [num_traits]: https://docs.rs/num_traits
 #[derive(Eq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BigFloat<CTX: BigFloatCtx> {
     pub(crate) num: astro_float::BigFloat,
     pub(crate) _pd: PhantomData<fn() -> CTX>,
@@ -92,7 +124,5 @@ macro_rules! impl_display_wrapper {
 }
 // Wrapper around Display method on BigFloat
 impl_display_wrapper!(Debug);
-impl_display_wrapper!(Display);
 impl_display_wrapper!(Binary);
 impl_display_wrapper!(Octal);
-impl_display_wrapper!(UpperHex);
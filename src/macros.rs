@@ -0,0 +1,221 @@
+// This file contains the bf!, bvec! and bmat! macros for writing BigFloat literals.
+//
+// The macros stringify their input and check it with a const fn, so a malformed literal is a
+// compile error. The checked string is then parsed at run-time with FromStr, which cannot fail.
+extern crate alloc;
+use alloc::string::String;
+
+use crate::{BigFloat, BigFloatCtx};
+use nalgebra::SMatrix;
+
+/// Creates a [`BigFloat`] from a numeric literal, checking its syntax at compile time.
+///
+/// The literal can be an integer or float literal with an optional sign, exponent and
+/// underscores, `inf`, `-inf` or `nan`. Hexadecimal float literals such as
+/// `"0x1.921fb54442d18p+1"` are not Rust tokens, so they are written as string literals. The
+/// value is rounded to the precision and rounding mode of the context, which is inferred from
+/// the surrounding code like [`str::parse`].
+///
+/// ## Example
+/// ```rust
+/// use astro_nalgebra::{bf, BigFloat, ConstCtx};
+///
+/// type BF256 = BigFloat<ConstCtx<256>>;
+///
+/// let x: BF256 = bf!(-1.5e-3);
+/// assert_eq!(x, "-0.0015".parse().unwrap());
+/// let pi: BF256 = bf!("0x1.921fb54442d18p+1");
+/// assert_eq!(pi.as_f64(), std::f64::consts::PI);
+/// let sum: BF256 = bf!(1_000) + bf!(inf);
+/// ```
+///
+/// A malformed literal does not compile:
+/// ```rust,compile_fail
+/// use astro_nalgebra::{bf, BigFloat, ConstCtx};
+///
+/// let x: BigFloat<ConstCtx<256>> = bf!("1.2.3");
+/// ```
+#[macro_export]
+macro_rules! bf {
+    ($($literal:tt)+) => {{
+        const _: () = assert!(
+            $crate::__is_valid_literal(stringify!($($literal)+)),
+            concat!("invalid BigFloat literal: ", stringify!($($literal)+))
+        );
+        $crate::__parse_literal(stringify!($($literal)+))
+    }};
+}
+
+/// Creates a column [`SVector`](nalgebra::SVector) of [`BigFloat`]s from literals, each checked
+/// at compile time like [`bf!`].
+///
+/// ## Example
+/// ```rust
+/// use astro_nalgebra::{bvec, BigFloat, ConstCtx};
+/// use nalgebra::Vector3;
+///
+/// let vec: Vector3<BigFloat<ConstCtx<256>>> = bvec![1, -2.5, "0x1p-3"];
+/// assert_eq!(vec.y.as_f64(), -2.5);
+/// ```
+#[macro_export]
+macro_rules! bvec {
+    ($($element:expr),* $(,)?) => {
+        $crate::nalgebra::SVector::from([$($crate::bf!($element)),*])
+    };
+}
+
+/// Creates an [`SMatrix`](nalgebra::SMatrix) of [`BigFloat`]s from literals, with elements
+/// separated by commas and rows separated by semicolons. Each element is checked at compile
+/// time like [`bf!`].
+///
+/// ## Example
+/// ```rust
+/// use astro_nalgebra::{bmat, BigFloat, ConstCtx};
+/// use nalgebra::Matrix2x3;
+///
+/// let mat: Matrix2x3<BigFloat<ConstCtx<256>>> = bmat![
+///     1, 2, 3;
+///     4, 5.5, -6e2;
+/// ];
+/// assert_eq!(mat[(1, 1)].as_f64(), 5.5);
+/// ```
+#[macro_export]
+macro_rules! bmat {
+    ($($($element:expr),+ $(,)?);+ $(;)?) => {
+        $crate::__from_rows([$([$($crate::bf!($element)),+]),+])
+    };
+}
+
+/// Returns the range of `s` without surrounding whitespace and string quotes.
+const fn literal_bounds(s: &[u8]) -> (usize, usize) {
+    let mut start = 0;
+    let mut end = s.len();
+    while start < end && s[start].is_ascii_whitespace() {
+        start += 1;
+    }
+    while end > start && s[end - 1].is_ascii_whitespace() {
+        end -= 1;
+    }
+    if end - start >= 2 && s[start] == b'"' && s[end - 1] == b'"' {
+        start += 1;
+        end -= 1;
+    }
+    (start, end)
+}
+
+/// Returns true for characters that are left out of the parsed literal. Whitespace is only
+/// accepted where `stringify!` puts it, between the tokens of a sign or `@` exponent.
+const fn is_ignored(ch: u8) -> bool {
+    ch.is_ascii_whitespace() || ch == b'_'
+}
+
+/// Returns the position after the whitespace starting at `pos`.
+const fn skip_whitespace(s: &[u8], mut pos: usize, end: usize) -> usize {
+    while pos < end && s[pos].is_ascii_whitespace() {
+        pos += 1;
+    }
+    pos
+}
+
+/// Returns the position after the digits and underscores starting at `pos` and the number of
+/// digits.
+const fn digits(s: &[u8], mut pos: usize, end: usize, hex: bool) -> (usize, usize) {
+    let mut count = 0;
+    while pos < end {
+        let ch = s[pos];
+        if ch.is_ascii_digit() || (hex && ch.is_ascii_hexdigit()) {
+            count += 1;
+        } else if ch != b'_' {
+            break;
+        }
+        pos += 1;
+    }
+    (pos, count)
+}
+
+/// Returns the position after `word` if it starts at `pos` in any case.
+const fn word(s: &[u8], pos: usize, end: usize, word: &[u8]) -> Option<usize> {
+    let mut idx = 0;
+    while idx < word.len() {
+        if pos + idx >= end || !s[pos + idx].eq_ignore_ascii_case(&word[idx]) {
+            return None;
+        }
+        idx += 1;
+    }
+    Some(pos + idx)
+}
+
+/// Returns the position after an optional sign and the whitespace following it.
+const fn sign(s: &[u8], pos: usize, end: usize) -> usize {
+    match pos < end && (s[pos] == b'+' || s[pos] == b'-') {
+        true => skip_whitespace(s, pos + 1, end),
+        false => pos,
+    }
+}
+
+/// Returns true if the stringified tokens are a literal accepted by [`bf!`].
+#[doc(hidden)]
+pub const fn __is_valid_literal(s: &str) -> bool {
+    let s = s.as_bytes();
+    let (start, end) = literal_bounds(s);
+    let pos = sign(s, start, end);
+    if let Some(after) = word(s, pos, end, b"infinity") {
+        return after == end;
+    }
+    if let Some(after) = word(s, pos, end, b"inf") {
+        return after == end;
+    }
+    if let Some(after) = word(s, pos, end, b"nan") {
+        return after == end;
+    }
+    let (hex, pos) = match word(s, pos, end, b"0x") {
+        Some(after) => (true, after),
+        None => (false, pos),
+    };
+    let (mut pos, int_digits) = digits(s, pos, end, hex);
+    let mut frac_digits = 0;
+    if pos < end && s[pos] == b'.' {
+        (pos, frac_digits) = digits(s, pos + 1, end, hex);
+    }
+    if int_digits + frac_digits == 0 {
+        return false;
+    }
+    let at = skip_whitespace(s, pos, end);
+    let exp_start = if !hex && at < end && s[at] == b'@' {
+        Some(skip_whitespace(s, at + 1, end))
+    } else if pos < end && matches!((hex, s[pos]), (true, b'p' | b'P') | (false, b'e' | b'E')) {
+        Some(pos + 1)
+    } else {
+        None
+    };
+    if let Some(exp_start) = exp_start {
+        let exp_digits;
+        (pos, exp_digits) = digits(s, sign(s, exp_start, end), end, false);
+        if exp_digits == 0 {
+            return false;
+        }
+    }
+    pos == end
+}
+
+/// Parses a literal checked by [`__is_valid_literal`].
+#[doc(hidden)]
+pub fn __parse_literal<CTX: BigFloatCtx>(s: &str) -> BigFloat<CTX> {
+    let (start, end) = literal_bounds(s.as_bytes());
+    let literal: String = s[start..end]
+        .bytes()
+        .filter(|ch| !is_ignored(*ch))
+        .map(char::from)
+        .collect();
+    literal
+        .parse()
+        .expect("BigFloat literal was checked at compile time")
+}
+
+/// Creates a matrix from an array of rows for [`bmat!`].
+#[doc(hidden)]
+pub fn __from_rows<CTX: BigFloatCtx + 'static, const R: usize, const C: usize>(
+    rows: [[BigFloat<CTX>; C]; R],
+) -> SMatrix<BigFloat<CTX>, R, C> {
+    SMatrix::from_row_iterator(rows.into_iter().flatten())
+}
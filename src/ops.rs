@@ -7,6 +7,7 @@ use core::ops::{Div, DivAssign};
 use core::ops::{Mul, MulAssign};
 use core::ops::{Rem, RemAssign};
 use core::ops::{Sub, SubAssign};
+use num_traits::FromPrimitive;
 
 impl<CTX: BigFloatCtx> Neg for BigFloat<CTX> {
     type Output = Self;
@@ -16,50 +17,105 @@ impl<CTX: BigFloatCtx> Neg for BigFloat<CTX> {
     }
 }
 
+impl<CTX: BigFloatCtx> Neg for &BigFloat<CTX> {
+    type Output = BigFloat<CTX>;
+    #[inline]
+    fn neg(self) -> BigFloat<CTX> {
+        BigFloat::from(-&self.num)
+    }
+}
+
+// Implements the operator for every combination of owned and borrowed operands. The underlying
+// astro_float methods only take references, so none of these variants clone the mantissa.
 macro_rules! binary_op {
-    ($name:ident, $func:ident) => {
-        impl<CTX: BigFloatCtx> $name<Self> for BigFloat<CTX> {
-            type Output = Self;
+    ($name:ident, $func:ident $(, $no_prec:ident)?) => {
+        binary_op!(@impl $name, $func, BigFloat<CTX>, BigFloat<CTX> $(, $no_prec)?);
+        binary_op!(@impl $name, $func, BigFloat<CTX>, &BigFloat<CTX> $(, $no_prec)?);
+        binary_op!(@impl $name, $func, &BigFloat<CTX>, BigFloat<CTX> $(, $no_prec)?);
+        binary_op!(@impl $name, $func, &BigFloat<CTX>, &BigFloat<CTX> $(, $no_prec)?);
+    };
+    (@impl $name:ident, $func:ident, $lhs:ty, $rhs:ty) => {
+        impl<CTX: BigFloatCtx> $name<$rhs> for $lhs {
+            type Output = BigFloat<CTX>;
             #[inline]
-            fn $func(self, rhs: Self) -> Self {
-                Self::from(self.num.$func(&rhs.num, CTX::get_prec(), CTX::get_rm()))
+            fn $func(self, rhs: $rhs) -> BigFloat<CTX> {
+                BigFloat::from(self.num.$func(&rhs.num, CTX::get_prec(), CTX::get_rm()))
+            }
+        }
+    };
+    (@impl $name:ident, $func:ident, $lhs:ty, $rhs:ty, no_prec) => {
+        impl<CTX: BigFloatCtx> $name<$rhs> for $lhs {
+            type Output = BigFloat<CTX>;
+            #[inline]
+            fn $func(self, rhs: $rhs) -> BigFloat<CTX> {
+                BigFloat::from(self.num.$func(&rhs.num))
             }
         }
     };
 }
 macro_rules! binary_op_assign {
-    ($name:ident, $func:ident, $calls:ident) => {
-        impl<CTX: BigFloatCtx> $name<Self> for BigFloat<CTX> {
+    ($name:ident, $func:ident, $calls:ident $(, $no_prec:ident)?) => {
+        binary_op_assign!(@impl $name, $func, $calls, Self $(, $no_prec)?);
+        binary_op_assign!(@impl $name, $func, $calls, &Self $(, $no_prec)?);
+    };
+    (@impl $name:ident, $func:ident, $calls:ident, $rhs:ty) => {
+        impl<CTX: BigFloatCtx> $name<$rhs> for BigFloat<CTX> {
             #[inline]
-            fn $func(&mut self, rhs: Self) {
+            fn $func(&mut self, rhs: $rhs) {
                 let out = self.num.$calls(&rhs.num, CTX::get_prec(), CTX::get_rm());
                 self.num = out;
             }
         }
     };
+    (@impl $name:ident, $func:ident, $calls:ident, $rhs:ty, no_prec) => {
+        impl<CTX: BigFloatCtx> $name<$rhs> for BigFloat<CTX> {
+            #[inline]
+            fn $func(&mut self, rhs: $rhs) {
+                self.num = self.num.$calls(&rhs.num);
+            }
+        }
+    };
 }
 
 binary_op!(Add, add);
 binary_op!(Sub, sub);
 binary_op!(Mul, mul);
 binary_op!(Div, div);
+binary_op!(Rem, rem, no_prec);
 binary_op_assign!(AddAssign, add_assign, add);
 binary_op_assign!(SubAssign, sub_assign, sub);
 binary_op_assign!(MulAssign, mul_assign, mul);
 binary_op_assign!(DivAssign, div_assign, div);
+binary_op_assign!(RemAssign, rem_assign, rem, no_prec);
 
-impl<CTX: BigFloatCtx> Rem<Self> for BigFloat<CTX> {
-    type Output = Self;
-    #[inline]
-    fn rem(self, rhs: Self) -> Self {
-        Self::from(self.num.rem(&rhs.num))
+impl<CTX: BigFloatCtx> BigFloat<CTX> {
+    /// Fused multiply-add. Computes `(self * a) + b` with a single rounding at the end.
+    ///
+    /// The product is computed exactly before the addition, so the result is the correctly
+    /// rounded value of `self * a + b` according to the context rounding mode.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use astro_nalgebra::{BigFloat, ConstCtx};
+    ///
+    /// type BF128 = BigFloat<ConstCtx<128>>;
+    ///
+    /// let x: BF128 = "3".parse().unwrap();
+    /// let a: BF128 = "4".parse().unwrap();
+    /// let b: BF128 = "5".parse().unwrap();
+    /// assert_eq!(x.fma(&a, &b), "17".parse().unwrap());
+    /// ```
+    pub fn fma(&self, a: &Self, b: &Self) -> Self {
+        let product = self.num.mul_full_prec(&a.num);
+        Self::from(product.add(&b.num, CTX::get_prec(), CTX::get_rm()))
     }
-}
 
-impl<CTX: BigFloatCtx> RemAssign for BigFloat<CTX> {
-    #[inline]
-    fn rem_assign(&mut self, rhs: Self) {
-        self.num = self.num.rem(&rhs.num);
+    /// Fused multiply-subtract. Computes `(self * a) - b` with a single rounding at the end.
+    ///
+    /// See [`BigFloat::fma`] for details.
+    pub fn fms(&self, a: &Self, b: &Self) -> Self {
+        let product = self.num.mul_full_prec(&a.num);
+        Self::from(product.sub(&b.num, CTX::get_prec(), CTX::get_rm()))
     }
 }
 
@@ -69,3 +125,80 @@ impl<CTX: BigFloatCtx> PartialOrd<Self> for BigFloat<CTX> {
         self.num.partial_cmp(&other.num)
     }
 }
+
+// Arithmetic between a BigFloat and a primitive scalar. The primitive is converted with the
+// FromPrimitive implementation, so floats are rounded to the context before the operation.
+macro_rules! prim_op {
+    ($prim:ty, $from:ident) => {
+        prim_op!(@impl $prim, $from, Add, add, AddAssign, add_assign);
+        prim_op!(@impl $prim, $from, Sub, sub, SubAssign, sub_assign);
+        prim_op!(@impl $prim, $from, Mul, mul, MulAssign, mul_assign);
+        prim_op!(@impl $prim, $from, Div, div, DivAssign, div_assign);
+        prim_op!(@impl $prim, $from, Rem, rem, RemAssign, rem_assign);
+    };
+    (@from $from:ident, $prim:expr) => {
+        <BigFloat<CTX> as FromPrimitive>::$from($prim)
+            .expect("conversion from a primitive cannot fail")
+            .num
+    };
+    (@call rem, $lhs:expr, $rhs:expr) => {
+        $lhs.rem($rhs)
+    };
+    (@call $func:ident, $lhs:expr, $rhs:expr) => {
+        $lhs.$func($rhs, CTX::get_prec(), CTX::get_rm())
+    };
+    (@impl $prim:ty, $from:ident, $name:ident, $func:ident, $name_assign:ident, $func_assign:ident) => {
+        impl<CTX: BigFloatCtx> $name<$prim> for BigFloat<CTX> {
+            type Output = BigFloat<CTX>;
+            #[inline]
+            fn $func(self, rhs: $prim) -> BigFloat<CTX> {
+                let rhs = prim_op!(@from $from, rhs);
+                BigFloat::from(prim_op!(@call $func, self.num, &rhs))
+            }
+        }
+        impl<CTX: BigFloatCtx> $name<$prim> for &BigFloat<CTX> {
+            type Output = BigFloat<CTX>;
+            #[inline]
+            fn $func(self, rhs: $prim) -> BigFloat<CTX> {
+                let rhs = prim_op!(@from $from, rhs);
+                BigFloat::from(prim_op!(@call $func, self.num, &rhs))
+            }
+        }
+        impl<CTX: BigFloatCtx> $name<BigFloat<CTX>> for $prim {
+            type Output = BigFloat<CTX>;
+            #[inline]
+            fn $func(self, rhs: BigFloat<CTX>) -> BigFloat<CTX> {
+                let lhs = prim_op!(@from $from, self);
+                BigFloat::from(prim_op!(@call $func, lhs, &rhs.num))
+            }
+        }
+        impl<CTX: BigFloatCtx> $name<&BigFloat<CTX>> for $prim {
+            type Output = BigFloat<CTX>;
+            #[inline]
+            fn $func(self, rhs: &BigFloat<CTX>) -> BigFloat<CTX> {
+                let lhs = prim_op!(@from $from, self);
+                BigFloat::from(prim_op!(@call $func, lhs, &rhs.num))
+            }
+        }
+        impl<CTX: BigFloatCtx> $name_assign<$prim> for BigFloat<CTX> {
+            #[inline]
+            fn $func_assign(&mut self, rhs: $prim) {
+                let rhs = prim_op!(@from $from, rhs);
+                self.num = prim_op!(@call $func, self.num, &rhs);
+            }
+        }
+    };
+}
+
+prim_op!(f64, from_f64);
+prim_op!(f32, from_f32);
+prim_op!(i8, from_i8);
+prim_op!(i16, from_i16);
+prim_op!(i32, from_i32);
+prim_op!(i64, from_i64);
+prim_op!(i128, from_i128);
+prim_op!(u8, from_u8);
+prim_op!(u16, from_u16);
+prim_op!(u32, from_u32);
+prim_op!(u64, from_u64);
+prim_op!(u128, from_u128);
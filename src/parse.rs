@@ -0,0 +1,330 @@
+// This file contains the string parser used by from_str_radix and FromStr, along with the
+// ParseBigFloatError type.
+//
+// astro_float's parser stops at the first character it does not understand and returns NaN when
+// it cannot read anything, so the syntax is checked here first to report where and why parsing
+// failed. Numbers in astro_float's own syntax are passed on to it. Other radices, `@` exponents
+// and hexadecimal float literals are converted exactly here and rounded once.
+use core::fmt::{self, Display, Formatter};
+
+use crate::cast::WORD_BITS;
+use crate::fmt::{exact_add, exact_mul, pow_radix, word};
+use astro_float::{Radix, RoundingMode, Sign, Word, EXPONENT_MAX, EXPONENT_MIN};
+
+/// Error returned when parsing a [`BigFloat`](crate::BigFloat) or
+/// [`BigComplex`](crate::BigComplex) from a string fails.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseBigFloatError {
+    kind: ParseBigFloatErrorKind,
+    position: usize,
+}
+
+/// The reason a string could not be parsed, see [`ParseBigFloatError::kind`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseBigFloatErrorKind {
+    /// The radix is not in the range from 2 to 36.
+    InvalidRadix,
+    /// The string is empty.
+    Empty,
+    /// A character of the mantissa is not a digit of the radix, or there are no digits at all.
+    InvalidDigit,
+    /// The exponent has no digits or contains a character that is not a digit of the radix.
+    InvalidExponent,
+    /// The number is followed by characters that are not part of it.
+    TrailingCharacters,
+}
+
+impl ParseBigFloatError {
+    #[inline]
+    pub(crate) fn new(kind: ParseBigFloatErrorKind, position: usize) -> Self {
+        Self { kind, position }
+    }
+
+    /// Returns the reason parsing failed.
+    #[inline]
+    pub fn kind(&self) -> &ParseBigFloatErrorKind {
+        &self.kind
+    }
+
+    /// Returns the byte offset of the offending character in the parsed string. This is the
+    /// length of the string if it ended too early, and zero for an invalid radix.
+    #[inline]
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Moves the position of the error by `offset` bytes, used when parsing part of a string.
+    pub(crate) fn offset(self, offset: usize) -> Self {
+        match self.kind {
+            ParseBigFloatErrorKind::InvalidRadix => self,
+            _ => Self::new(self.kind, self.position + offset),
+        }
+    }
+}
+
+impl Display for ParseBigFloatError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            ParseBigFloatErrorKind::InvalidRadix => f.write_str("invalid radix"),
+            ParseBigFloatErrorKind::Empty => f.write_str("cannot parse number from empty string"),
+            ParseBigFloatErrorKind::InvalidDigit => {
+                write!(f, "invalid digit at byte {}", self.position)
+            }
+            ParseBigFloatErrorKind::InvalidExponent => {
+                write!(f, "invalid exponent at byte {}", self.position)
+            }
+            ParseBigFloatErrorKind::TrailingCharacters => {
+                write!(
+                    f,
+                    "unexpected trailing characters at byte {}",
+                    self.position
+                )
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseBigFloatError {}
+
+/// A string that passed the syntax check.
+pub(crate) enum Parsed<'a> {
+    NaN,
+    Inf(Sign),
+    /// A finite number in the syntax of astro_float, which parses it in the given radix.
+    Astro(Radix),
+    /// Any other finite number.
+    Number(Number<'a>),
+}
+
+/// A finite number equal to `mantissa * base^scale`, where the mantissa is the integer written
+/// by the digits of `int` followed by the digits of `frac` in `radix`.
+pub(crate) struct Number<'a> {
+    sign: Sign,
+    int: &'a str,
+    frac: &'a str,
+    radix: u32,
+    base: u32,
+    scale: i64,
+}
+
+impl Number<'_> {
+    /// Returns the number rounded to `prec` bits with `rm`.
+    pub(crate) fn to_float(&self, prec: usize, rm: RoundingMode) -> astro_float::BigFloat {
+        let int = self.int.trim_start_matches('0');
+        let sig_digits = match int.is_empty() {
+            true => self.frac.trim_start_matches('0').len(),
+            false => int.len() + self.frac.len(),
+        };
+        if sig_digits == 0 {
+            return match self.sign {
+                Sign::Pos => word(0),
+                Sign::Neg => -word(0),
+            };
+        }
+        // The mantissa is in [radix^(sig_digits - 1), radix^sig_digits), so numbers far outside
+        // the exponent range are known to overflow or underflow without computing them
+        let log2 = |val: u32| (val as f64).log2();
+        let scale_log2 = self.scale as f64 * log2(self.base);
+        if (sig_digits - 1) as f64 * log2(self.radix) + scale_log2 > EXPONENT_MAX as f64 + 1.0 {
+            return match self.sign {
+                Sign::Pos => astro_float::INF_POS,
+                Sign::Neg => astro_float::INF_NEG,
+            };
+        }
+        let min_log2 = EXPONENT_MIN as f64 - (prec as i64 + WORD_BITS) as f64;
+        if sig_digits as f64 * log2(self.radix) + scale_log2 < min_log2 {
+            return match self.sign {
+                Sign::Pos => word(0),
+                Sign::Neg => -word(0),
+            };
+        }
+        let digits = int.bytes().chain(self.frac.bytes());
+        let mut mantissa = integer_from_digits(digits, self.radix);
+        if self.sign == Sign::Neg {
+            mantissa = -mantissa;
+        }
+        // The mantissa and the power are exact, so the result is rounded only once
+        if self.scale >= 0 {
+            let mut num = exact_mul(&mantissa, &pow_radix(self.base, self.scale as usize));
+            if let Err(err) = num.set_precision(prec, rm) {
+                num = astro_float::BigFloat::nan(Some(err));
+            }
+            num
+        } else {
+            let power = pow_radix(self.base, self.scale.unsigned_abs() as usize);
+            mantissa.div(&power, prec, rm)
+        }
+    }
+}
+
+/// Returns the exact integer written by ASCII `digits` in `radix`.
+fn integer_from_digits(digits: impl Iterator<Item = u8>, radix: u32) -> astro_float::BigFloat {
+    // Digits are accumulated in groups that fit in 32 bits
+    let mut group_len = 1;
+    let mut group_scale = radix as u64;
+    while group_scale * radix as u64 <= u32::MAX as u64 {
+        group_scale *= radix as u64;
+        group_len += 1;
+    }
+    let mut int = word(0);
+    let mut group = 0u64;
+    let mut len = 0;
+    for digit in digits {
+        group = group * radix as u64 + (digit as char).to_digit(radix).unwrap_or(0) as u64;
+        len += 1;
+        if len == group_len {
+            int = exact_add(
+                &exact_mul(&int, &word(group_scale as Word)),
+                &word(group as Word),
+            );
+            group = 0;
+            len = 0;
+        }
+    }
+    if len > 0 {
+        let scale = (radix as u64).pow(len);
+        int = exact_add(&exact_mul(&int, &word(scale as Word)), &word(group as Word));
+    }
+    int
+}
+
+/// Checks that `s` is a number in `radix` and splits it into its parts. The syntax is described
+/// in the documentation of `from_str_radix` in impls/num_traits.rs.
+pub(crate) fn check(s: &str, radix: u32) -> Result<Parsed<'_>, ParseBigFloatError> {
+    use ParseBigFloatErrorKind::*;
+    if !(2..=36).contains(&radix) {
+        return Err(ParseBigFloatError::new(InvalidRadix, 0));
+    }
+    if s.is_empty() {
+        return Err(ParseBigFloatError::new(Empty, 0));
+    }
+    let bytes = s.as_bytes();
+    let mut pos = 0;
+    let mut sign = Sign::Pos;
+    if let Some(&ch @ (b'+' | b'-')) = bytes.first() {
+        pos += 1;
+        if ch == b'-' {
+            sign = Sign::Neg;
+        }
+    }
+    let rest = &s[pos..];
+    let special = |word: &str| {
+        rest.get(..word.len())
+            .is_some_and(|start| start.eq_ignore_ascii_case(word))
+    };
+    let word = if special("infinity") {
+        Some(("infinity", Parsed::Inf(sign)))
+    } else if special("inf") {
+        Some(("inf", Parsed::Inf(sign)))
+    } else if special("nan") {
+        Some(("nan", Parsed::NaN))
+    } else {
+        None
+    };
+    if let Some((word, parsed)) = word {
+        return match pos + word.len() {
+            end if end == s.len() => Ok(parsed),
+            end => Err(ParseBigFloatError::new(TrailingCharacters, end)),
+        };
+    }
+
+    let at = |pos: usize, marker: &[u8]| {
+        bytes
+            .get(pos..pos + marker.len())
+            .is_some_and(|found| found.eq_ignore_ascii_case(marker))
+    };
+    let digits = |pos: &mut usize, radix: u32| {
+        let start = *pos;
+        while *pos < bytes.len() && (bytes[*pos] as char).is_digit(radix) {
+            *pos += 1;
+        }
+        &s[start..*pos]
+    };
+    // Hexadecimal float literals have hexadecimal digits and a decimal power of two exponent
+    let hex = (radix == 10 || radix == 16) && at(pos, b"0x");
+    if hex {
+        pos += 2;
+    }
+    let digit_radix = if hex { 16 } else { radix };
+    let int = digits(&mut pos, digit_radix);
+    let mut frac = "";
+    if bytes.get(pos) == Some(&b'.') {
+        pos += 1;
+        frac = digits(&mut pos, digit_radix);
+    }
+    if int.is_empty() && frac.is_empty() {
+        return Err(ParseBigFloatError::new(InvalidDigit, pos));
+    }
+
+    // astro_float writes the exponent in the radix of the mantissa, `@` is always decimal
+    let astro = matches!(radix, 2 | 8 | 10 | 16) && !hex;
+    let (marker_len, exp_radix) = if hex && at(pos, b"p") {
+        (1, 10)
+    } else if hex {
+        (0, 10)
+    } else if at(pos, b"@") {
+        (1, 10)
+    } else if radix == 16 && at(pos, b"_e") {
+        (2, 16)
+    } else if radix <= 10 && at(pos, b"e") {
+        (1, if astro { radix } else { 10 })
+    } else {
+        (0, 10)
+    };
+    let has_exponent = marker_len > 0;
+    let astro = astro && (!has_exponent || bytes[pos] != b'@');
+    let mut exp = 0i64;
+    if has_exponent {
+        pos += marker_len;
+        let negative = bytes.get(pos) == Some(&b'-');
+        if let Some(b'+' | b'-') = bytes.get(pos) {
+            pos += 1;
+        }
+        let exp_digits = digits(&mut pos, exp_radix);
+        if exp_digits.is_empty() {
+            return Err(ParseBigFloatError::new(InvalidExponent, pos));
+        }
+        for digit in exp_digits.bytes() {
+            let digit = (digit as char).to_digit(exp_radix).unwrap_or(0) as i64;
+            exp = exp.saturating_mul(exp_radix as i64).saturating_add(digit);
+        }
+        if negative {
+            exp = -exp;
+        }
+    }
+    // A character that could continue the number is a bad digit, anything else is trailing
+    match bytes.get(pos) {
+        None => {}
+        Some(&ch) if ch.is_ascii_alphanumeric() || ch == b'.' => {
+            return match has_exponent {
+                true => Err(ParseBigFloatError::new(InvalidExponent, pos)),
+                false => Err(ParseBigFloatError::new(InvalidDigit, pos)),
+            };
+        }
+        Some(_) => return Err(ParseBigFloatError::new(TrailingCharacters, pos)),
+    }
+
+    if astro {
+        let radix = match radix {
+            2 => Radix::Bin,
+            8 => Radix::Oct,
+            16 => Radix::Hex,
+            _ => Radix::Dec,
+        };
+        return Ok(Parsed::Astro(radix));
+    }
+    let (base, scale) = match hex {
+        true => (2, exp.saturating_sub(4 * frac.len() as i64)),
+        false => (radix, exp.saturating_sub(frac.len() as i64)),
+    };
+    Ok(Parsed::Number(Number {
+        sign,
+        int,
+        frac,
+        radix: digit_radix,
+        base,
+        scale,
+    }))
+}
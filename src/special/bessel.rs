@@ -0,0 +1,705 @@
+// This file contains the Bessel functions of real order: J and Y of the first and second kind, the
+// modified Bessel functions I and K, and the Airy functions Ai and Bi, which are Bessel functions
+// of order 1/3.
+//
+// Arguments of at least half the working precision plus the square of the order use the
+// asymptotic Hankel expansions, whose smallest term is about exp(-2x) and so below the working
+// precision. Arguments of at least half the working precision and a quarter of the order step from
+// the orders below two, where those expansions hold: J and I with Miller's backward recurrence and
+// Y and K with the forward one, each in the direction it is stable. Smaller arguments use the power
+// series, with extra bits for the cancellation between its terms, which grow like exp(x). Y and K
+// are combinations of J and I of opposite orders, which are replaced by their limits, the
+// logarithmic series, for integer orders.
+extern crate alloc;
+use alloc::vec::Vec;
+
+use super::gamma::{gamma, polygamma};
+use super::{
+    int_part, is_odd, magnitude_bits, negligible, reduce_half, round, run, Float, Work, RM,
+};
+use crate::fmt::{exact_add, word};
+use crate::{BigFloat, BigFloatCtx};
+use astro_float::Word;
+
+/// Kinds of Bessel functions.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    /// Bessel function of the first kind.
+    J,
+    /// Bessel function of the second kind.
+    Y,
+    /// Modified Bessel function of the first kind.
+    I,
+    /// Modified Bessel function of the second kind.
+    K,
+}
+
+/// Returns true if `x` is large enough for the expansions of [`asymptotic`], which is when it is
+/// at least half the working precision plus `nu^2`.
+fn is_large(w: &Work, nu: &Float, x: &Float) -> bool {
+    let min = w.add(&w.int((w.p / 2) as i64), &w.mul(nu, nu));
+    *x >= min
+}
+
+/// Returns true if `x` is large enough for [`recurrence`], where the asymptotic expansions hold
+/// for the orders below two and the orders up to `|nu|` are at most about `4x` steps away.
+fn is_recurrence(w: &Work, nu: &Float, x: &Float) -> bool {
+    let max = w.add(&w.mul(x, &w.int(4)), &w.int(w.p as i64));
+    is_large(w, &w.int(2), x) && nu.abs() <= max
+}
+
+/// Returns the number of bits lost when power series terms of the size of `exp(x)` cancel to a
+/// result of the size of one, about `x / ln(2)`.
+fn cancelled_bits(x: &Float) -> usize {
+    int_part(x).saturating_add(1).saturating_mul(3) / 2 + 8
+}
+
+/// Returns the number of bits lost when the combinations of opposite orders are divided by
+/// `sin(pi nu)`, for `nu` close to an integer.
+fn near_int_bits(nu: &Float) -> usize {
+    let (_, r) = reduce_half(nu);
+    r.exponent().map_or(0, |exp| (-exp).max(0) as usize)
+}
+
+/// Returns `-x^2 / 4`, or `x^2 / 4` for the modified functions.
+fn quarter_square(w: &Work, x: &Float, modified: bool) -> Float {
+    let h = w.mul(x, &w.half());
+    let q = w.mul(&h, &h);
+    match modified {
+        true => q,
+        false => -q,
+    }
+}
+
+/// Returns `J_nu(x)`, or `I_nu(x)` if `modified`, from the series
+/// `(x/2)^nu sum of (-x^2/4)^k / (k! gamma(nu + k + 1))`, for `nu` that is not a negative
+/// integer.
+fn power_series(w: &mut Work, nu: &Float, x: &Float, modified: bool) -> Float {
+    let h = w.mul(x, &w.half());
+    let q = quarter_square(w, x, modified);
+    let pow = w.pow(&h, nu);
+    let gamma = gamma(w, &w.add(nu, &w.int(1)));
+    let mut term = w.div(&pow, &gamma);
+    let mut sum = term.clone();
+    let mut k = 0;
+    loop {
+        k += 1;
+        let den = w.mul(&w.int(k), &w.add(nu, &w.int(k)));
+        term = w.div(&w.mul(&term, &q), &den);
+        // The terms only decrease once k (nu + k) exceeds x^2 / 4
+        if den.abs() > q.abs() && negligible(&term, &sum, w.p) {
+            return sum;
+        }
+        sum = w.add(&sum, &term);
+    }
+}
+
+/// Returns `J_n(x)`, or `I_n(x)` if `modified`, along with the two other sums of the logarithmic
+/// series of `Y_n(x)` and `K_n(x)` for `n >= 0`:
+/// `(x/2)^-n sum over k < n of (n - k - 1)! / k! (x^2/4)^k` and
+/// `(x/2)^n sum of (digamma(k + 1) + digamma(n + k + 1)) (-x^2/4)^k / (k! (n + k)!)`, with the
+/// signs of `x^2/4` swapped if `modified`.
+fn log_series(w: &mut Work, n: usize, x: &Float, modified: bool) -> (Float, Float, Float) {
+    let h = w.mul(x, &w.half());
+    let q = quarter_square(w, x, modified);
+    let mut finite = w.int(0);
+    let mut coef = (1..n as i64).fold(w.int(1), |acc, i| w.mul(&acc, &w.int(i)));
+    for k in 0..n {
+        finite = w.add(&finite, &coef);
+        if k + 1 < n {
+            let den = w.int(((k + 1) * (n - k - 1)) as i64);
+            coef = w.div(&w.mul(&coef, &-q.clone()), &den);
+        }
+    }
+    let pow = w.powi(&h, n);
+    finite = w.div(&finite, &pow);
+
+    let fact_n = (1..=n as i64).fold(w.int(1), |acc, i| w.mul(&acc, &w.int(i)));
+    let mut term = w.div(&pow, &fact_n);
+    // digamma(1) is minus the Euler-Mascheroni constant
+    let mut psi_k = polygamma(w, 0, &w.int(1));
+    let mut psi_nk = (1..=n as i64).fold(psi_k.clone(), |acc, i| w.add(&acc, &w.recip(&w.int(i))));
+    let mut sum = term.clone();
+    let mut digamma = w.mul(&term, &w.add(&psi_k, &psi_nk));
+    let mut k = 0;
+    loop {
+        k += 1;
+        psi_k = w.add(&psi_k, &w.recip(&w.int(k)));
+        psi_nk = w.add(&psi_nk, &w.recip(&w.int(n as i64 + k)));
+        let den = w.int(k * (n as i64 + k));
+        term = w.div(&w.mul(&term, &q), &den);
+        let digamma_term = w.mul(&term, &w.add(&psi_k, &psi_nk));
+        if den > q.abs() && negligible(&term, &sum, w.p) && negligible(&digamma_term, &digamma, w.p)
+        {
+            return (sum, finite, digamma);
+        }
+        sum = w.add(&sum, &term);
+        digamma = w.add(&digamma, &digamma_term);
+    }
+}
+
+/// Returns `Y_n(x)` for `n >= 0` from the logarithmic series.
+fn y_int(w: &mut Work, n: usize, x: &Float) -> Float {
+    let (j, finite, digamma) = log_series(w, n, x, false);
+    // Y_n(x) = (2 ln(x/2) J_n(x) - finite - digamma) / pi
+    let ln_h = w.ln(&w.mul(x, &w.half()));
+    let log = w.mul(&w.mul(&ln_h, &j), &w.int(2));
+    let pi = w.pi();
+    w.div(&w.sub(&w.sub(&log, &finite), &digamma), &pi)
+}
+
+/// Returns `K_n(x)` for `n >= 0` from the logarithmic series.
+fn k_int(w: &mut Work, n: usize, x: &Float) -> Float {
+    let (i, finite, digamma) = log_series(w, n, x, true);
+    // K_n(x) = finite / 2 + (-1)^n (digamma / 2 - ln(x/2) I_n(x))
+    let ln_h = w.ln(&w.mul(x, &w.half()));
+    let log = w.sub(&w.mul(&digamma, &w.half()), &w.mul(&ln_h, &i));
+    let log = match n % 2 {
+        0 => log,
+        _ => -log,
+    };
+    w.add(&w.mul(&finite, &w.half()), &log)
+}
+
+/// Returns the terms `a_k(nu) / x^k` of the asymptotic expansions up to the first negligible
+/// one, where `a_k(nu) = (4 nu^2 - 1) (4 nu^2 - 9) ... (4 nu^2 - (2k - 1)^2) / (k! 8^k)`.
+fn asymptotic_terms(w: &Work, nu: &Float, x: &Float) -> Vec<Float> {
+    let mu = w.mul(&w.mul(nu, nu), &w.int(4));
+    let eight_x = w.mul(x, &w.int(8));
+    let one = w.int(1);
+    let mut terms = Vec::from([one.clone()]);
+    let mut k = 1;
+    loop {
+        let factor = w.sub(&mu, &w.int((2 * k - 1) * (2 * k - 1)));
+        let den = w.mul(&eight_x, &w.int(k));
+        let term = w.div(&w.mul(&terms[terms.len() - 1], &factor), &den);
+        // Half-integer orders end the expansion with exact zeros
+        if negligible(&term, &one, w.p) {
+            return terms;
+        }
+        terms.push(term);
+        k += 1;
+    }
+}
+
+/// Returns the Bessel function of the given kind for `x` that [`is_large`], from the Hankel
+/// expansions.
+fn asymptotic(w: &mut Work, kind: Kind, nu: &Float, x: &Float) -> Float {
+    let terms = asymptotic_terms(w, nu, x);
+    let pi = w.pi();
+    match kind {
+        Kind::J | Kind::Y => {
+            // P = sum of (-1)^k a_2k / x^2k and Q = sum of (-1)^k a_(2k + 1) / x^(2k + 1)
+            let (mut p, mut q) = (w.int(0), w.int(0));
+            for (k, term) in terms.iter().enumerate() {
+                let term = match k % 4 {
+                    0 | 1 => term.clone(),
+                    _ => -term.clone(),
+                };
+                match k % 2 {
+                    0 => p = w.add(&p, &term),
+                    _ => q = w.add(&q, &term),
+                }
+            }
+            // chi = x - (nu / 2 + 1 / 4) pi
+            let theta = w.add(&w.mul(nu, &w.half()), &w.mul(&w.half(), &w.half()));
+            let (cos_theta, sin_theta) = (w.cos_pi(&theta), w.sin_pi(&theta));
+            let (cos_x, sin_x) = (w.cos(x), w.sin(x));
+            let cos = w.add(&w.mul(&cos_x, &cos_theta), &w.mul(&sin_x, &sin_theta));
+            let sin = w.sub(&w.mul(&sin_x, &cos_theta), &w.mul(&cos_x, &sin_theta));
+            let scale = w.sqrt(&w.div(&w.int(2), &w.mul(&pi, x)));
+            // J_nu(x) = sqrt(2 / (pi x)) (P cos(chi) - Q sin(chi))
+            // Y_nu(x) = sqrt(2 / (pi x)) (P sin(chi) + Q cos(chi))
+            let sum = match kind {
+                Kind::J => w.sub(&w.mul(&p, &cos), &w.mul(&q, &sin)),
+                _ => w.add(&w.mul(&p, &sin), &w.mul(&q, &cos)),
+            };
+            w.mul(&scale, &sum)
+        }
+        Kind::I => {
+            // I_nu(x) = exp(x) / sqrt(2 pi x) sum of (-1)^k a_k / x^k
+            let sum = terms
+                .iter()
+                .enumerate()
+                .fold(w.int(0), |acc, (k, term)| match k % 2 {
+                    0 => w.add(&acc, term),
+                    _ => w.sub(&acc, term),
+                });
+            let exp = w.exp(x);
+            let den = w.sqrt(&w.mul(&w.mul(&pi, x), &w.int(2)));
+            w.div(&w.mul(&exp, &sum), &den)
+        }
+        Kind::K => {
+            // K_nu(x) = sqrt(pi / 2x) exp(-x) sum of a_k / x^k
+            let sum = terms.iter().fold(w.int(0), |acc, term| w.add(&acc, term));
+            let exp = w.exp(&-x.clone());
+            let scale = w.sqrt(&w.div(&pi, &w.mul(x, &w.int(2))));
+            // exp underflows to a zero with the sign of its argument, but K is positive
+            w.mul(&w.mul(&scale, &exp), &sum).abs()
+        }
+    }
+}
+
+/// Returns the Bessel function of the given kind for `x` that [`is_recurrence`] from the three
+/// term recurrence `f_(nu - 1) + f_(nu + 1) = 2 nu / x f_nu`, with the signs of the modified
+/// functions, started from the asymptotic expansions at the orders `alpha` and `alpha + 1` where
+/// `nu = alpha + n`. Negative orders use the reflection formulas.
+fn recurrence(w: &mut Work, kind: Kind, nu: &Float, x: &Float) -> Float {
+    if nu.is_negative() {
+        let pos = nu.abs();
+        let (cos, sin) = (w.cos_pi(&pos), w.sin_pi(&pos));
+        let other = match kind {
+            Kind::J => Kind::Y,
+            Kind::Y => Kind::J,
+            _ => Kind::K,
+        };
+        let value = recurrence(w, kind, &pos, x);
+        let other = recurrence(w, other, &pos, x);
+        return match kind {
+            // J_-nu = cos(pi nu) J_nu - sin(pi nu) Y_nu
+            Kind::J => w.sub(&w.mul(&cos, &value), &w.mul(&sin, &other)),
+            // Y_-nu = sin(pi nu) J_nu + cos(pi nu) Y_nu
+            Kind::Y => w.add(&w.mul(&sin, &other), &w.mul(&cos, &value)),
+            // I_-nu = I_nu + 2 / pi sin(pi nu) K_nu
+            _ => {
+                let pi = w.pi();
+                let k = w.div(&w.mul(&w.mul(&sin, &other), &w.int(2)), &pi);
+                w.add(&value, &k)
+            }
+        };
+    }
+    let n = int_part(nu);
+    let alpha = exact_add(nu, &-word(n as Word));
+    // Rounding errors add up over the steps
+    let extra = (usize::BITS - n.leading_zeros()) as usize + 8;
+    let evaluate = |w: &mut Work| match kind {
+        Kind::J | Kind::I => miller(w, kind, &alpha, n, x),
+        _ => forward(w, kind, &alpha, n, x),
+    };
+    // Close to a zero the result is much smaller than its neighbors, whose size sets the rounding
+    // errors, so it is evaluated again with the bits that cancelled
+    let (value, lost) = w.extended(extra, evaluate);
+    match lost {
+        0 => value,
+        _ => w.extended(extra + lost, |w| evaluate(w).0),
+    }
+}
+
+/// Returns `2 (alpha + k) / x`, the coefficient of the recurrence at order `alpha + k`.
+fn coefficient(w: &Work, alpha: &Float, k: usize, two_over_x: &Float) -> Float {
+    w.mul(&w.add(alpha, &w.int(k as i64)), two_over_x)
+}
+
+/// Returns the number of bits `f_n` is smaller than the larger of its neighbors.
+fn lost_bits(f_n: &Float, neighbors: [&Float; 2]) -> usize {
+    let largest = neighbors.iter().filter_map(|f| f.exponent()).max();
+    match (largest, f_n.exponent()) {
+        (Some(largest), Some(exp)) if largest > exp => (largest - exp) as usize,
+        _ => 0,
+    }
+}
+
+/// Returns `J_(alpha + n)(x)` or `I_(alpha + n)(x)` from Miller's algorithm, which runs the
+/// recurrence backwards from an order where the function is negligible, then scales the result
+/// to the asymptotic values at `alpha` and `alpha + 1`. Also returns the bits lost at order
+/// `alpha + n`.
+fn miller(w: &mut Work, kind: Kind, alpha: &Float, n: usize, x: &Float) -> (Float, usize) {
+    let modified = kind == Kind::I;
+    let two_over_x = w.div(&w.int(2), x);
+    // The start is where the solution growing with the order, started at n, has passed the
+    // working precision, which 64 bits are enough to find
+    let two_over_x_64 = word(2).div(x, 64, RM);
+    let (mut prev, mut cur) = (word(0), word(1));
+    let mut start = n + 1;
+    while cur.exponent().unwrap_or(0) < (w.p + 16) as i32 {
+        let coef = alpha
+            .add(&word(start as Word), 64, RM)
+            .mul(&two_over_x_64, 64, RM);
+        let next = match modified {
+            true => coef.mul(&cur, 64, RM).add(&prev, 64, RM),
+            false => coef.mul(&cur, 64, RM).sub(&prev, 64, RM),
+        };
+        (prev, cur) = (cur, next);
+        start += 1;
+    }
+    let (mut above, mut f) = (w.int(0), w.int(1));
+    let mut values = [w.int(0), w.int(0), w.int(0)];
+    let mut low = [w.int(0), w.int(0)];
+    for k in (0..start).rev() {
+        // f is f_(k + 1) and above is f_(k + 2)
+        let coef = coefficient(w, alpha, k + 1, &two_over_x);
+        let below = match modified {
+            true => w.add(&w.mul(&coef, &f), &above),
+            false => w.sub(&w.mul(&coef, &f), &above),
+        };
+        (above, f) = (f, below);
+        if k + 1 >= n && k <= n + 1 {
+            values[k + 1 - n] = f.clone();
+        }
+        if k <= 1 {
+            low[k] = f.clone();
+        }
+    }
+    let alpha_1 = w.add(alpha, &w.int(1));
+    let scale = match modified {
+        true => {
+            let i_alpha = asymptotic(w, Kind::I, alpha, x);
+            w.div(&i_alpha, &low[0])
+        }
+        false => {
+            // Least squares over both orders, since either can be close to a zero
+            let j_alpha = asymptotic(w, Kind::J, alpha, x);
+            let j_alpha_1 = asymptotic(w, Kind::J, &alpha_1, x);
+            let num = w.add(&w.mul(&j_alpha, &low[0]), &w.mul(&j_alpha_1, &low[1]));
+            let den = w.add(&w.mul(&low[0], &low[0]), &w.mul(&low[1], &low[1]));
+            w.div(&num, &den)
+        }
+    };
+    let lost = lost_bits(&values[1], [&values[0], &values[2]]);
+    (w.mul(&values[1], &scale), lost)
+}
+
+/// Returns `Y_(alpha + n)(x)` or `K_(alpha + n)(x)` from the recurrence run forwards from the
+/// asymptotic values at `alpha` and `alpha + 1`, in the direction they grow. Also returns the
+/// bits lost at order `alpha + n`.
+fn forward(w: &mut Work, kind: Kind, alpha: &Float, n: usize, x: &Float) -> (Float, usize) {
+    let two_over_x = w.div(&w.int(2), x);
+    let alpha_1 = w.add(alpha, &w.int(1));
+    let f_0 = asymptotic(w, kind, alpha, x);
+    let f_1 = asymptotic(w, kind, &alpha_1, x);
+    // The values at the orders k - 1, k and k + 1
+    let mut values = [w.int(0), f_0, f_1];
+    for k in 1..n + 1 {
+        let coef = coefficient(w, alpha, k, &two_over_x);
+        let next = match kind {
+            Kind::K => w.add(&w.mul(&coef, &values[2]), &values[1]),
+            _ => w.sub(&w.mul(&coef, &values[2]), &values[1]),
+        };
+        values = [values[1].clone(), values[2].clone(), next];
+    }
+    let lost = lost_bits(&values[1], [&values[0], &values[2]]);
+    (values[1].clone(), lost)
+}
+
+/// Returns the Bessel function of the given kind for `x > 0`.
+fn bessel(w: &mut Work, kind: Kind, nu: &Float, x: &Float) -> Float {
+    if nu.is_negative() && (nu.is_int() || kind == Kind::K) {
+        // J_-n = (-1)^n J_n, Y_-n = (-1)^n Y_n, I_-n = I_n and K_-nu = K_nu
+        let value = bessel(w, kind, &nu.abs(), x);
+        return match kind {
+            Kind::J | Kind::Y if is_odd(nu) => -value,
+            _ => value,
+        };
+    }
+    if is_large(w, nu, x) {
+        return asymptotic(w, kind, nu, x);
+    }
+    if is_recurrence(w, nu, x) {
+        return recurrence(w, kind, nu, x);
+    }
+    match kind {
+        Kind::J => w.extended(cancelled_bits(x), |w| power_series(w, nu, x, false)),
+        Kind::I => power_series(w, nu, x, true),
+        Kind::Y if nu.is_int() => w.extended(cancelled_bits(x), |w| y_int(w, int_part(nu), x)),
+        Kind::K if nu.is_int() => w.extended(2 * cancelled_bits(x), |w| k_int(w, int_part(nu), x)),
+        Kind::Y => {
+            let extra = cancelled_bits(x) + near_int_bits(nu);
+            w.extended(extra, |w| {
+                // Y_nu(x) = (J_nu(x) cos(pi nu) - J_-nu(x)) / sin(pi nu)
+                let pos = power_series(w, nu, x, false);
+                let neg = power_series(w, &-nu.clone(), x, false);
+                let (cos, sin) = (w.cos_pi(nu), w.sin_pi(nu));
+                w.div(&w.sub(&w.mul(&pos, &cos), &neg), &sin)
+            })
+        }
+        Kind::K => {
+            let extra = 2 * cancelled_bits(x) + near_int_bits(nu);
+            w.extended(extra, |w| {
+                // K_nu(x) = pi (I_-nu(x) - I_nu(x)) / (2 sin(pi nu))
+                let pos = power_series(w, nu, x, true);
+                let neg = power_series(w, &-nu.clone(), x, true);
+                let pi = w.pi();
+                let sin = w.sin_pi(nu);
+                w.div(&w.mul(&w.sub(&neg, &pos), &pi), &w.mul(&sin, &w.int(2)))
+            })
+        }
+    }
+}
+
+/// Returns the Bessel function of the given kind at zero, which is its limit from above.
+fn at_zero(kind: Kind, nu: &Float) -> Float {
+    match kind {
+        Kind::J | Kind::I if nu.is_zero() => word(1),
+        Kind::J | Kind::I if nu.is_int() || nu.is_positive() => word(0),
+        // (x/2)^nu / gamma(nu + 1) has the sign of gamma(nu + 1)
+        Kind::J | Kind::I => match *nu < -word(1) && !is_odd(&nu.floor()) {
+            true => astro_float::INF_NEG,
+            false => astro_float::INF_POS,
+        },
+        Kind::K => astro_float::INF_POS,
+        Kind::Y if !nu.is_negative() => astro_float::INF_NEG,
+        Kind::Y => {
+            // Y_-nu(x) behaves like -cos(pi nu) J_-nu(x) / sin(pi nu), which is zero for
+            // half-integer orders
+            let (n, r) = reduce_half(nu);
+            match r.abs() == Float::from_f64(0.5, 64) {
+                true => word(0),
+                false if is_odd(&n) => astro_float::INF_POS,
+                false => astro_float::INF_NEG,
+            }
+        }
+    }
+}
+
+/// Returns the Bessel function of the given kind, see [`BigFloat::bessel_j`].
+fn bessel_value<CTX: BigFloatCtx>(kind: Kind, x: &Float, nu: &Float) -> BigFloat<CTX> {
+    if x.is_nan() || nu.is_nan() || nu.is_inf() || magnitude_bits(nu) >= 32 {
+        return BigFloat::from(Float::nan(None));
+    }
+    if x.is_zero() {
+        return BigFloat::from(at_zero(kind, nu));
+    }
+    // J_n(-x) = (-1)^n J_n(x) and I_n(-x) = (-1)^n I_n(x), other values are complex
+    if x.is_negative() && (!nu.is_int() || matches!(kind, Kind::Y | Kind::K)) {
+        return BigFloat::from(Float::nan(None));
+    }
+    let negate = x.is_negative() && is_odd(nu);
+    let x = x.abs();
+    if x.is_inf() {
+        return BigFloat::from(match (kind, negate) {
+            (Kind::I, true) => astro_float::INF_NEG,
+            (Kind::I, false) => astro_float::INF_POS,
+            _ => word(0),
+        });
+    }
+    round(run::<CTX, _>(magnitude_bits(&x), |w| {
+        let value = bessel(w, kind, nu, &x);
+        match negate {
+            true => -value,
+            false => value,
+        }
+    }))
+}
+
+/// Returns `Ai(x)`, or `Bi(x)` if `bi`, for any finite `x`.
+fn airy(w: &mut Work, x: &Float, bi: bool) -> Float {
+    let third = w.div(&w.int(1), &w.int(3));
+    if x.is_zero() {
+        // Ai(0) = 3^(-2/3) / gamma(2/3) and Bi(0) = 3^(-1/6) / gamma(2/3)
+        let two_thirds = w.mul(&third, &w.int(2));
+        let exp = match bi {
+            true => -w.mul(&third, &w.half()),
+            false => -two_thirds.clone(),
+        };
+        let pow = w.pow(&w.int(3), &exp);
+        let gamma = gamma(w, &two_thirds);
+        return w.div(&pow, &gamma);
+    }
+    let z = x.abs();
+    // zeta = 2/3 z^(3/2)
+    let zeta = w.mul(&w.mul(&z, &w.sqrt(&z)), &w.mul(&third, &w.int(2)));
+    let root = w.sqrt(&w.mul(&z, &third));
+    let neg_third = -third.clone();
+    match (x.is_positive(), bi) {
+        (true, false) => {
+            // Ai(x) = sqrt(x/3) K_1/3(zeta) / pi
+            let k = bessel(w, Kind::K, &third, &zeta);
+            let pi = w.pi();
+            w.div(&w.mul(&root, &k), &pi)
+        }
+        (true, true) => {
+            // Bi(x) = sqrt(x/3) (I_-1/3(zeta) + I_1/3(zeta))
+            let neg = bessel(w, Kind::I, &neg_third, &zeta);
+            let pos = bessel(w, Kind::I, &third, &zeta);
+            w.mul(&root, &w.add(&neg, &pos))
+        }
+        (false, false) => {
+            // Ai(-z) = sqrt(z/3) (J_1/3(zeta) + J_-1/3(zeta)) / sqrt(3)
+            let neg = bessel(w, Kind::J, &neg_third, &zeta);
+            let pos = bessel(w, Kind::J, &third, &zeta);
+            let sqrt_3 = w.sqrt(&w.int(3));
+            w.div(&w.mul(&root, &w.add(&pos, &neg)), &sqrt_3)
+        }
+        (false, true) => {
+            // Bi(-z) = sqrt(z/3) (J_-1/3(zeta) - J_1/3(zeta))
+            let neg = bessel(w, Kind::J, &neg_third, &zeta);
+            let pos = bessel(w, Kind::J, &third, &zeta);
+            w.mul(&root, &w.sub(&neg, &pos))
+        }
+    }
+}
+
+/// Returns the Airy function `Ai`, or `Bi` if `bi`, see [`BigFloat::airy_ai`].
+fn airy_value<CTX: BigFloatCtx>(x: &Float, bi: bool) -> BigFloat<CTX> {
+    if x.is_nan() {
+        return BigFloat::from(x.clone());
+    }
+    if x.is_inf() {
+        return BigFloat::from(match bi && x.is_positive() {
+            true => astro_float::INF_POS,
+            false => word(0),
+        });
+    }
+    // zeta grows like |x|^(3/2)
+    let extra = magnitude_bits(x) * 3 / 2 + 1;
+    round(run::<CTX, _>(extra, |w| airy(w, x, bi)))
+}
+
+impl<CTX: BigFloatCtx> BigFloat<CTX> {
+    /// Returns the Bessel function of the first kind of order `nu` at this number, `J_nu(x)`.
+    ///
+    /// The order can be any real number of magnitude below `2^31`, and `J_-n = (-1)^n J_n` for
+    /// integer orders. Negative arguments are only defined for integer orders, with
+    /// `J_n(-x) = (-1)^n J_n(x)`, and give `NaN` otherwise. Zero gives the limit from above,
+    /// which is infinite for negative non-integer orders.
+    ///
+    /// Arguments of at least half the precision of the context plus `nu^2` use the asymptotic
+    /// expansion, arguments of at least half the precision and `|nu| / 4` the recurrence in the
+    /// order from the expansion at orders below two, and smaller ones the power series with enough
+    /// extra bits for its cancellation.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use astro_nalgebra::{BigFloat, ConstCtx};
+    ///
+    /// type BF256 = BigFloat<ConstCtx<256>>;
+    ///
+    /// let j0 = BF256::from(1.0).bessel_j(&BF256::from(0.0));
+    /// assert!((j0.as_f64() - 0.7651976865579666).abs() < 1e-16);
+    /// let j = BF256::from(-2.5).bessel_j(&BF256::from(3.0));
+    /// assert!((j.as_f64() + 0.2166003910391135).abs() < 1e-16);
+    /// ```
+    pub fn bessel_j(&self, nu: &Self) -> Self {
+        bessel_value(Kind::J, &self.num, &nu.num)
+    }
+
+    /// Returns the Bessel function of the second kind of order `nu` at this number, `Y_nu(x)`.
+    ///
+    /// Integer orders use the logarithmic series, and other orders
+    /// `(J_nu(x) cos(pi nu) - J_-nu(x)) / sin(pi nu)` with extra bits when `nu` is close to an
+    /// integer. Negative arguments give `NaN`, and zero gives the limit from above.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use astro_nalgebra::{BigFloat, ConstCtx};
+    ///
+    /// type BF256 = BigFloat<ConstCtx<256>>;
+    ///
+    /// let y0 = BF256::from(1.0).bessel_y(&BF256::from(0.0));
+    /// assert!((y0.as_f64() - 0.08825696421567696).abs() < 1e-17);
+    /// assert_eq!(BF256::from(0.0).bessel_y(&BF256::from(2.0)).as_f64(), f64::NEG_INFINITY);
+    /// ```
+    pub fn bessel_y(&self, nu: &Self) -> Self {
+        bessel_value(Kind::Y, &self.num, &nu.num)
+    }
+
+    /// Returns the modified Bessel function of the first kind of order `nu` at this number,
+    /// `I_nu(x)`.
+    ///
+    /// Like [`BigFloat::bessel_j`], negative arguments are only defined for integer orders, with
+    /// `I_n(-x) = (-1)^n I_n(x)`, and `I_-n = I_n`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use astro_nalgebra::{BigFloat, ConstCtx};
+    ///
+    /// type BF256 = BigFloat<ConstCtx<256>>;
+    ///
+    /// let i1 = BF256::from(2.0).bessel_i(&BF256::from(1.0));
+    /// assert!((i1.as_f64() - 1.590636854637329).abs() < 1e-15);
+    /// ```
+    pub fn bessel_i(&self, nu: &Self) -> Self {
+        bessel_value(Kind::I, &self.num, &nu.num)
+    }
+
+    /// Returns the modified Bessel function of the second kind of order `nu` at this number,
+    /// `K_nu(x)`.
+    ///
+    /// It decays like `exp(-x)`, and keeps the full precision of the context where it is much
+    /// smaller than `I_nu(x)`. `K_-nu = K_nu`, negative arguments give `NaN`, and zero gives
+    /// positive infinity.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use astro_nalgebra::{BigFloat, ConstCtx};
+    ///
+    /// type BF256 = BigFloat<ConstCtx<256>>;
+    ///
+    /// let k0 = BF256::from(1.0).bessel_k(&BF256::from(0.0));
+    /// assert!((k0.as_f64() - 0.42102443824070834).abs() < 1e-16);
+    /// let k = BF256::from(50.0).bessel_k(&BF256::from(2.5));
+    /// assert!((k.as_f64() - 3.627839645299048e-23).abs() < 1e-38);
+    /// ```
+    pub fn bessel_k(&self, nu: &Self) -> Self {
+        bessel_value(Kind::K, &self.num, &nu.num)
+    }
+
+    /// Returns the Airy function `Ai` of this number, the solution of `y'' = x y` that decays
+    /// for positive `x`.
+    ///
+    /// It is computed from the Bessel functions of order `1/3` of `2/3 |x|^(3/2)`, so it keeps the
+    /// full precision of the context where it decays for positive arguments.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use astro_nalgebra::{BigFloat, ConstCtx};
+    ///
+    /// type BF256 = BigFloat<ConstCtx<256>>;
+    ///
+    /// let ai = BF256::from(0.0).airy_ai();
+    /// assert!((ai.as_f64() - 0.3550280538878172).abs() < 1e-16);
+    /// let ai = BF256::from(-10.0).airy_ai();
+    /// assert!((ai.as_f64() - 0.04024123848644319).abs() < 1e-16);
+    /// ```
+    pub fn airy_ai(&self) -> Self {
+        airy_value(&self.num, false)
+    }
+
+    /// Returns the Airy function `Bi` of this number, the solution of `y'' = x y` that grows for
+    /// positive `x` and has the same amplitude as [`BigFloat::airy_ai`] for negative `x`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use astro_nalgebra::{BigFloat, ConstCtx};
+    ///
+    /// type BF256 = BigFloat<ConstCtx<256>>;
+    ///
+    /// let bi = BF256::from(1.0).airy_bi();
+    /// assert!((bi.as_f64() - 1.2074235949528713).abs() < 1e-15);
+    /// ```
+    pub fn airy_bi(&self) -> Self {
+        airy_value(&self.num, true)
+    }
+}
+
+/// Returns the Bessel function of the first kind `J_nu(x)`, see [`BigFloat::bessel_j`].
+pub fn j<CTX: BigFloatCtx>(nu: &BigFloat<CTX>, x: &BigFloat<CTX>) -> BigFloat<CTX> {
+    x.bessel_j(nu)
+}
+
+/// Returns the Bessel function of the second kind `Y_nu(x)`, see [`BigFloat::bessel_y`].
+pub fn y<CTX: BigFloatCtx>(nu: &BigFloat<CTX>, x: &BigFloat<CTX>) -> BigFloat<CTX> {
+    x.bessel_y(nu)
+}
+
+/// Returns the modified Bessel function of the first kind `I_nu(x)`, see
+/// [`BigFloat::bessel_i`].
+pub fn i<CTX: BigFloatCtx>(nu: &BigFloat<CTX>, x: &BigFloat<CTX>) -> BigFloat<CTX> {
+    x.bessel_i(nu)
+}
+
+/// Returns the modified Bessel function of the second kind `K_nu(x)`, see
+/// [`BigFloat::bessel_k`].
+pub fn k<CTX: BigFloatCtx>(nu: &BigFloat<CTX>, x: &BigFloat<CTX>) -> BigFloat<CTX> {
+    x.bessel_k(nu)
+}
+
+/// Returns the Airy function `Ai(x)`, see [`BigFloat::airy_ai`].
+pub fn airy_ai<CTX: BigFloatCtx>(x: &BigFloat<CTX>) -> BigFloat<CTX> {
+    x.airy_ai()
+}
+
+/// Returns the Airy function `Bi(x)`, see [`BigFloat::airy_bi`].
+pub fn airy_bi<CTX: BigFloatCtx>(x: &BigFloat<CTX>) -> BigFloat<CTX> {
+    x.airy_bi()
+}
@@ -0,0 +1,457 @@
+// This file contains the error function and its relatives: the complementary and scaled
+// complementary error functions, their inverses, and the distribution and quantile functions of
+// the standard normal distribution.
+//
+// Below the square root of the working precision, erf is summed from a series of positive terms,
+// and erfc is taken as 1 - erf with enough extra bits to absorb the cancellation. Above it, the
+// asymptotic series of the scaled function erfcx converges to the working precision, so erfc never
+// loses accuracy in the tail. The inverses are refined with Newton's method, on the logarithm of
+// erfc in the tail.
+use super::{
+    inf, int_part, magnitude_bits, negligible, round, run, sign_of, Float, Work, GUARD_BITS,
+};
+use crate::fmt::{exact_add, exact_mul, word};
+use crate::{BigFloat, BigFloatCtx};
+use astro_float::Sign;
+use core::cmp::Ordering;
+
+/// Returns true if `x` is large enough for the asymptotic series of [`erfcx_series`], which is
+/// when `x^2` is at least the working precision.
+fn is_large(w: &Work, x: &Float) -> bool {
+    w.mul(x, x) >= w.int(w.p as i64)
+}
+
+/// Returns `2 / sqrt(pi)`.
+fn two_over_sqrt_pi(w: &mut Work) -> Float {
+    let pi = w.pi();
+    w.div(&w.int(2), &w.sqrt(&pi))
+}
+
+/// Returns `erf(x)` for `x >= 0` from the series `2 / sqrt(pi) exp(-x^2) sum of
+/// 2^n x^(2n + 1) / (1 3 5 ... (2n + 1))`, whose terms are all positive.
+fn erf_series(w: &mut Work, x: &Float) -> Float {
+    let x2 = w.mul(x, x);
+    let two_x2 = w.mul(&x2, &w.int(2));
+    let mut term = x.clone();
+    let mut sum = x.clone();
+    let mut n = 1;
+    loop {
+        let den = w.int(2 * n + 1);
+        term = w.div(&w.mul(&term, &two_x2), &den);
+        // The terms only decrease once 2n + 1 exceeds 2 x^2
+        if den > two_x2 && negligible(&term, &sum, w.p) {
+            break;
+        }
+        sum = w.add(&sum, &term);
+        n += 1;
+    }
+    let exp = w.exp(&-x2);
+    let scale = two_over_sqrt_pi(w);
+    w.mul(&w.mul(&scale, &exp), &sum)
+}
+
+/// Returns `erfcx(x)` for `x` that [`is_large`] from the asymptotic series
+/// `1 / (x sqrt(pi)) sum of (-1)^n 1 3 5 ... (2n - 1) / (2 x^2)^n`.
+///
+/// Its smallest term is about `exp(-x^2)`, which is below the working precision.
+fn erfcx_series(w: &mut Work, x: &Float) -> Float {
+    let two_x2 = w.mul(&w.mul(x, x), &w.int(2));
+    let mut term = w.int(1);
+    let mut sum = term.clone();
+    let mut n = 1;
+    loop {
+        term = -w.div(&w.mul(&term, &w.int(2 * n - 1)), &two_x2);
+        if negligible(&term, &sum, w.p) {
+            break;
+        }
+        sum = w.add(&sum, &term);
+        n += 1;
+    }
+    let pi = w.pi();
+    w.div(&sum, &w.mul(x, &w.sqrt(&pi)))
+}
+
+/// Returns the number of bits lost when `erfc(x)` is taken as `1 - erf(x)`, about
+/// `x^2 / ln(2)`, for `x` that is not [`is_large`].
+fn cancelled_bits(w: &Work, x: &Float) -> usize {
+    // x^2 is below the working precision, so its integer part fits in one word
+    (int_part(&w.mul(x, x)) + 1) * 3 / 2 + 8
+}
+
+/// Returns `erfc(x)` for `x >= 0`.
+fn erfc_pos(w: &mut Work, x: &Float) -> Float {
+    if is_large(w, x) {
+        let series = erfcx_series(w, x);
+        let exp = w.exp(&-w.mul(x, x));
+        // exp underflows to a zero with the sign of its argument, but erfc is positive
+        return w.mul(&series, &exp).abs();
+    }
+    let extra = cancelled_bits(w, x);
+    w.extended(extra, |w| {
+        let erf = erf_series(w, x);
+        w.sub(&w.int(1), &erf)
+    })
+}
+
+/// Returns `erfcx(x)` for `x >= 0`.
+fn erfcx_pos(w: &mut Work, x: &Float) -> Float {
+    if is_large(w, x) {
+        return erfcx_series(w, x);
+    }
+    let extra = cancelled_bits(w, x);
+    w.extended(extra, |w| {
+        let erf = erf_series(w, x);
+        let exp = w.exp(&w.mul(x, x));
+        w.mul(&w.sub(&w.int(1), &erf), &exp)
+    })
+}
+
+/// Returns `erf(x)` for any finite `x`.
+fn erf(w: &mut Work, x: &Float) -> Float {
+    let abs = x.abs();
+    let erf = match is_large(w, &abs) {
+        true => {
+            let erfc = erfc_pos(w, &abs);
+            w.sub(&w.int(1), &erfc)
+        }
+        false => erf_series(w, &abs),
+    };
+    match x.is_negative() {
+        true => -erf,
+        false => erf,
+    }
+}
+
+/// Returns `erfc(x)` for any finite `x`.
+fn erfc(w: &mut Work, x: &Float) -> Float {
+    if !x.is_negative() {
+        return erfc_pos(w, x);
+    }
+    // erfc(-x) = 2 - erfc(x)
+    let erfc = erfc_pos(w, &x.abs());
+    w.sub(&w.int(2), &erfc)
+}
+
+/// Returns true once a Newton step no longer changes `x` at the working precision, less half of
+/// the guard bits. The error left after the next step would be about the square of the step.
+fn converged(w: &Work, step: &Float, x: &Float) -> bool {
+    negligible(step, x, w.p - GUARD_BITS / 2)
+}
+
+/// Returns the `x > 0` with `erf(x) = y`, for `0 < y <= 1/2`.
+fn inv_erf(w: &mut Work, y: &Float) -> Float {
+    // erf is concave, so starting below the root at its tangent at zero, every step stays below
+    // the root
+    let scale = two_over_sqrt_pi(w);
+    let mut x = w.div(y, &scale);
+    loop {
+        // x += (y - erf(x)) / (2 / sqrt(pi) exp(-x^2))
+        let erf = erf_series(w, &x);
+        let exp = w.exp(&w.mul(&x, &x));
+        let step = w.div(&w.mul(&w.sub(y, &erf), &exp), &scale);
+        x = w.add(&x, &step);
+        if converged(w, &step, &x) {
+            return x;
+        }
+    }
+}
+
+/// Returns the `x > 0` with `erfc(x) = y`, for `0 < y <= 1/2`.
+fn inv_erfc(w: &mut Work, y: &Float) -> Float {
+    // Newton's method on ln(erfc(x)) = ln(y), which is well conditioned in the tail. The
+    // logarithm is concave, so every step after the first one stays above the root.
+    let ln_y = w.ln(y);
+    // x is about sqrt(t - ln(pi t) / 2) with t = -ln(y)
+    let t = -ln_y.clone();
+    let pi = w.pi();
+    let ln_pi_t = w.ln(&w.mul(&pi, &t));
+    let mut x = w.sqrt(&w.sub(&t, &w.mul(&ln_pi_t, &w.half())));
+    let sqrt_pi = w.sqrt(&pi);
+    loop {
+        // The derivative of ln(erfc(x)) is -2 / (sqrt(pi) erfcx(x))
+        let erfcx = erfcx_pos(w, &x);
+        let ln_erfcx = w.ln(&erfcx);
+        let ln_erfc = w.sub(&ln_erfcx, &w.mul(&x, &x));
+        let slope = w.mul(&w.mul(&sqrt_pi, &erfcx), &w.half());
+        let step = w.mul(&w.sub(&ln_erfc, &ln_y), &slope);
+        x = w.add(&x, &step);
+        if converged(w, &step, &x) {
+            return x;
+        }
+    }
+}
+
+/// Returns `erfc_inv(y)` for `0 < y < 2`, see [`BigFloat::erfc_inv`].
+fn erfc_inv(w: &mut Work, y: &Float) -> Float {
+    let half = w.half();
+    let one = word(1);
+    let two = word(2);
+    if *y <= half {
+        return inv_erfc(w, y);
+    }
+    // erfc_inv(2 - y) = -erfc_inv(y)
+    let reflected = exact_add(&two, &-y);
+    if reflected <= half {
+        return -inv_erfc(w, &reflected);
+    }
+    // erfc_inv(y) = erf_inv(1 - y), where 1 - y is exact
+    let diff = exact_add(&one, &-y);
+    match diff.is_zero() {
+        true => word(0),
+        false if diff.is_negative() => -inv_erf(w, &diff.abs()),
+        false => inv_erf(w, &diff),
+    }
+}
+
+/// Returns `1` or `-1` with the given sign.
+fn unit(sign: Sign) -> Float {
+    match sign {
+        Sign::Pos => word(1),
+        Sign::Neg => -word(1),
+    }
+}
+
+/// Returns the extra bits needed for functions of `x` that grow or decay like `exp(x^2)`, to
+/// make up for the rounding of `x^2`.
+fn square_bits(x: &Float) -> usize {
+    2 * magnitude_bits(x)
+}
+
+impl<CTX: BigFloatCtx> BigFloat<CTX> {
+    /// Returns the error function of this number, `2 / sqrt(pi)` times the integral of
+    /// `exp(-t^2)` from zero to `x`.
+    ///
+    /// Infinities give `1` with the sign of the infinity.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use astro_nalgebra::{BigFloat, ConstCtx};
+    ///
+    /// type BF256 = BigFloat<ConstCtx<256>>;
+    ///
+    /// let erf = BF256::from(0.5).erf();
+    /// assert!((erf.as_f64() - 0.5204998778130465).abs() < 1e-16);
+    /// assert_eq!(BF256::from(-f64::INFINITY).erf(), BF256::from(-1.0));
+    /// ```
+    pub fn erf(&self) -> Self {
+        let x = &self.num;
+        if x.is_nan() || x.is_zero() {
+            return self.clone();
+        }
+        if x.is_inf() {
+            return BigFloat::from(unit(sign_of(x)));
+        }
+        round(run::<CTX, _>(square_bits(x), |w| erf(w, x)))
+    }
+
+    /// Returns the complementary error function of this number, `1 - erf(x)`.
+    ///
+    /// It is computed without subtracting from one for large `x`, so the result keeps the full
+    /// precision of the context far into the tail, where it is much smaller than the precision
+    /// of `erf(x)`. The result only becomes zero when it is below the exponent range, and the
+    /// zero is positive.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use astro_nalgebra::{BigFloat, ConstCtx};
+    ///
+    /// type BF256 = BigFloat<ConstCtx<256>>;
+    ///
+    /// // About 6.4e-4346, far below the range of f64
+    /// let tail = BF256::from(100.0).erfc();
+    /// let scale: BF256 = "1e4346".parse().unwrap();
+    /// assert!(((tail * scale).as_f64() - 6.405961424921732).abs() < 1e-14);
+    /// ```
+    pub fn erfc(&self) -> Self {
+        let x = &self.num;
+        if x.is_nan() {
+            return self.clone();
+        }
+        if x.is_inf() {
+            return BigFloat::from(match x.is_negative() {
+                true => word(2),
+                false => word(0),
+            });
+        }
+        round(run::<CTX, _>(square_bits(x), |w| erfc(w, x)))
+    }
+
+    /// Returns the scaled complementary error function of this number, `exp(x^2) erfc(x)`.
+    ///
+    /// It decreases like `1 / (x sqrt(pi))` for large `x` instead of underflowing like
+    /// [`BigFloat::erfc`]. Negative infinity gives positive infinity.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use astro_nalgebra::{BigFloat, ConstCtx};
+    ///
+    /// type BF256 = BigFloat<ConstCtx<256>>;
+    ///
+    /// assert_eq!(BF256::from(0.0).erfcx(), BF256::from(1.0));
+    /// let scaled = BF256::from(1e10).erfcx();
+    /// assert!((scaled.as_f64() - 5.641895835477563e-11).abs() < 1e-26);
+    /// ```
+    pub fn erfcx(&self) -> Self {
+        let x = &self.num;
+        if x.is_nan() {
+            return self.clone();
+        }
+        if x.is_inf() {
+            return BigFloat::from(match x.is_negative() {
+                true => astro_float::INF_POS,
+                false => word(0),
+            });
+        }
+        round(run::<CTX, _>(square_bits(x), |w| {
+            if !x.is_negative() {
+                return erfcx_pos(w, x);
+            }
+            // erfcx(-x) = 2 exp(x^2) - erfcx(x)
+            let exp = w.exp(&w.mul(x, x));
+            let scaled = erfcx_pos(w, &x.abs());
+            w.sub(&w.mul(&exp, &w.int(2)), &scaled)
+        }))
+    }
+
+    /// Returns the inverse error function of this number, the `x` with `erf(x) = y`.
+    ///
+    /// Arguments close to `1` or `-1` are solved through the complementary error function, so the
+    /// result is accurate to the precision of the argument. `1` and `-1` give infinities, and
+    /// arguments outside `[-1, 1]` give `NaN`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use astro_nalgebra::{BigFloat, ConstCtx};
+    ///
+    /// type BF256 = BigFloat<ConstCtx<256>>;
+    ///
+    /// let y = BF256::from(0.75);
+    /// assert!((y.erf_inv().erf() - y).as_f64().abs() < 1e-75);
+    /// assert_eq!(BF256::from(-1.0).erf_inv().as_f64(), f64::NEG_INFINITY);
+    /// ```
+    pub fn erf_inv(&self) -> Self {
+        let y = &self.num;
+        if y.is_nan() || y.is_zero() {
+            return self.clone();
+        }
+        let one = word(1);
+        match y.abs().partial_cmp(&one) {
+            Some(Ordering::Less) => {}
+            Some(Ordering::Equal) => return BigFloat::from(inf(sign_of(y))),
+            _ => return BigFloat::from(Float::nan(None)),
+        }
+        // erf_inv(y) = erfc_inv(1 - y), where 1 - y is exact
+        let diff = exact_add(&one, &-y);
+        round(run::<CTX, _>(0, |w| erfc_inv(w, &diff)))
+    }
+
+    /// Returns the inverse complementary error function of this number, the `x` with
+    /// `erfc(x) = y`.
+    ///
+    /// Tiny arguments are solved from the logarithm of [`BigFloat::erfc`], so they keep the full
+    /// precision of the context even below the range of `f64`. `0` and `2` give infinities, and
+    /// arguments outside `[0, 2]` give `NaN`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use astro_nalgebra::{BigFloat, ConstCtx};
+    ///
+    /// type BF256 = BigFloat<ConstCtx<256>>;
+    ///
+    /// let tiny: BF256 = "1e-1000".parse().unwrap();
+    /// let x = tiny.erfc_inv();
+    /// assert!((x.as_f64() - 47.93894640426451).abs() < 1e-12);
+    /// assert_eq!(BF256::from(1.0).erfc_inv(), BF256::from(0.0));
+    /// ```
+    pub fn erfc_inv(&self) -> Self {
+        let y = &self.num;
+        if y.is_nan() {
+            return self.clone();
+        }
+        if y.is_zero() {
+            return BigFloat::from(astro_float::INF_POS);
+        }
+        let two = word(2);
+        match y.partial_cmp(&two) {
+            _ if y.is_negative() => BigFloat::from(Float::nan(None)),
+            Some(Ordering::Less) => round(run::<CTX, _>(0, |w| erfc_inv(w, y))),
+            Some(Ordering::Equal) => BigFloat::from(astro_float::INF_NEG),
+            _ => BigFloat::from(Float::nan(None)),
+        }
+    }
+
+    /// Returns the cumulative distribution function of the standard normal distribution at this
+    /// number, `erfc(-x / sqrt(2)) / 2`.
+    ///
+    /// The lower tail keeps the full precision of the context like [`BigFloat::erfc`].
+    ///
+    /// ## Example
+    /// ```rust
+    /// use astro_nalgebra::{BigFloat, ConstCtx};
+    ///
+    /// type BF256 = BigFloat<ConstCtx<256>>;
+    ///
+    /// assert_eq!(BF256::from(0.0).cdf(), BF256::from(0.5));
+    /// let tail = BF256::from(-10.0).cdf();
+    /// assert!((tail.as_f64() - 7.61985302416047e-24).abs() < 1e-37);
+    /// ```
+    pub fn cdf(&self) -> Self {
+        let x = &self.num;
+        if x.is_nan() {
+            return self.clone();
+        }
+        if x.is_inf() {
+            return BigFloat::from(match x.is_negative() {
+                true => word(0),
+                false => word(1),
+            });
+        }
+        round(run::<CTX, _>(square_bits(x), |w| {
+            let sqrt_2 = w.sqrt(&w.int(2));
+            let erfc = erfc(w, &-w.div(x, &sqrt_2));
+            w.mul(&erfc, &w.half())
+        }))
+    }
+
+    /// Returns the quantile function of the standard normal distribution at this number, the
+    /// inverse of [`BigFloat::cdf`], `-sqrt(2) erfc_inv(2p)`.
+    ///
+    /// Probabilities close to `0` or `1` keep the full precision of the context like
+    /// [`BigFloat::erfc_inv`]. `0` and `1` give infinities, and probabilities outside `[0, 1]`
+    /// give `NaN`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use astro_nalgebra::{BigFloat, ConstCtx};
+    ///
+    /// type BF256 = BigFloat<ConstCtx<256>>;
+    ///
+    /// let p = BF256::from(0.975);
+    /// assert!((p.quantile().as_f64() - 1.959963984540054).abs() < 1e-15);
+    /// assert_eq!(BF256::from(0.5).quantile(), BF256::from(0.0));
+    /// ```
+    pub fn quantile(&self) -> Self {
+        let p = &self.num;
+        if p.is_nan() {
+            return self.clone();
+        }
+        let y = exact_mul(p, &word(2));
+        if y.is_zero() {
+            return BigFloat::from(astro_float::INF_NEG);
+        }
+        match y.partial_cmp(&word(2)) {
+            _ if y.is_negative() => BigFloat::from(Float::nan(None)),
+            Some(Ordering::Less) => round(run::<CTX, _>(0, |w| {
+                let sqrt_2 = w.sqrt(&w.int(2));
+                let x = erfc_inv(w, &y);
+                let x = w.mul(&x, &sqrt_2);
+                match x.is_zero() {
+                    true => x,
+                    false => -x,
+                }
+            })),
+            Some(Ordering::Equal) => BigFloat::from(astro_float::INF_POS),
+            _ => BigFloat::from(Float::nan(None)),
+        }
+    }
+}
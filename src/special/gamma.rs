@@ -0,0 +1,581 @@
+// This file contains the gamma function and its relatives: log-gamma, digamma, polygamma, beta,
+// factorial and binomial coefficients.
+//
+// Arguments below 1/2 use the reflection formulas. Other arguments are shifted up with the
+// recurrences until the asymptotic Stirling series converges to the working precision. Small
+// positive integer arguments are computed exactly and rounded once.
+extern crate alloc;
+use alloc::vec::Vec;
+
+use super::{
+    exact_factorial, inf, is_nonpositive_int, magnitude_bits, mul_sign, negligible, round, run,
+    sign_of, Float, Work,
+};
+use crate::fmt::{exact_add, exact_mul, word};
+use crate::{BigFloat, BigFloatCtx};
+use astro_float::Sign;
+
+/// Largest integer whose factorial is computed exactly.
+const EXACT_MAX: u128 = 1024;
+
+/// Returns the argument above which the asymptotic series of the polygamma function of order `n`
+/// is used.
+fn series_min(w: &Work, n: usize) -> Float {
+    w.int((w.p / 4 + n + 8) as i64)
+}
+
+/// Returns enough Bernoulli numbers for the asymptotic series at the working precision.
+fn series_bernoulli(w: &mut Work, n: usize) -> Vec<Float> {
+    w.bernoulli(w.p / 8 + n + 8)
+}
+
+/// Returns `x + m` for the smallest `m` that reaches [`series_min`], along with `f(x + k)` summed
+/// or multiplied over `k < m` by `combine`.
+fn shift(
+    w: &Work,
+    x: &Float,
+    n: usize,
+    init: Float,
+    mut combine: impl FnMut(&Float, &Float) -> Float,
+) -> (Float, Float) {
+    let min = series_min(w, n);
+    let one = w.int(1);
+    let mut y = x.clone();
+    let mut acc = init;
+    while y < min {
+        acc = combine(&acc, &y);
+        y = w.add(&y, &one);
+    }
+    (y, acc)
+}
+
+/// Returns `ln(gamma(y))` from the Stirling series, for `y` above [`series_min`].
+fn stirling(w: &mut Work, y: &Float) -> Float {
+    let ln_y = w.ln(y);
+    let half = w.half();
+    let pi = w.pi();
+    let ln_2pi = w.ln(&w.mul(&pi, &w.int(2)));
+    let mut sum = w.sub(&w.mul(&w.sub(y, &half), &ln_y), y);
+    sum = w.add(&sum, &w.mul(&ln_2pi, &half));
+    let inv = w.recip(y);
+    let inv2 = w.mul(&inv, &inv);
+    let mut pow = inv;
+    for (idx, bernoulli) in series_bernoulli(w, 0).iter().enumerate() {
+        let k = idx as i64 + 1;
+        let term = w.div(&w.mul(bernoulli, &pow), &w.int(2 * k * (2 * k - 1)));
+        if negligible(&term, &sum, w.p) {
+            break;
+        }
+        sum = w.add(&sum, &term);
+        pow = w.mul(&pow, &inv2);
+    }
+    sum
+}
+
+/// Returns `ln(gamma(x))` for `x >= 1/2`.
+pub(super) fn ln_gamma_pos(w: &mut Work, x: &Float) -> Float {
+    // The result vanishes at 1 and 2, where the series and the logarithm of the product cancel
+    // to about x - 1 or x - 2, so the bits lost are made up with extra bits
+    let mut extra = 0;
+    for zero in [1, 2] {
+        let diff = exact_add(x, &-word(zero));
+        if diff.is_zero() {
+            return w.int(0);
+        }
+        extra = extra.max((-diff.exponent().unwrap_or(0)).max(0) as usize);
+    }
+    w.extended(extra, |w| {
+        let (y, prod) = shift(w, x, 0, w.int(1), |acc, y| w.mul(acc, y));
+        let series = stirling(w, &y);
+        let ln_prod = w.ln(&prod);
+        w.sub(&series, &ln_prod)
+    })
+}
+
+/// Returns `gamma(x)` for `x >= 1/2`.
+fn gamma_pos(w: &mut Work, x: &Float) -> Float {
+    let (y, prod) = shift(w, x, 0, w.int(1), |acc, y| w.mul(acc, y));
+    let series = stirling(w, &y);
+    let exp = w.exp(&series);
+    w.div(&exp, &prod)
+}
+
+/// Returns `gamma(x)` for any `x` that is not a pole.
+pub(super) fn gamma(w: &mut Work, x: &Float) -> Float {
+    if *x >= w.half() {
+        return gamma_pos(w, x);
+    }
+    // gamma(x) = pi / (sin(pi x) gamma(1 - x))
+    let reflected = gamma_pos(w, &w.sub(&w.int(1), x));
+    let sin = w.sin_pi(x);
+    let pi = w.pi();
+    w.div(&pi, &w.mul(&sin, &reflected))
+}
+
+/// Returns `ln|gamma(x)|` and the sign of `gamma(x)` for any `x` that is not a pole.
+fn ln_gamma(w: &mut Work, x: &Float) -> (Float, Sign) {
+    if *x >= w.half() {
+        return (ln_gamma_pos(w, x), Sign::Pos);
+    }
+    // ln|gamma(x)| = ln(pi) - ln|sin(pi x)| - ln(gamma(1 - x))
+    let reflected = ln_gamma_pos(w, &w.sub(&w.int(1), x));
+    let sin = w.sin_pi(x);
+    let pi = w.pi();
+    let ln_pi = w.ln(&pi);
+    let ln_sin = w.ln(&sin.abs());
+    let sign = match sin.is_negative() {
+        true => Sign::Neg,
+        false => Sign::Pos,
+    };
+    (w.sub(&w.sub(&ln_pi, &ln_sin), &reflected), sign)
+}
+
+/// Returns `digamma(x)` for `x >= 1/2`.
+fn digamma_pos(w: &mut Work, x: &Float) -> Float {
+    // digamma(x) = digamma(x + m) - sum of 1 / (x + k)
+    let (y, shifted) = shift(w, x, 0, w.int(0), |acc, y| w.add(acc, &w.recip(y)));
+    // digamma(y) = ln(y) - 1 / 2y - sum of B_2k / (2k y^2k)
+    let ln_y = w.ln(&y);
+    let inv = w.recip(&y);
+    let mut sum = w.sub(&ln_y, &w.mul(&inv, &w.half()));
+    let inv2 = w.mul(&inv, &inv);
+    let mut pow = inv2.clone();
+    for (idx, bernoulli) in series_bernoulli(w, 0).iter().enumerate() {
+        let k = idx as i64 + 1;
+        let term = w.div(&w.mul(bernoulli, &pow), &w.int(2 * k));
+        if negligible(&term, &sum, w.p) {
+            break;
+        }
+        sum = w.sub(&sum, &term);
+        pow = w.mul(&pow, &inv2);
+    }
+    w.sub(&sum, &shifted)
+}
+
+/// Returns `polygamma(n, x)` for `n >= 1` and `x >= 1/2`.
+fn polygamma_pos(w: &mut Work, n: usize, x: &Float) -> Float {
+    let n_int = n as i64;
+    // polygamma(n, x) = polygamma(n, x + m) + (-1)^(n + 1) n! sum of 1 / (x + k)^(n + 1)
+    let (y, shifted) = shift(w, x, n, w.int(0), |acc, y| {
+        w.add(acc, &w.recip(&w.powi(y, n + 1)))
+    });
+    // (-1)^(n + 1) polygamma(n, y) = (n - 1)! / y^n + n! / 2y^(n + 1)
+    //     + sum of B_2k (2k + n - 1)! / ((2k)! y^(2k + n))
+    let fact_n_1 = (1..n_int).fold(w.int(1), |acc, i| w.mul(&acc, &w.int(i)));
+    let fact_n = w.mul(&fact_n_1, &w.int(n_int));
+    let inv = w.recip(&y);
+    let inv_n = w.powi(&inv, n);
+    let mut sum = w.mul(&fact_n_1, &inv_n);
+    let half_term = w.mul(&w.mul(&fact_n, &w.half()), &w.mul(&inv_n, &inv));
+    sum = w.add(&sum, &half_term);
+    let inv2 = w.mul(&inv, &inv);
+    let mut pow = w.mul(&inv_n, &inv2);
+    // (2k + n - 1)! / (2k)! for k = 1
+    let mut coef = w.mul(&fact_n, &w.int(n_int + 1));
+    coef = w.mul(&coef, &w.half());
+    for (idx, bernoulli) in series_bernoulli(w, n).iter().enumerate() {
+        let k = idx as i64 + 1;
+        let term = w.mul(&w.mul(bernoulli, &coef), &pow);
+        if negligible(&term, &sum, w.p) {
+            break;
+        }
+        sum = w.add(&sum, &term);
+        pow = w.mul(&pow, &inv2);
+        let num = w.int((2 * k + n_int) * (2 * k + n_int + 1));
+        let den = w.int((2 * k + 1) * (2 * k + 2));
+        coef = w.div(&w.mul(&coef, &num), &den);
+    }
+    let total = w.add(&sum, &w.mul(&fact_n, &shifted));
+    match n % 2 {
+        0 => -total,
+        _ => total,
+    }
+}
+
+/// Returns `polygamma(n, x)` for any `x` that is not a pole, with `n = 0` being digamma.
+pub(super) fn polygamma(w: &mut Work, n: usize, x: &Float) -> Float {
+    if *x >= w.half() {
+        return match n {
+            0 => digamma_pos(w, x),
+            _ => polygamma_pos(w, n, x),
+        };
+    }
+    // polygamma(n, x) = (-1)^n polygamma(n, 1 - x) - pi^(n + 1) P_n(cot(pi x)), where P_n is the
+    // polynomial with d^n/dx^n cot(pi x) = pi^n P_n(cot(pi x))
+    let one_minus = w.sub(&w.int(1), x);
+    let mut reflected = match n {
+        0 => digamma_pos(w, &one_minus),
+        _ => polygamma_pos(w, n, &one_minus),
+    };
+    if n % 2 == 1 {
+        reflected = -reflected;
+    }
+    let cos = w.cos_pi(x);
+    let sin = w.sin_pi(x);
+    let cot = w.div(&cos, &sin);
+    let mut poly = Vec::from([w.int(0), w.int(1)]);
+    for _ in 0..n {
+        // P_(k + 1)(c) = -(1 + c^2) P_k'(c)
+        let deriv: Vec<Float> = (1..poly.len())
+            .map(|i| w.mul(&poly[i], &w.int(i as i64)))
+            .collect();
+        poly = (0..deriv.len() + 2)
+            .map(|i| {
+                let low = deriv.get(i).cloned().unwrap_or_else(|| w.int(0));
+                let high = match i {
+                    0 | 1 => w.int(0),
+                    _ => deriv.get(i - 2).cloned().unwrap_or_else(|| w.int(0)),
+                };
+                -w.add(&low, &high)
+            })
+            .collect();
+    }
+    let value = poly
+        .iter()
+        .rev()
+        .fold(w.int(0), |acc, coef| w.add(&w.mul(&acc, &cot), coef));
+    let pi = w.pi();
+    let pi_pow = w.powi(&pi, n + 1);
+    w.sub(&reflected, &w.mul(&pi_pow, &value))
+}
+
+/// Returns `n` if `x` is a non-negative integer of at most [`EXACT_MAX`].
+fn small_int<CTX: BigFloatCtx>(x: &BigFloat<CTX>) -> Option<u64> {
+    if !x.num.is_int() || x.num.is_negative() {
+        return None;
+    }
+    match x.as_int() {
+        Some((_, n)) if n <= EXACT_MAX => Some(n as u64),
+        _ => None,
+    }
+}
+
+impl<CTX: BigFloatCtx> BigFloat<CTX> {
+    /// Returns the gamma function of this number, which extends the factorial with
+    /// `gamma(n) = (n - 1)!`.
+    ///
+    /// Negative arguments use the reflection formula `gamma(x) gamma(1 - x) = pi / sin(pi x)`.
+    /// Zero gives infinity with the sign of the zero, and negative integers and negative infinity
+    /// give `NaN`. Positive integers up to 1024 are computed exactly before rounding.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use astro_nalgebra::{BigFloat, ConstCtx};
+    /// use nalgebra::{ComplexField, RealField};
+    ///
+    /// type BF256 = BigFloat<ConstCtx<256>>;
+    ///
+    /// let half: BF256 = "0.5".parse().unwrap();
+    /// let diff = half.gamma() - BF256::pi().sqrt();
+    /// assert!(diff.abs() < "1e-75".parse().unwrap());
+    /// assert_eq!(BF256::from(5.0).gamma(), BF256::from(24.0));
+    /// ```
+    pub fn gamma(&self) -> Self {
+        gamma_value(&self.num)
+    }
+
+    /// Returns the natural logarithm of the absolute value of the gamma function along with the
+    /// sign of the gamma function, like C's `lgamma`.
+    ///
+    /// The logarithm stays finite for arguments where [`BigFloat::gamma`] overflows. Zero,
+    /// negative integers and infinities give positive infinity, and `NaN` gives `NaN`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use astro_nalgebra::{BigFloat, ConstCtx, Sign};
+    ///
+    /// type BF256 = BigFloat<ConstCtx<256>>;
+    ///
+    /// let (ln, sign) = BF256::from(-0.5).lgamma();
+    /// assert_eq!(sign, Sign::Neg);
+    /// assert!((ln.as_f64() - 1.2655121234846454).abs() < 1e-15);
+    /// let (ln, _) = BF256::from(1e10).lgamma();
+    /// assert!((ln.as_f64() - 220258509288.81).abs() < 0.01);
+    /// ```
+    pub fn lgamma(&self) -> (Self, Sign) {
+        let x = &self.num;
+        if x.is_nan() {
+            return (self.clone(), Sign::Pos);
+        }
+        if x.is_inf() || is_nonpositive_int(x) {
+            return (BigFloat::from(astro_float::INF_POS), sign_of(x));
+        }
+        if let Some(n) = small_int(self) {
+            let fact = exact_factorial(n - 1);
+            return (run::<CTX, _>(0, |w| round(w.ln(&fact))), Sign::Pos);
+        }
+        let (ln, sign) = run::<CTX, _>(0, |w| ln_gamma(w, x));
+        (round(ln), sign)
+    }
+
+    /// Returns the digamma function of this number, the derivative of the logarithm of the gamma
+    /// function.
+    ///
+    /// Negative arguments use the reflection formula
+    /// `digamma(1 - x) - digamma(x) = pi cot(pi x)`. Zero gives infinity with the opposite sign of
+    /// the zero, negative integers and negative infinity give `NaN`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use astro_nalgebra::{BigFloat, ConstCtx};
+    ///
+    /// type BF256 = BigFloat<ConstCtx<256>>;
+    ///
+    /// // digamma(1) is minus the Euler-Mascheroni constant
+    /// let gamma = BF256::from(1.0).digamma();
+    /// assert!((gamma.as_f64() + 0.5772156649015329).abs() < 1e-16);
+    /// ```
+    pub fn digamma(&self) -> Self {
+        self.polygamma(0)
+    }
+
+    /// Returns the polygamma function of order `n` of this number, the `n`th derivative of
+    /// [`BigFloat::digamma`]. Order zero is the digamma function itself.
+    ///
+    /// Negative arguments use the derivatives of the reflection formula of digamma. At the poles,
+    /// zero and the negative integers, odd orders give positive infinity and even orders give
+    /// `NaN`, or an infinity for a signed zero.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use astro_nalgebra::{BigFloat, ConstCtx};
+    /// use nalgebra::RealField;
+    ///
+    /// type BF256 = BigFloat<ConstCtx<256>>;
+    ///
+    /// // polygamma(1, 1) = pi^2 / 6
+    /// let trigamma = BF256::from(1.0).polygamma(1);
+    /// let expected = BF256::pi() * BF256::pi() / BF256::from(6.0);
+    /// assert!((trigamma - expected).as_f64().abs() < 1e-75);
+    /// ```
+    pub fn polygamma(&self, n: u32) -> Self {
+        let x = &self.num;
+        let n = n as usize;
+        if x.is_nan() {
+            return self.clone();
+        }
+        if x.is_inf_pos() {
+            return match n {
+                0 => self.clone(),
+                _ => BigFloat::from(word(0)),
+            };
+        }
+        if x.is_zero() {
+            // polygamma(n, x) behaves like (-1)^(n + 1) n! / x^(n + 1) near zero
+            let sign = match n % 2 {
+                0 => mul_sign(sign_of(x), Sign::Neg),
+                _ => Sign::Pos,
+            };
+            return BigFloat::from(inf(sign));
+        }
+        if x.is_inf() || is_nonpositive_int(x) {
+            return match n % 2 {
+                0 => BigFloat::from(Float::nan(None)),
+                _ => BigFloat::from(astro_float::INF_POS),
+            };
+        }
+        round(run::<CTX, _>(0, |w| polygamma(w, n, x)))
+    }
+
+    /// Returns the beta function `gamma(a) gamma(b) / gamma(a + b)` of this number and `b`.
+    ///
+    /// The result is computed from the logarithms of the gamma functions, so it does not overflow
+    /// when the gamma functions do. It is zero when `a + b` is a pole of gamma and `NaN` when `a`
+    /// or `b` is a pole. Positive integer arguments are computed exactly before rounding.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use astro_nalgebra::{BigFloat, ConstCtx};
+    /// use nalgebra::RealField;
+    ///
+    /// type BF256 = BigFloat<ConstCtx<256>>;
+    ///
+    /// let half = BF256::from(0.5);
+    /// assert!((half.beta(&half) - BF256::pi()).as_f64().abs() < 1e-75);
+    /// let twelfth = BF256::from(1.0) / BF256::from(12.0);
+    /// assert_eq!(BF256::from(2.0).beta(&BF256::from(3.0)), twelfth);
+    /// ```
+    pub fn beta(&self, b: &Self) -> Self {
+        let (x, y) = (&self.num, &b.num);
+        if x.is_nan() || y.is_nan() || is_nonpositive_int(x) || is_nonpositive_int(y) {
+            return BigFloat::from(Float::nan(None));
+        }
+        if x.is_inf() || y.is_inf() {
+            // beta(a, b) tends to zero when one argument grows and the other one is positive
+            return match x.is_inf_pos() && y.is_positive() || y.is_inf_pos() && x.is_positive() {
+                true => BigFloat::from(word(0)),
+                false => BigFloat::from(Float::nan(None)),
+            };
+        }
+        if let (Some(m), Some(n)) = (small_int(self), small_int(b)) {
+            if m + n <= EXACT_MAX as u64 + 1 {
+                let num = exact_mul(&exact_factorial(m - 1), &exact_factorial(n - 1));
+                let den = exact_factorial(m + n - 1);
+                return BigFloat::from(num.div(&den, CTX::get_prec(), CTX::get_rm()));
+            }
+        }
+        let extra = magnitude_bits(x).max(magnitude_bits(y)) + 1;
+        round(run::<CTX, _>(extra, |w| {
+            let sum = w.add(x, y);
+            if is_nonpositive_int(&sum) {
+                return word(0);
+            }
+            let (ln_x, sign_x) = ln_gamma(w, x);
+            let (ln_y, sign_y) = ln_gamma(w, y);
+            let (ln_sum, sign_sum) = ln_gamma(w, &sum);
+            let ln = w.sub(&w.add(&ln_x, &ln_y), &ln_sum);
+            let beta = w.exp(&ln);
+            match mul_sign(mul_sign(sign_x, sign_y), sign_sum) {
+                Sign::Pos => beta,
+                Sign::Neg => -beta,
+            }
+        }))
+    }
+
+    /// Returns the factorial of this number, `gamma(x + 1)`, which is also defined for
+    /// non-integer arguments.
+    ///
+    /// Integers up to 1023 are computed exactly before rounding, and negative integers give
+    /// `NaN`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use astro_nalgebra::{BigFloat, ConstCtx};
+    ///
+    /// type BF256 = BigFloat<ConstCtx<256>>;
+    ///
+    /// assert_eq!(BF256::from(20.0).factorial(), BF256::from(2432902008176640000.0));
+    /// let half_fact = BF256::from(0.5).factorial();
+    /// assert!((half_fact.as_f64() - 0.886226925452758).abs() < 1e-15);
+    /// ```
+    pub fn factorial(&self) -> Self {
+        let x = &self.num;
+        if x.is_int() && x.is_negative() && !x.is_zero() {
+            return BigFloat::from(Float::nan(None));
+        }
+        // Huge arguments are integers, so adding one exactly is not needed
+        if x.is_nan() || x.is_inf() || magnitude_bits(x) >= 64 {
+            return gamma_value(x);
+        }
+        gamma_value(&exact_add(x, &word(1)))
+    }
+
+    /// Returns the binomial coefficient of this number over `k`, which is also defined for
+    /// non-integer arguments.
+    ///
+    /// For an integer `k`, this is `x (x - 1) ... (x - k + 1) / k!`, which is zero for negative
+    /// `k`. Integer arguments are computed exactly before rounding when `k` or `x - k` is at most
+    /// 1024, and other arguments use `gamma(x + 1) / (gamma(k + 1) gamma(x - k + 1))`, which is
+    /// zero when only the denominator has a pole and `NaN` when the numerator has one.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use astro_nalgebra::{BigFloat, ConstCtx};
+    ///
+    /// type BF256 = BigFloat<ConstCtx<256>>;
+    ///
+    /// let ten = BF256::from(10.0);
+    /// assert_eq!(ten.binomial(&BF256::from(3.0)), BF256::from(120.0));
+    /// assert_eq!(BF256::from(-1.0).binomial(&BF256::from(5.0)), BF256::from(-1.0));
+    /// assert_eq!(BF256::from(0.5).binomial(&BF256::from(2.0)), BF256::from(-0.125));
+    /// ```
+    pub fn binomial(&self, k: &Self) -> Self {
+        let (x, k_num) = (&self.num, &k.num);
+        if x.is_nan() || k_num.is_nan() || x.is_inf() || k_num.is_inf() {
+            return BigFloat::from(Float::nan(None));
+        }
+        if k_num.is_int() {
+            if k_num.is_negative() && !k_num.is_zero() {
+                return BigFloat::from(word(0));
+            }
+            if let Some(result) = exact_binomial::<CTX>(x, k_num) {
+                return result;
+            }
+            if let Some(k) = small_int(k) {
+                // x (x - 1) ... (x - k + 1) / k!
+                return round(run::<CTX, _>(0, |w| {
+                    let mut num = w.int(1);
+                    let mut den = w.int(1);
+                    for i in 0..k as i64 {
+                        num = w.mul(&num, &w.sub(x, &w.int(i)));
+                        den = w.mul(&den, &w.int(i + 1));
+                    }
+                    w.div(&num, &den)
+                }));
+            }
+        }
+        if x.is_int() && x.is_negative() && !x.is_zero() {
+            return BigFloat::from(Float::nan(None));
+        }
+        let extra = magnitude_bits(x).max(magnitude_bits(k_num)) + 1;
+        round(run::<CTX, _>(extra, |w| {
+            let one = w.int(1);
+            let x_1 = w.add(x, &one);
+            let k_1 = w.add(k_num, &one);
+            let diff_1 = w.add(&w.sub(x, k_num), &one);
+            if is_nonpositive_int(&k_1) || is_nonpositive_int(&diff_1) {
+                return word(0);
+            }
+            let (ln_x, sign_x) = ln_gamma(w, &x_1);
+            let (ln_k, sign_k) = ln_gamma(w, &k_1);
+            let (ln_diff, sign_diff) = ln_gamma(w, &diff_1);
+            let ln = w.sub(&w.sub(&ln_x, &ln_k), &ln_diff);
+            let binomial = w.exp(&ln);
+            match mul_sign(mul_sign(sign_x, sign_k), sign_diff) {
+                Sign::Pos => binomial,
+                Sign::Neg => -binomial,
+            }
+        }))
+    }
+}
+
+/// Returns the gamma function of `x`, see [`BigFloat::gamma`].
+fn gamma_value<CTX: BigFloatCtx>(x: &Float) -> BigFloat<CTX> {
+    if x.is_nan() || x.is_inf_pos() {
+        return BigFloat::from(x.clone());
+    }
+    if x.is_zero() {
+        return BigFloat::from(inf(sign_of(x)));
+    }
+    if x.is_inf() || is_nonpositive_int(x) {
+        return BigFloat::from(Float::nan(None));
+    }
+    if let Some(n) = small_int(&BigFloat::<CTX>::from(x.clone())) {
+        return round(exact_factorial(n - 1));
+    }
+    round(run::<CTX, _>(magnitude_bits(x), |w| gamma(w, x)))
+}
+
+/// Returns the binomial coefficient of the integer `x` over the non-negative integer `k` when it
+/// can be computed exactly.
+fn exact_binomial<CTX: BigFloatCtx>(x: &Float, k: &Float) -> Option<BigFloat<CTX>> {
+    if !x.is_int() || magnitude_bits(x) >= 64 || magnitude_bits(k) >= 64 {
+        return None;
+    }
+    let (x_sign, x_abs) = BigFloat::<CTX>::from(x.clone()).as_int()?;
+    let (_, k) = BigFloat::<CTX>::from(k.clone()).as_int()?;
+    let (x_abs, k) = (x_abs as u64, k as u64);
+    // binomial(x, k) = (-1)^k binomial(k - x - 1, k) for negative x
+    let (n, negative) = match x_sign {
+        Sign::Neg if x_abs > 0 => (k.checked_add(x_abs - 1)?, k % 2 == 1),
+        _ => (x_abs, false),
+    };
+    if k > n {
+        return Some(BigFloat::from(word(0)));
+    }
+    let k = k.min(n - k);
+    if k as u128 > EXACT_MAX {
+        return None;
+    }
+    let num = (n - k + 1..=n).fold(word(1), |acc, i| exact_mul(&acc, &Float::from_u64(i, 64)));
+    let num = match negative {
+        true => -num,
+        false => num,
+    };
+    let den = exact_factorial(k);
+    Some(BigFloat::from(num.div(
+        &den,
+        CTX::get_prec(),
+        CTX::get_rm(),
+    )))
+}
@@ -0,0 +1,196 @@
+// This file contains the two real branches of the Lambert W function, the inverse of w exp(w).
+//
+// Both branches are refined with Halley's method on w exp(w) - x from an estimate that is already
+// a few bits accurate: the series in p = sqrt(2 (1 + e x)) close to the branch point -1/e, and
+// logarithmic estimates elsewhere. The steps divide by 1 + w, which vanishes at the branch point,
+// so close to it 1 + e x is computed without cancellation and the iteration runs with enough
+// extra bits to make up for the division.
+use super::{magnitude_bits, negligible, round, run, Float, Work, GUARD_BITS};
+use crate::fmt::word;
+use crate::{BigFloat, BigFloatCtx};
+
+/// Returns `1 + e x` to the working precision, with as many extra bits as the cancellation close
+/// to the branch point needs.
+fn branch_offset(w: &mut Work, x: &Float) -> Float {
+    let mut extra = 0;
+    loop {
+        let q = w.extended(extra, |w| {
+            let e = w.exp(&w.int(1));
+            w.add(&w.int(1), &w.mul(&e, x))
+        });
+        // x is never exactly -1/e, so a zero only means that more bits were lost
+        let lost = match q.exponent() {
+            Some(exp) if !q.is_zero() => (-exp).max(0) as usize,
+            _ => 2 * extra + 64,
+        };
+        if lost <= extra {
+            return q;
+        }
+        extra = lost + 8;
+    }
+}
+
+/// Returns the estimate `-1 + p - p^2 / 3 + 11 p^3 / 72 - 43 p^4 / 540` of the branch with
+/// `W(x) = -1 + p + O(p^2)`, where `p = sqrt(2 q)` for the principal branch and `-sqrt(2 q)` for
+/// the lower one, and `q = 1 + e x`.
+fn branch_series(w: &Work, p: &Float) -> Float {
+    let coefs = [(-43, 540), (11, 72), (-1, 3), (1, 1)];
+    let sum = coefs.iter().fold(w.int(0), |sum, &(num, den)| {
+        let coef = w.div(&w.int(num), &w.int(den));
+        w.mul(&w.add(&sum, &coef), p)
+    });
+    w.sub(&sum, &w.int(1))
+}
+
+/// Returns the estimate `L1 - L2 + L2 / L1` with `L1 = ln(|x|)` and `L2 = ln(|L1|)`, which is
+/// accurate for large `x` on the principal branch and tiny `x` on the lower one.
+fn log_estimate(w: &mut Work, x: &Float) -> Float {
+    let l1 = w.ln(&x.abs());
+    let l2 = w.ln(&l1.abs());
+    w.add(&w.sub(&l1, &l2), &w.div(&l2, &l1))
+}
+
+/// Returns an estimate of the principal branch for `x >= -1/e` that is not close to it, from
+/// Winitzki's approximation `L (1 - ln(1 + L) / (2 + L))` with `L = ln(1 + x)`.
+fn principal_estimate(w: &mut Work, x: &Float) -> Float {
+    if x.exponent().is_some_and(|exp| exp < -4) {
+        // W(x) = x - x^2 + O(x^3), without rounding 1 + x
+        return w.mul(x, &w.sub(&w.int(1), x));
+    }
+    let l = w.ln(&w.add(&w.int(1), x));
+    let ln = w.ln(&w.add(&w.int(1), &l));
+    let ratio = w.div(&ln, &w.add(&w.int(2), &l));
+    w.mul(&l, &w.sub(&w.int(1), &ratio))
+}
+
+/// Refines the estimate `v` of a branch at `x` with Halley's method on `f(v) = v exp(v) - x`,
+/// until a step no longer changes `v` at `bits` bits. The error left after the next step would
+/// be about the cube of the step.
+fn halley(w: &mut Work, x: &Float, mut v: Float, bits: usize) -> Float {
+    loop {
+        // v -= f / (exp(v) (v + 1) - (v + 2) f / (2 v + 2))
+        let exp = w.exp(&v);
+        let f = w.sub(&w.mul(&v, &exp), x);
+        if f.is_zero() {
+            return v;
+        }
+        let v1 = w.add(&v, &w.int(1));
+        let v2 = w.add(&v, &w.int(2));
+        let correction = w.div(&w.mul(&v2, &f), &w.mul(&v1, &w.int(2)));
+        let slope = w.sub(&w.mul(&exp, &v1), &correction);
+        let step = w.div(&f, &slope);
+        v = w.sub(&v, &step);
+        if negligible(&step, &v, bits) {
+            return v;
+        }
+    }
+}
+
+/// Returns the branch of `W(x)` for `x >= -1/e`, the principal one if `principal` and otherwise
+/// the lower one, which requires `x < 0`. Returns `None` if `x < -1/e`.
+fn lambert_w(w: &mut Work, x: &Float, principal: bool) -> Option<Float> {
+    let q = match x.is_negative() {
+        true => branch_offset(w, x),
+        false => word(1),
+    };
+    if q.is_negative() {
+        return None;
+    }
+    let half = w.half();
+    let estimate = match q < half {
+        true => {
+            let p = w.sqrt(&w.mul(&q, &w.int(2)));
+            let p = match principal {
+                true => p,
+                false => -p,
+            };
+            branch_series(w, &p)
+        }
+        false if principal => principal_estimate(w, x),
+        false => log_estimate(w, x),
+    };
+    // A rounding error in f moves the root by about the error over exp(w) (w + 1), and
+    // w + 1 is about sqrt(2 q) close to the branch point, while exp(w) amplifies the rounding
+    // error of large |w|. The extra bits only make up for this, so the tolerance stays at the
+    // precision of the context plus half of the guard bits.
+    let bits = w.p - GUARD_BITS / 2;
+    let extra =
+        q.exponent().map_or(0, |exp| (-exp).max(0) as usize) / 2 + magnitude_bits(&estimate);
+    Some(w.extended(extra, |w| halley(w, x, estimate, bits)))
+}
+
+impl<CTX: BigFloatCtx> BigFloat<CTX> {
+    /// Returns the principal branch of the Lambert W function at this number, the `w >= -1` with
+    /// `w exp(w) = x`.
+    ///
+    /// It is defined for `x >= -1/e`, and arguments below `-1/e` give `NaN`. Arguments close to
+    /// the branch point keep the full precision of the context, where `W0(x)` is about
+    /// `-1 + sqrt(2 (1 + e x))`. Positive infinity gives positive infinity.
+    ///
+    /// The result is refined with Halley's method until the steps are below the precision of the
+    /// context, which takes a few steps at any precision.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use astro_nalgebra::{BigFloat, ConstCtx};
+    ///
+    /// type BF256 = BigFloat<ConstCtx<256>>;
+    ///
+    /// // The omega constant, with omega exp(omega) = 1
+    /// let omega = BF256::from(1.0).lambert_w0();
+    /// assert!((omega.as_f64() - 0.5671432904097838).abs() < 1e-16);
+    /// assert!(BF256::from(-0.5).lambert_w0().as_f64().is_nan());
+    /// ```
+    pub fn lambert_w0(&self) -> Self {
+        let x = &self.num;
+        if x.is_nan() || x.is_zero() {
+            return self.clone();
+        }
+        if x.is_inf() {
+            return BigFloat::from(match x.is_negative() {
+                true => Float::nan(None),
+                false => astro_float::INF_POS,
+            });
+        }
+        match run::<CTX, _>(0, |w| lambert_w(w, x, true)) {
+            Some(value) => round(value),
+            None => BigFloat::from(Float::nan(None)),
+        }
+    }
+
+    /// Returns the lower branch of the Lambert W function at this number, the `w <= -1` with
+    /// `w exp(w) = x`.
+    ///
+    /// It is defined for `-1/e <= x < 0`, and decreases from `-1` at the branch point to negative
+    /// infinity at zero, which gives negative infinity. Other arguments give `NaN`. Like
+    /// [`BigFloat::lambert_w0`], arguments close to the branch point keep the full precision of
+    /// the context.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use astro_nalgebra::{BigFloat, ConstCtx};
+    ///
+    /// type BF256 = BigFloat<ConstCtx<256>>;
+    ///
+    /// let w = BF256::from(-0.25).lambert_wm1();
+    /// assert!((w.as_f64() + 2.153292364110349).abs() < 1e-15);
+    /// assert_eq!(BF256::from(0.0).lambert_wm1().as_f64(), f64::NEG_INFINITY);
+    /// assert!(BF256::from(0.5).lambert_wm1().as_f64().is_nan());
+    /// ```
+    pub fn lambert_wm1(&self) -> Self {
+        let x = &self.num;
+        if x.is_nan() {
+            return self.clone();
+        }
+        if x.is_zero() {
+            return BigFloat::from(astro_float::INF_NEG);
+        }
+        if x.is_inf() || x.is_positive() {
+            return BigFloat::from(Float::nan(None));
+        }
+        match run::<CTX, _>(0, |w| lambert_w(w, x, false)) {
+            Some(value) => round(value),
+            None => BigFloat::from(Float::nan(None)),
+        }
+    }
+}
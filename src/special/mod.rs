@@ -0,0 +1,361 @@
+// This module contains special functions of BigFloat, such as gamma and its relatives, and the
+// helpers they share.
+//
+// Each function is evaluated with astro_float at a working precision of the context plus guard
+// bits, inside a single access to the cache of the context so its constants and Bernoulli numbers
+// can be used, and the result is rounded to the context once at the end.
+extern crate alloc;
+use alloc::vec::Vec;
+
+use crate::ctx::{with_cache, BernoulliCache};
+use crate::fmt::{exact_add, exact_mul, pow_radix, word};
+use crate::{BigFloat, BigFloatCtx};
+use astro_float::{Consts, RoundingMode, Sign, Word};
+
+/// Bessel functions of real order and Airy functions, as free functions of the order and the
+/// argument.
+///
+/// They are the same as the methods [`BigFloat::bessel_j`], [`BigFloat::bessel_y`],
+/// [`BigFloat::bessel_i`], [`BigFloat::bessel_k`], [`BigFloat::airy_ai`] and
+/// [`BigFloat::airy_bi`], which describe how each one is computed. The series or asymptotic
+/// expansion is chosen from the precision of the context.
+///
+/// ## Example
+/// ```rust
+/// use astro_nalgebra::special::bessel;
+/// use astro_nalgebra::{BigFloat, ConstCtx};
+///
+/// type BF256 = BigFloat<ConstCtx<256>>;
+///
+/// let (nu, x) = (BF256::from(0.0), BF256::from(1.0));
+/// assert_eq!(bessel::j(&nu, &x), x.bessel_j(&nu));
+/// assert!((bessel::k(&nu, &x).as_f64() - 0.42102443824070834).abs() < 1e-16);
+/// ```
+pub mod bessel;
+mod erf;
+mod gamma;
+mod lambert;
+mod zeta;
+
+pub(crate) type Float = astro_float::BigFloat;
+
+/// Guard bits added to the precision of the context for intermediate results.
+const GUARD_BITS: usize = 64;
+
+/// Rounding mode of intermediate results.
+const RM: RoundingMode = RoundingMode::ToEven;
+
+/// Arithmetic rounded to nearest at the working precision `p`, with the constants and Bernoulli
+/// number caches of the context.
+pub(crate) struct Work<'a> {
+    pub(crate) p: usize,
+    cc: &'a mut Consts,
+    bernoulli: &'a mut BernoulliCache,
+}
+
+impl Work<'_> {
+    pub(crate) fn add(&self, a: &Float, b: &Float) -> Float {
+        a.add(b, self.p, RM)
+    }
+    pub(crate) fn sub(&self, a: &Float, b: &Float) -> Float {
+        a.sub(b, self.p, RM)
+    }
+    pub(crate) fn mul(&self, a: &Float, b: &Float) -> Float {
+        a.mul(b, self.p, RM)
+    }
+    pub(crate) fn div(&self, a: &Float, b: &Float) -> Float {
+        a.div(b, self.p, RM)
+    }
+    pub(crate) fn recip(&self, a: &Float) -> Float {
+        a.reciprocal(self.p, RM)
+    }
+    pub(crate) fn sqrt(&self, a: &Float) -> Float {
+        a.sqrt(self.p, RM)
+    }
+    pub(crate) fn powi(&self, a: &Float, n: usize) -> Float {
+        a.powi(n, self.p, RM)
+    }
+    pub(crate) fn int(&self, n: i64) -> Float {
+        Float::from_i64(n, self.p)
+    }
+    pub(crate) fn half(&self) -> Float {
+        Float::from_f64(0.5, self.p)
+    }
+    pub(crate) fn ln(&mut self, a: &Float) -> Float {
+        a.ln(self.p, RM, self.cc)
+    }
+    pub(crate) fn exp(&mut self, a: &Float) -> Float {
+        a.exp(self.p, RM, self.cc)
+    }
+    pub(crate) fn sin(&mut self, a: &Float) -> Float {
+        a.sin(self.p, RM, self.cc)
+    }
+    pub(crate) fn cos(&mut self, a: &Float) -> Float {
+        a.cos(self.p, RM, self.cc)
+    }
+    pub(crate) fn atan(&mut self, a: &Float) -> Float {
+        a.atan(self.p, RM, self.cc)
+    }
+    pub(crate) fn pi(&mut self) -> Float {
+        self.cc.pi(self.p, RM)
+    }
+
+    /// Returns `h^nu` for `h > 0`.
+    pub(crate) fn pow(&mut self, h: &Float, nu: &Float) -> Float {
+        if nu.is_int() && magnitude_bits(nu) < 32 {
+            let pow = self.powi(h, int_part(nu));
+            return match nu.is_negative() {
+                true => self.recip(&pow),
+                false => pow,
+            };
+        }
+        // The rounding error of the logarithm grows with the exponent
+        let ln = self.ln(h);
+        let extra = magnitude_bits(&self.mul(nu, &ln));
+        self.extended(extra, |w| {
+            let ln = w.ln(h);
+            w.exp(&w.mul(nu, &ln))
+        })
+    }
+
+    /// Returns the Bernoulli numbers `B_2, B_4, ..., B_2count` rounded to at least the working
+    /// precision, from the cache of the context when it has enough of them.
+    pub(crate) fn bernoulli(&mut self, count: usize) -> Vec<Float> {
+        let cache = &mut *self.bernoulli;
+        if cache.values.len() < count || cache.prec < self.p {
+            cache.prec = cache.prec.max(self.p);
+            cache.values = bernoulli(count.max(cache.values.len()), cache.prec);
+        }
+        cache.values[..count].to_vec()
+    }
+
+    /// Runs `f` with `extra` more bits of working precision.
+    pub(crate) fn extended<R>(&mut self, extra: usize, f: impl FnOnce(&mut Work<'_>) -> R) -> R {
+        f(&mut Work {
+            p: self.p + extra,
+            cc: self.cc,
+            bernoulli: self.bernoulli,
+        })
+    }
+
+    /// Returns `sin(pi * x)`, reducing `x` to `[-1/2, 1/2]` first so it stays accurate near the
+    /// zeros.
+    pub(crate) fn sin_pi(&mut self, x: &Float) -> Float {
+        let (n, r) = reduce_half(x);
+        let pi = self.pi();
+        let sin = self.sin(&self.mul(&pi, &r));
+        match is_odd(&n) {
+            true => -sin,
+            false => sin,
+        }
+    }
+
+    /// Returns `cos(pi * x)`, see [`Work::sin_pi`].
+    pub(crate) fn cos_pi(&mut self, x: &Float) -> Float {
+        let (n, r) = reduce_half(x);
+        let pi = self.pi();
+        let cos = self.cos(&self.mul(&pi, &r));
+        match is_odd(&n) {
+            true => -cos,
+            false => cos,
+        }
+    }
+
+    /// Returns `ln(1 + x)`. The sum `1 + x` is formed with the bits `x` lies below one, so small
+    /// `x` keep their digits, and very small `x` use the series instead.
+    pub(crate) fn ln_1p(&mut self, x: &Float) -> Float {
+        let small = small_bits(x);
+        if x.is_zero() || small == 0 {
+            let sum = self.add(&self.int(1), x);
+            return self.ln(&sum);
+        }
+        if small * 8 < self.p {
+            return self.extended(small, |w| {
+                let sum = w.add(&w.int(1), x);
+                w.ln(&sum)
+            });
+        }
+        // x - x^2/2 + x^3/3 - ..., where each term is 2^small times smaller than the last
+        let mut sum = x.clone();
+        let mut pow = x.clone();
+        for k in 2.. {
+            pow = self.mul(&pow, x);
+            let term = self.div(&pow, &self.int(k));
+            if negligible(&term, &sum, self.p) {
+                break;
+            }
+            sum = match k % 2 {
+                0 => self.sub(&sum, &term),
+                _ => self.add(&sum, &term),
+            };
+        }
+        sum
+    }
+
+    /// Returns `exp(x) - 1`, with the bits lost to the subtraction added to the precision of the
+    /// exponential, or the series for very small `x`.
+    pub(crate) fn exp_m1(&mut self, x: &Float) -> Float {
+        let small = small_bits(x);
+        if x.is_zero() || small == 0 {
+            let exp = self.exp(x);
+            return self.sub(&exp, &self.int(1));
+        }
+        if small * 8 < self.p {
+            return self.extended(small, |w| {
+                let exp = w.exp(x);
+                w.sub(&exp, &w.int(1))
+            });
+        }
+        // x + x^2/2! + x^3/3! + ...
+        let mut sum = x.clone();
+        let mut term = x.clone();
+        for k in 2.. {
+            term = self.div(&self.mul(&term, x), &self.int(k));
+            if negligible(&term, &sum, self.p) {
+                break;
+            }
+            sum = self.add(&sum, &term);
+        }
+        sum
+    }
+}
+
+/// Runs `f` at the precision of the context plus guard bits and `extra` bits.
+pub(crate) fn run<CTX: BigFloatCtx, R>(extra: usize, f: impl FnOnce(&mut Work<'_>) -> R) -> R {
+    with_cache(CTX::get_prec(), CTX::get_rm(), |cache| {
+        let p = cache.ctx.precision() + GUARD_BITS + extra;
+        f(&mut Work {
+            p,
+            cc: cache.ctx.consts(),
+            bernoulli: &mut cache.bernoulli,
+        })
+    })
+}
+
+/// Rounds a result to the precision and rounding mode of the context.
+pub(crate) fn round<CTX: BigFloatCtx>(mut num: Float) -> BigFloat<CTX> {
+    if let Err(err) = num.set_precision(CTX::get_prec(), CTX::get_rm()) {
+        num = Float::nan(Some(err));
+    }
+    BigFloat::from(num)
+}
+
+/// Returns true if adding `term` to `sum` does not change it at precision `p`.
+pub(crate) fn negligible(term: &Float, sum: &Float, p: usize) -> bool {
+    match (term.exponent(), sum.exponent()) {
+        _ if term.is_zero() => true,
+        _ if sum.is_zero() => false,
+        (Some(term), Some(sum)) => (term as i64) + (p as i64) < sum as i64,
+        _ => false,
+    }
+}
+
+/// Returns the number of bits of the integer part of `|x|`, at most 64. Results that grow like
+/// `exp(x)` need this many extra bits to stay accurate.
+pub(crate) fn magnitude_bits(x: &Float) -> usize {
+    x.exponent().map_or(0, |exp| exp.clamp(0, 64) as usize)
+}
+
+/// Returns the number of bits `|x|` lies below one, or zero if it is at least one half.
+fn small_bits(x: &Float) -> usize {
+    x.exponent()
+        .map_or(0, |exp| exp.min(0).unsigned_abs() as usize)
+}
+
+/// Returns the integer part of `|x|`, or `usize::MAX` if it does not fit in one word.
+pub(crate) fn int_part(x: &Float) -> usize {
+    match x.as_raw_parts() {
+        Some((_, _, _, exp, _)) if exp > Word::BITS as i32 => usize::MAX,
+        Some((mantissa, _, _, exp, _)) if exp > 0 => {
+            (mantissa[mantissa.len() - 1] >> (Word::BITS as i32 - exp)) as usize
+        }
+        _ => 0,
+    }
+}
+
+/// Returns true if `x` is zero or a negative integer.
+pub(crate) fn is_nonpositive_int(x: &Float) -> bool {
+    x.is_int() && !x.is_positive() || x.is_zero()
+}
+
+/// Returns true if the integer `n` is odd.
+pub(crate) fn is_odd(n: &Float) -> bool {
+    !exact_mul(n, &Float::from_f64(0.5, 64)).is_int()
+}
+
+/// Splits `x` into the nearest integer `n` and `x - n` in `[-1/2, 1/2]`, both exact.
+fn reduce_half(x: &Float) -> (Float, Float) {
+    let n = exact_add(x, &Float::from_f64(0.5, 64)).floor();
+    let r = exact_add(x, &-&n);
+    (n, r)
+}
+
+/// Returns the infinity with the given sign.
+pub(crate) fn inf(sign: Sign) -> Float {
+    match sign {
+        Sign::Pos => astro_float::INF_POS,
+        Sign::Neg => astro_float::INF_NEG,
+    }
+}
+
+/// Returns the sign of a number that is not NaN.
+pub(crate) fn sign_of(x: &Float) -> Sign {
+    match x.is_negative() {
+        true => Sign::Neg,
+        false => Sign::Pos,
+    }
+}
+
+/// Returns the sign of a product of numbers with the signs `a` and `b`.
+pub(crate) fn mul_sign(a: Sign, b: Sign) -> Sign {
+    match a == b {
+        true => Sign::Pos,
+        false => Sign::Neg,
+    }
+}
+
+/// Returns `n!` exactly.
+pub(crate) fn exact_factorial(n: u64) -> Float {
+    (2..=n).fold(word(1), |acc, i| exact_mul(&acc, &Float::from_u64(i, 64)))
+}
+
+/// Returns the Bernoulli numbers `B_2, B_4, ..., B_2count` rounded to `p` bits, see
+/// [`Work::bernoulli`] for the cached ones.
+///
+/// They are computed from the tangent numbers with the algorithm of Brent and Harvey, which
+/// only needs exact integer additions and multiplications by small integers, followed by one
+/// division for each number.
+fn bernoulli(count: usize, p: usize) -> Vec<Float> {
+    let mut tangent: Vec<Float> = Vec::with_capacity(count);
+    if count == 0 {
+        return tangent;
+    }
+    tangent.push(word(1));
+    for k in 1..count {
+        let next = exact_mul(&tangent[k - 1], &word(k as Word));
+        tangent.push(next);
+    }
+    for k in 1..count {
+        for j in k..count {
+            let lower = exact_mul(&tangent[j - 1], &word((j - k) as Word));
+            let upper = exact_mul(&tangent[j], &word((j - k + 2) as Word));
+            tangent[j] = exact_add(&lower, &upper);
+        }
+    }
+    // B_2k = (-1)^(k - 1) 2k T_k / (4^k (4^k - 1))
+    tangent
+        .iter()
+        .enumerate()
+        .map(|(idx, tangent)| {
+            let k = idx + 1;
+            let power = pow_radix(4, k);
+            let den = exact_mul(&power, &exact_add(&power, &-word(1)));
+            let num = exact_mul(tangent, &word(2 * k as Word));
+            let bernoulli = num.div(&den, p, RM);
+            match k % 2 {
+                0 => -bernoulli,
+                _ => bernoulli,
+            }
+        })
+        .collect()
+}
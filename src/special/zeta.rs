@@ -0,0 +1,628 @@
+// This file contains the Riemann and Hurwitz zeta functions, the Dirichlet eta function and the
+// polylogarithm of integer order.
+//
+// The zeta functions are summed directly up to a number of terms that grows with the working
+// precision, and the tail is the Euler-Maclaurin formula with the Bernoulli numbers cached in the
+// context. Arguments of the Riemann zeta function below 1/2 use the functional equation, and the
+// Hurwitz zeta function far below zero uses Hurwitz's formula, a Fourier series in a. The
+// polylogarithm is a power series for |x| <= 1/2, and a series in ln(x) whose coefficients are
+// zeta values closer to one. Other arguments are brought into these ranges with the duplication
+// and inversion formulas. Negative orders are rational functions with the Eulerian numbers as
+// coefficients, and large negative orders use a series in 1 / (2 pi i k - ln(x))^(1 - n) instead.
+extern crate alloc;
+use alloc::vec::Vec;
+
+use super::gamma::{gamma, ln_gamma_pos};
+use super::{
+    exact_factorial, int_part, is_odd, magnitude_bits, negligible, round, run, Float, Work,
+};
+use crate::fmt::{exact_add, exact_mul, word};
+use crate::{BigFloat, BigFloatCtx};
+use astro_float::Word;
+use core::cmp::Ordering;
+
+/// Returns the point `b` where the zeta functions switch from the direct sum to the tail, for
+/// `sigma = max(-s, 0)` at precision `p`. The terms of the tail shrink like
+/// `((|s| + 2j) / (2 pi b))^2`, so `2 pi b` is kept at twice `|s| + 2j` for every Bernoulli number
+/// used.
+fn tail_start(p: usize, sigma: usize) -> usize {
+    sigma.saturating_add(2 * tail_bernoulli(p)) / 3 + 1
+}
+
+/// Returns the number of Bernoulli numbers used by the tails of the series at precision `p`.
+fn tail_bernoulli(p: usize) -> usize {
+    p / 4 + 16
+}
+
+/// Returns the number of bits lost when `1 - x` is rounded, for `x` close to one.
+fn near_one_bits(x: &Float) -> usize {
+    let diff = exact_add(&word(1), &-x.clone());
+    diff.exponent().map_or(0, |exp| (-exp).max(0) as usize)
+}
+
+/// Returns `zeta(s, a)` for `s != 1` and `a > 0`.
+fn hurwitz(w: &mut Work, s: &Float, a: &Float) -> Float {
+    let sigma = match s.is_negative() {
+        true => int_part(s),
+        false => 0,
+    };
+    // Far below zero the Bernoulli numbers the tail needs are too many, unless a is already past
+    // the start of the tail
+    if sigma >= w.p / 8 && int_part(a) < tail_start(w.p, sigma) {
+        return hurwitz_fourier(w, s, a);
+    }
+    // For negative s the terms grow like b^-s and cancel to a much smaller result. The lost bits
+    // make the tail longer, which moves b further out.
+    let mut extra = 0;
+    let count = loop {
+        let count = tail_start(w.p + extra, sigma).saturating_sub(int_part(a));
+        let bits = magnitude_bits(a).max((usize::BITS - count.leading_zeros()) as usize) + 1;
+        let lost = match count > 0 && s.is_negative() {
+            true => (sigma + 1).saturating_mul(bits),
+            false => 0,
+        };
+        if lost <= extra {
+            break count;
+        }
+        extra = lost;
+    };
+    w.extended(extra, |w| euler_maclaurin(w, s, a, count))
+}
+
+/// Returns `zeta(s, a)` from the Euler-Maclaurin formula, after summing `count` terms directly.
+fn euler_maclaurin(w: &mut Work, s: &Float, a: &Float, count: usize) -> Float {
+    let one = w.int(1);
+    let neg_s = -s.clone();
+    let mut sum = w.int(0);
+    let mut b = a.clone();
+    for _ in 0..count {
+        let term = w.pow(&b, &neg_s);
+        sum = w.add(&sum, &term);
+        b = w.add(&b, &one);
+    }
+    // zeta(s, b) = b^(1 - s) / (s - 1) + b^-s / 2
+    //     + sum of B_2j / (2j)! s (s + 1) ... (s + 2j - 2) b^(-s - 2j + 1)
+    let pow = w.pow(&b, &neg_s);
+    let s_1 = w.sub(s, &one);
+    sum = w.add(&sum, &w.div(&w.mul(&pow, &b), &s_1));
+    sum = w.add(&sum, &w.mul(&pow, &w.half()));
+    let inv_b = w.recip(&b);
+    let inv_b2 = w.mul(&inv_b, &inv_b);
+    let mut pow = w.mul(&pow, &inv_b);
+    let mut coef = w.mul(s, &w.half());
+    let mut last = None;
+    for (idx, bernoulli) in w.bernoulli(tail_bernoulli(w.p)).iter().enumerate() {
+        let term = w.mul(&w.mul(bernoulli, &coef), &pow);
+        // The coefficients become zero for negative integers s, and the series is asymptotic
+        // so it ends before the terms grow again
+        if negligible(&term, &sum, w.p) || last.is_some_and(|last| term.exponent() > Some(last)) {
+            break;
+        }
+        last = term.exponent();
+        sum = w.add(&sum, &term);
+        let j = idx as i64 + 1;
+        let num = w.mul(&w.add(s, &w.int(2 * j - 1)), &w.add(s, &w.int(2 * j)));
+        coef = w.div(&w.mul(&coef, &num), &w.int((2 * j + 1) * (2 * j + 2)));
+        pow = w.mul(&pow, &inv_b2);
+    }
+    sum
+}
+
+/// Returns `zeta(s, a)` for `s < 0` from Hurwitz's formula for `0 < a <= 1`,
+/// `2 gamma(1 - s) / (2 pi)^(1 - s) sum of sin(pi (s / 2 + 2 n a)) / n^(1 - s)`, whose terms
+/// shrink fast when `s` is far below zero. Larger `a` subtract the first terms of the sum.
+fn hurwitz_fourier(w: &mut Work, s: &Float, a: &Float) -> Float {
+    // a = a0 + m with 0 < a0 <= 1
+    let m = match a.is_int() {
+        true => int_part(a) - 1,
+        false => int_part(a),
+    };
+    let a0 = exact_add(a, &-word(m as Word));
+    let evaluate = |w: &mut Work| {
+        let one_s = w.sub(&w.int(1), s);
+        let neg_one_s = -one_s.clone();
+        let half_s = w.mul(s, &w.half());
+        let mut sum = w.int(0);
+        for n in 1.. {
+            let pow = w.pow(&w.int(n), &neg_one_s);
+            if pow.exponent().is_none_or(|exp| (-exp as i64) > w.p as i64) {
+                break;
+            }
+            let angle = exact_add(&half_s, &exact_mul(&a0, &word(2 * n as Word)));
+            let sin = w.sin_pi(&angle);
+            sum = w.add(&sum, &w.mul(&sin, &pow));
+        }
+        let gamma = gamma(w, &one_s);
+        let pi = w.pi();
+        let two_pi_pow = w.pow(&w.mul(&w.int(2), &pi), &one_s);
+        let factor = w.div(&w.mul(&w.int(2), &gamma), &two_pi_pow);
+        let fourier = w.mul(&factor, &sum);
+        // zeta(s, a) = zeta(s, a0) - sum of (a0 + k)^-s for k < m
+        let neg_s = -s.clone();
+        let mut powers = w.int(0);
+        let mut b = a0.clone();
+        for _ in 0..m {
+            let pow = w.pow(&b, &neg_s);
+            powers = w.add(&powers, &pow);
+            b = exact_add(&b, &word(1));
+        }
+        let result = w.sub(&fourier, &powers);
+        let largest = fourier.exponent().max(powers.exponent());
+        (result, largest)
+    };
+    // The two parts can cancel, by a number of bits that is known after the first evaluation
+    let (result, largest) = evaluate(w);
+    match (largest, result.exponent()) {
+        (Some(largest), Some(exp)) if largest > exp => {
+            w.extended((largest - exp) as usize, |w| evaluate(w).0)
+        }
+        _ => result,
+    }
+}
+
+/// Returns `zeta(s)` for `s != 1`.
+fn zeta(w: &mut Work, s: &Float) -> Float {
+    let one = w.int(1);
+    if s.is_zero() {
+        return -w.half();
+    }
+    if *s >= w.half() {
+        return hurwitz(w, s, &one);
+    }
+    // zeta(s) = 2^s pi^(s - 1) sin(pi s / 2) gamma(1 - s) zeta(1 - s)
+    let reflected = w.sub(&one, s);
+    let zeta = hurwitz(w, &reflected, &one);
+    let gamma = gamma(w, &reflected);
+    let half_s = w.mul(s, &w.half());
+    let sin = w.sin_pi(&half_s);
+    let two_pow = w.pow(&w.int(2), s);
+    let pi = w.pi();
+    let pi_pow = w.pow(&pi, &-reflected);
+    let factor = w.mul(&w.mul(&two_pow, &pi_pow), &sin);
+    w.mul(&w.mul(&factor, &gamma), &zeta)
+}
+
+/// Returns `eta(s)` for `s != 1`.
+fn eta(w: &mut Work, s: &Float) -> Float {
+    // eta(s) = (1 - 2^(1 - s)) zeta(s), where the first factor cancels close to s = 1
+    w.extended(near_one_bits(s), |w| {
+        let one = w.int(1);
+        let pow = w.pow(&w.int(2), &w.sub(&one, s));
+        let factor = w.sub(&one, &pow);
+        let zeta = zeta(w, s);
+        w.mul(&factor, &zeta)
+    })
+}
+
+/// Returns `Li_n(x)` for `n >= 1` and `|x| <= 1/2` from the series `sum of x^k / k^n`.
+fn li_series(w: &mut Work, n: usize, x: &Float) -> Float {
+    let mut pow = x.clone();
+    let mut sum = x.clone();
+    let mut k = 1;
+    loop {
+        k += 1;
+        pow = w.mul(&pow, x);
+        let term = w.div(&pow, &w.powi(&w.int(k), n));
+        if negligible(&term, &sum, w.p) {
+            return sum;
+        }
+        sum = w.add(&sum, &term);
+    }
+}
+
+/// Returns `Li_n(x)` for `n >= 2` and `1/2 < x < 1` from the series in `mu = ln(x)`,
+/// `mu^(n - 1) / (n - 1)! (H_(n - 1) - ln(-mu)) + sum over k != n - 1 of zeta(n - k) mu^k / k!`.
+fn li_log(w: &mut Work, n: usize, x: &Float) -> Float {
+    // mu keeps the bits of x that cancel in 1 - x
+    w.extended(near_one_bits(x), |w| {
+        let mu = w.ln(x);
+        let mut sum = w.int(0);
+        // mu^k / k!
+        let mut pow = w.int(1);
+        for k in 0..n {
+            let coef = match k == n - 1 {
+                true => {
+                    let harmonic =
+                        (1..n as i64).fold(w.int(0), |acc, i| w.add(&acc, &w.recip(&w.int(i))));
+                    let ln = w.ln(&mu.abs());
+                    w.sub(&harmonic, &ln)
+                }
+                false => zeta(w, &w.int((n - k) as i64)),
+            };
+            sum = w.add(&sum, &w.mul(&coef, &pow));
+            pow = w.div(&w.mul(&pow, &mu), &w.int(k as i64 + 1));
+        }
+        // zeta(0) = -1/2
+        sum = w.sub(&sum, &w.mul(&pow, &w.half()));
+        pow = w.div(&w.mul(&pow, &mu), &w.int(n as i64 + 1));
+        // zeta(1 - 2j) = -B_2j / 2j, and zeta is zero at the other negative integers
+        let mu2 = w.mul(&mu, &mu);
+        for (idx, bernoulli) in w.bernoulli(tail_bernoulli(w.p)).iter().enumerate() {
+            let j = idx as i64 + 1;
+            let term = -w.div(&w.mul(bernoulli, &pow), &w.int(2 * j));
+            if negligible(&term, &sum, w.p) {
+                break;
+            }
+            sum = w.add(&sum, &term);
+            let den = w.int((n as i64 + 2 * j) * (n as i64 + 2 * j + 1));
+            pow = w.div(&w.mul(&pow, &mu2), &den);
+        }
+        sum
+    })
+}
+
+/// Returns `Li_n(x)` for `n >= 1` and any finite `x < 1` other than zero.
+fn polylog(w: &mut Work, n: usize, x: &Float) -> Float {
+    let half = w.half();
+    let abs = x.abs();
+    if n == 1 && abs > half {
+        // Li_1(x) = -ln(1 - x), where 1 - x is exact
+        return -w.ln(&exact_add(&word(1), &-x.clone()));
+    }
+    if abs <= half {
+        return li_series(w, n, x);
+    }
+    if x.is_positive() {
+        return li_log(w, n, x);
+    }
+    match abs.partial_cmp(&word(1)) {
+        Some(Ordering::Equal) => -eta(w, &w.int(n as i64)),
+        Some(Ordering::Less) => {
+            // Li_n(x) = 2^(1 - n) Li_n(x^2) - Li_n(-x)
+            let square = polylog(w, n, &exact_mul(x, x));
+            let reflected = polylog(w, n, &abs);
+            let scale = w.powi(&half, n - 1);
+            w.sub(&w.mul(&square, &scale), &reflected)
+        }
+        _ => {
+            // Li_n(x) = -(-1)^n Li_n(1/x) - mu^n / n!
+            //     - 2 sum over 1 <= k <= n/2 of mu^(n - 2k) / (n - 2k)! eta(2k), with mu = ln(-x)
+            let inverse = polylog(w, n, &w.recip(x));
+            let mut sum = match n % 2 {
+                0 => -inverse,
+                _ => inverse,
+            };
+            let mu = w.ln(&abs);
+            // mu^(n - 2k) / (n - 2k)!
+            let mut pow = w.int(1);
+            for i in 0..=n {
+                if (n - i).is_multiple_of(2) {
+                    let coef = match i == n {
+                        true => w.int(1),
+                        false => {
+                            let eta = eta(w, &w.int((n - i) as i64));
+                            w.mul(&eta, &w.int(2))
+                        }
+                    };
+                    sum = w.sub(&sum, &w.mul(&coef, &pow));
+                }
+                pow = w.div(&w.mul(&pow, &mu), &w.int(i as i64 + 1));
+            }
+            sum
+        }
+    }
+}
+
+/// Returns `Li_-m(x)` for `m >= 0` and `x != 1`.
+fn li_neg(w: &mut Work, m: usize, x: &Float) -> Float {
+    let diff = exact_add(&word(1), &-x.clone());
+    if m == 0 {
+        return w.div(x, &diff);
+    }
+    // The Eulerian numbers take O(m^2) exact operations, and the series in 1 / (2 pi k - mu)
+    // converges fast enough once m is a fair part of the precision
+    if m >= w.p / 4 {
+        return li_neg_large(w, m, x);
+    }
+    // Li_-m(x) = x sum of A(m, k) x^k / (1 - x)^(m + 1), with the Eulerian numbers
+    // A(i, k) = (k + 1) A(i - 1, k) + (i - k) A(i - 1, k - 1)
+    let mut eulerian: Vec<Float> = Vec::from([word(1)]);
+    for i in 2..m + 1 {
+        eulerian = (0..i)
+            .map(|k| {
+                let low = match eulerian.get(k) {
+                    Some(coef) => exact_mul(coef, &word(k as Word + 1)),
+                    None => word(0),
+                };
+                let high = match k {
+                    0 => word(0),
+                    _ => exact_mul(&eulerian[k - 1], &word((i - k) as Word)),
+                };
+                exact_add(&low, &high)
+            })
+            .collect();
+    }
+    // The coefficients add up to m!, and can cancel for negative x
+    let extra = exact_factorial(m as u64)
+        .exponent()
+        .map_or(0, |exp| exp as usize);
+    w.extended(extra, |w| {
+        let poly = eulerian
+            .iter()
+            .rev()
+            .fold(w.int(0), |acc, coef| w.add(&w.mul(&acc, x), coef));
+        let den = w.powi(&diff, m + 1);
+        w.div(&w.mul(x, &poly), &den)
+    })
+}
+
+/// Returns `Li_-m(x)` for large `m` and `x != 1`, from `|x| <= 1` where `mu = ln|x| <= 0`. Tiny
+/// `|x|` use the series `sum of k^m x^k`, and the others
+/// `Li_-m(e^mu) = m! sum over all integers k of 1 / (2 pi i k - mu)^(m + 1)`, with `mu + i pi` in
+/// place of `mu` for negative `x`.
+fn li_neg_large(w: &mut Work, m: usize, x: &Float) -> Float {
+    // Li_-m(1 / x) = (-1)^(m + 1) Li_-m(x)
+    let flip = x.abs() > word(1) && m.is_multiple_of(2);
+    let negative = x.is_negative();
+    // mu keeps the bits of |x| that cancel against one, and the logarithms of m! and of the terms
+    // are multiplied by numbers up to about m ln(m)
+    let bits = (usize::BITS - (m + 1).leading_zeros()) as usize;
+    let value = w.extended(near_one_bits(&x.abs()) + 2 * bits + 8, |w| {
+        let mu = -w.ln(&x.abs()).abs();
+        if mu < w.int(-(m as i64)) {
+            // The terms shrink at least like (2 / e)^m
+            let mu = w.extended(magnitude_bits(&mu), |w| -w.ln(&x.abs()).abs());
+            return li_neg_series(w, m, &mu, negative);
+        }
+        let (sum, largest) = li_neg_fourier(w, m, &mu, negative);
+        // The terms of negative x have different signs and can cancel close to the zeros
+        match (largest, sum.exponent()) {
+            (Some(largest), Some(exp)) if largest > exp => {
+                w.extended((largest - exp) as usize, |w| {
+                    let mu = -w.ln(&x.abs()).abs();
+                    li_neg_fourier(w, m, &mu, negative).0
+                })
+            }
+            _ => sum,
+        }
+    });
+    match flip {
+        true => -value,
+        false => value,
+    }
+}
+
+/// Returns `sum of (+-1)^k k^m e^(k mu)`, with the sign for negative `x`.
+fn li_neg_series(w: &mut Work, m: usize, mu: &Float, negative: bool) -> Float {
+    let mut sum = w.int(0);
+    for k in 1.. {
+        let ln_k = w.ln(&w.int(k));
+        let exponent = w.add(&w.mul(&w.int(m as i64), &ln_k), &w.mul(&w.int(k), mu));
+        let term = w.exp(&exponent);
+        if negligible(&term, &sum, w.p) {
+            break;
+        }
+        sum = match negative && k % 2 == 1 {
+            true => w.sub(&sum, &term),
+            false => w.add(&sum, &term),
+        };
+    }
+    sum
+}
+
+/// Returns `m! sum of 1 / (i t - mu)^(m + 1)` over `t = 2 pi k` for positive `x`, or
+/// `t = pi (2k - 1)` for negative `x`, along with the exponent of the largest term.
+fn li_neg_fourier(w: &mut Work, m: usize, mu: &Float, negative: bool) -> (Float, Option<i32>) {
+    let ln_factorial = ln_gamma_pos(w, &w.int(m as i64 + 1));
+    let half_m1 = w.mul(&w.int(m as i64 + 1), &w.half());
+    let neg_mu = -mu.clone();
+    let mu2 = w.mul(mu, mu);
+    let pi = w.pi();
+    let mut sum = w.int(0);
+    let mut largest = None;
+    if !negative {
+        // t = 0 gives m! / (-mu)^(m + 1)
+        let ln_mu = w.ln(&neg_mu);
+        let ln_term = w.sub(&ln_factorial, &w.mul(&w.int(m as i64 + 1), &ln_mu));
+        sum = w.exp(&ln_term);
+        largest = sum.exponent();
+    }
+    for k in 1.. {
+        let t = match negative {
+            true => w.mul(&pi, &w.int(2 * k - 1)),
+            false => w.mul(&pi, &w.int(2 * k)),
+        };
+        // The terms t and -t are conjugate, so they add up to twice the real part
+        let ln_square = w.ln(&w.add(&mu2, &w.mul(&t, &t)));
+        let ln_term = w.sub(&ln_factorial, &w.mul(&half_m1, &ln_square));
+        let modulus = w.exp(&ln_term);
+        let modulus = w.mul(&modulus, &w.int(2));
+        let exp = modulus.exponent();
+        if modulus.is_zero() || largest.is_some_and(|largest| exp < Some(largest - w.p as i32)) {
+            break;
+        }
+        largest = largest.max(exp);
+        // i t - mu has the argument pi / 2 - psi with psi = atan(-mu / t)
+        let psi = w.atan(&w.div(&neg_mu, &t));
+        let angle = w.mul(&w.int(m as i64 + 1), &psi);
+        let cos = match (m + 1) % 4 {
+            0 => w.cos(&angle),
+            1 => w.sin(&angle),
+            2 => -w.cos(&angle),
+            _ => -w.sin(&angle),
+        };
+        sum = w.add(&sum, &w.mul(&modulus, &cos));
+        // An overflow of m! decides the result
+        if modulus.is_inf() {
+            break;
+        }
+    }
+    (sum, largest)
+}
+
+/// Returns true if `s` is a negative even integer, where the zeta and eta functions are zero.
+fn is_trivial_zero(s: &Float) -> bool {
+    s.is_int() && s.is_negative() && !is_odd(s)
+}
+
+impl<CTX: BigFloatCtx> BigFloat<CTX> {
+    /// Returns the Riemann zeta function of this number, the sum of `1 / k^s` over the positive
+    /// integers `k`, continued to all real numbers.
+    ///
+    /// Arguments below 1/2 use the functional equation
+    /// `zeta(s) = 2^s pi^(s - 1) sin(pi s / 2) gamma(1 - s) zeta(1 - s)`. The pole at one gives
+    /// `NaN`, as do `NaN` and negative infinity, and the negative even integers give zero.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use astro_nalgebra::{BigFloat, ConstCtx};
+    /// use nalgebra::RealField;
+    ///
+    /// type BF256 = BigFloat<ConstCtx<256>>;
+    ///
+    /// let pi = BF256::pi();
+    /// let expected = pi.clone() * pi / BF256::from(6.0);
+    /// assert!((BF256::from(2.0).zeta() - expected).as_f64().abs() < 1e-75);
+    /// assert_eq!(BF256::from(-1.0).zeta(), BF256::from(-1.0) / BF256::from(12.0));
+    /// ```
+    pub fn zeta(&self) -> Self {
+        let s = &self.num;
+        if s.is_nan() {
+            return self.clone();
+        }
+        if s.is_inf_pos() {
+            return BigFloat::from(word(1));
+        }
+        if s.is_inf() || *s == word(1) {
+            return BigFloat::from(Float::nan(None));
+        }
+        if is_trivial_zero(s) {
+            return BigFloat::from(word(0));
+        }
+        round(run::<CTX, _>(magnitude_bits(s), |w| zeta(w, s)))
+    }
+
+    /// Returns the Hurwitz zeta function of this number and `a`, the sum of `1 / (a + k)^s` over
+    /// the non-negative integers `k`, continued to all real `s`.
+    ///
+    /// `a` must be positive, and other values give `NaN`, as does the pole at `s = 1`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use astro_nalgebra::{BigFloat, ConstCtx};
+    /// use nalgebra::RealField;
+    ///
+    /// type BF256 = BigFloat<ConstCtx<256>>;
+    ///
+    /// // zeta(2, 1/2) = pi^2 / 2
+    /// let pi = BF256::pi();
+    /// let zeta = BF256::from(2.0).hurwitz_zeta(&BF256::from(0.5));
+    /// assert!((zeta - pi.clone() * pi / BF256::from(2.0)).as_f64().abs() < 1e-75);
+    /// ```
+    pub fn hurwitz_zeta(&self, a: &Self) -> Self {
+        let (s, a) = (&self.num, &a.num);
+        if s.is_nan() || a.is_nan() || a.is_zero() || a.is_negative() || *s == word(1) {
+            return BigFloat::from(Float::nan(None));
+        }
+        if s.is_inf_pos() {
+            // a^-s dominates the sum
+            return BigFloat::from(match a.partial_cmp(&word(1)) {
+                Some(Ordering::Less) => astro_float::INF_POS,
+                Some(Ordering::Equal) => word(1),
+                _ => word(0),
+            });
+        }
+        if s.is_inf() {
+            return BigFloat::from(Float::nan(None));
+        }
+        if a.is_inf() {
+            return BigFloat::from(match *s > word(1) {
+                true => word(0),
+                false => Float::nan(None),
+            });
+        }
+        round(run::<CTX, _>(magnitude_bits(s), |w| hurwitz(w, s, a)))
+    }
+
+    /// Returns the Dirichlet eta function of this number, the alternating sum of
+    /// `(-1)^(k + 1) / k^s` over the positive integers `k`, which is `(1 - 2^(1 - s)) zeta(s)`.
+    ///
+    /// Unlike the zeta function it has no pole, and `eta(1) = ln(2)`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use astro_nalgebra::{BigFloat, ConstCtx};
+    /// use nalgebra::ComplexField;
+    ///
+    /// type BF256 = BigFloat<ConstCtx<256>>;
+    ///
+    /// assert_eq!(BF256::from(1.0).eta(), BF256::from(2.0).ln());
+    /// assert_eq!(BF256::from(0.0).eta(), BF256::from(0.5));
+    /// ```
+    pub fn eta(&self) -> Self {
+        let s = &self.num;
+        if s.is_nan() {
+            return self.clone();
+        }
+        if s.is_inf_pos() {
+            return BigFloat::from(word(1));
+        }
+        if s.is_inf() {
+            return BigFloat::from(Float::nan(None));
+        }
+        if *s == word(1) {
+            return round(run::<CTX, _>(0, |w| w.ln(&w.int(2))));
+        }
+        if is_trivial_zero(s) {
+            return BigFloat::from(word(0));
+        }
+        round(run::<CTX, _>(magnitude_bits(s), |w| eta(w, s)))
+    }
+
+    /// Returns the polylogarithm of order `n` of this number, `Li_n(x)`, the sum of `x^k / k^n`
+    /// over the positive integers `k`, continued to real `x`.
+    ///
+    /// For positive orders, the result is real for `x <= 1`, with `Li_n(1) = zeta(n)`, and
+    /// arguments above one give `NaN`. Orders of zero and below are rational functions of `x`
+    /// with a pole at one.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use astro_nalgebra::{BigFloat, ConstCtx};
+    /// use nalgebra::{ComplexField, RealField};
+    ///
+    /// type BF256 = BigFloat<ConstCtx<256>>;
+    ///
+    /// // Li_2(1/2) = pi^2 / 12 - ln(2)^2 / 2
+    /// let pi = BF256::pi();
+    /// let ln_2 = BF256::from(2.0).ln();
+    /// let expected = pi.clone() * pi / BF256::from(12.0) - ln_2.clone() * ln_2 / BF256::from(2.0);
+    /// assert!((BF256::from(0.5).polylog(2) - expected).as_f64().abs() < 1e-75);
+    /// // Li_-1(x) = x / (1 - x)^2
+    /// assert_eq!(BF256::from(3.0).polylog(-1), BF256::from(0.75));
+    /// ```
+    pub fn polylog(&self, n: i32) -> Self {
+        let x = &self.num;
+        if x.is_nan() || x.is_zero() {
+            return self.clone();
+        }
+        let one = word(1);
+        if n <= 0 {
+            let m = n.unsigned_abs() as usize;
+            if x.is_inf() {
+                return BigFloat::from(match m {
+                    0 => -one,
+                    _ => word(0),
+                });
+            }
+            if *x == one {
+                // Li_-m(x) behaves like m! / (1 - x)^(m + 1) close to one
+                return BigFloat::from(match m % 2 {
+                    1 => astro_float::INF_POS,
+                    _ => Float::nan(None),
+                });
+            }
+            return round(run::<CTX, _>(0, |w| li_neg(w, m, x)));
+        }
+        if x.is_inf_neg() {
+            return self.clone();
+        }
+        let n = n as usize;
+        match x.partial_cmp(&one) {
+            Some(Ordering::Less) => round(run::<CTX, _>(0, |w| polylog(w, n, x))),
+            Some(Ordering::Equal) if n == 1 => BigFloat::from(astro_float::INF_POS),
+            Some(Ordering::Equal) => round(run::<CTX, _>(0, |w| zeta(w, &w.int(n as i64)))),
+            _ => BigFloat::from(Float::nan(None)),
+        }
+    }
+}
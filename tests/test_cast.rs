@@ -1,6 +1,6 @@
-use astro_nalgebra::{self, BigFloat, ConstCtx, Sign};
-use nalgebra::RealField;
-use num_traits::FromPrimitive;
+use astro_nalgebra::{self, BigFloat, BigFloatConversionError, ConstCtx, RoundingMode, Sign};
+use nalgebra::{ComplexField, DMatrix, RealField, Vector3};
+use num_traits::{FromPrimitive, One, Zero};
 
 type BF64 = BigFloat<ConstCtx<64>>;
 type BF128 = BigFloat<ConstCtx<128>>;
@@ -42,6 +42,119 @@ fn test_nan() {
     assert!(BF64::from_f64(f64::NAN).unwrap().as_f64().is_nan());
 }
 
+#[test]
+fn test_f64_rounding() {
+    let tests: Vec<(&str, f64)> = vec![
+        ("0.1", 0.1),
+        ("-0.3", -0.3),
+        ("2.2250738585072014e-308", f64::MIN_POSITIVE),
+        ("4.9406564584124654e-324", 5e-324),
+        ("-1.5e-320", -1.5e-320),
+        ("1.7976931348623157e+308", f64::MAX),
+        ("1.7976931348623158e+308", f64::MAX),
+        ("1e309", f64::INFINITY),
+        ("-1e400", f64::NEG_INFINITY),
+        ("1e-400", 0.),
+        (
+            "123456789012345678901234567890",
+            123456789012345678901234567890.,
+        ),
+    ];
+    for (string, float) in tests {
+        let num: BF256 = string.parse().unwrap();
+        assert_eq!(num.as_f64(), float, "{}", string);
+        assert_eq!(f64::from(num), float, "{}", string);
+    }
+    // Signed zero
+    let neg_zero = -BF256::from_f64(0.).unwrap();
+    assert!(neg_zero.as_f64().is_sign_negative());
+    assert!(neg_zero.to_f32_round(RoundingMode::Up).is_sign_negative());
+    assert!(BF256::from_f64(0.).unwrap().as_f64().is_sign_positive());
+    assert!("-1e-400"
+        .parse::<BF256>()
+        .unwrap()
+        .as_f64()
+        .is_sign_negative());
+    // Directed rounding of huge and tiny values
+    let huge: BF256 = "1e400".parse().unwrap();
+    assert_eq!(huge.to_f64_round(RoundingMode::ToZero), f64::MAX);
+    assert_eq!(huge.to_f64_round(RoundingMode::Down), f64::MAX);
+    assert_eq!(huge.to_f64_round(RoundingMode::Up), f64::INFINITY);
+    assert_eq!((-huge.clone()).to_f64_round(RoundingMode::Up), -f64::MAX);
+    assert_eq!(huge.to_f32_round(RoundingMode::None), f32::MAX);
+    let tiny: BF256 = "1e-400".parse().unwrap();
+    assert_eq!(tiny.to_f64_round(RoundingMode::Up), 5e-324);
+    assert_eq!(tiny.to_f64_round(RoundingMode::FromZero), 5e-324);
+    assert_eq!(tiny.to_f64_round(RoundingMode::ToZero), 0.);
+    assert_eq!((-tiny).to_f64_round(RoundingMode::Down), -5e-324);
+}
+
+/// Converts a finite f64 exactly, including subnormals
+fn exact_f64(x: f64) -> BF256 {
+    let bits = x.to_bits();
+    let exp = ((bits >> 52) & 0x7ff) as i32;
+    let mant = bits & ((1 << 52) - 1);
+    let (mant, exp) = match exp {
+        0 => (mant, -1074),
+        _ => (mant | (1 << 52), exp - 1075),
+    };
+    BF256::from_u64(mant).unwrap() * BF256::from_u8(2).unwrap().powi(exp)
+}
+
+#[test]
+fn test_f64_rounding_ties() {
+    use rand::{Rng, SeedableRng};
+    let mut rng = rand::rngs::StdRng::seed_from_u64(8);
+    // Normal and subnormal floats whose midpoints with the next float are exactly representable
+    let floats = (0..2000)
+        .map(|_| f64::from_bits(rng.gen::<u64>() & !(1 << 63)))
+        .filter(|x| x.is_finite() && *x < f64::MAX)
+        .chain([0., 5e-324, 1., 3., f64::MIN_POSITIVE]);
+    let two = BF256::from_u8(2).unwrap();
+    for low in floats {
+        let high = low.next_up();
+        let low_big = exact_f64(low);
+        let high_big = exact_f64(high);
+        let mid = (&low_big + &high_big) / &two;
+        let quarter = (&high_big - &low_big) / &two / &two;
+        let even = if low.to_bits() & 1 == 0 { low } else { high };
+        let odd = if low.to_bits() & 1 == 0 { high } else { low };
+        assert_eq!(mid.to_f64_round(RoundingMode::ToEven), even, "{}", low);
+        assert_eq!(mid.to_f64_round(RoundingMode::ToOdd), odd, "{}", low);
+        assert_eq!(mid.to_f64_round(RoundingMode::Up), high, "{}", low);
+        assert_eq!(mid.to_f64_round(RoundingMode::Down), low, "{}", low);
+        assert_eq!(mid.to_f64_round(RoundingMode::ToZero), low, "{}", low);
+        assert_eq!(mid.to_f64_round(RoundingMode::FromZero), high, "{}", low);
+        let above = &mid + &quarter;
+        assert_eq!(above.to_f64_round(RoundingMode::ToEven), high, "{}", low);
+        assert_eq!(above.to_f64_round(RoundingMode::ToOdd), high, "{}", low);
+        let below = &mid - &quarter;
+        assert_eq!(below.to_f64_round(RoundingMode::ToEven), low, "{}", low);
+        assert_eq!((-below).to_f64_round(RoundingMode::ToOdd), -low, "{}", low);
+        for rm in [RoundingMode::Up, RoundingMode::Down, RoundingMode::ToEven] {
+            assert_eq!(low_big.to_f64_round(rm), low);
+        }
+    }
+}
+
+#[test]
+fn test_f32_rounding() {
+    // 1 + 2^-24 + 2^-60 rounds to 1 + 2^-24 in f64, which would then be a tie in f32
+    let num = BF256::from_u8(1).unwrap()
+        + BF256::from_f64(2f64.powi(-24)).unwrap()
+        + BF256::from_f64(2f64.powi(-60)).unwrap();
+    assert_eq!(f32::from(num.clone()), 1. + f32::EPSILON);
+    assert_eq!(num.to_f32_round(RoundingMode::ToZero), 1.);
+    assert_eq!(f32::from(BF256::from_f64(0.1).unwrap()), 0.1f32);
+    assert_eq!(f32::from(BF256::from_f64(1e-45).unwrap()), 1e-45f32);
+    assert_eq!(f32::from(BF256::from_f64(1e39).unwrap()), f32::INFINITY);
+    assert!(f32::from(BF256::from_f64(f64::NAN).unwrap()).is_nan());
+    assert_eq!(
+        f32::from(BF256::from_f64(-f64::INFINITY).unwrap()),
+        f32::NEG_INFINITY
+    );
+}
+
 #[test]
 fn test_from_u128() {
     for x in (0..500).chain((u128::MAX - 500)..u128::MAX) {
@@ -58,7 +171,7 @@ fn test_from_u128() {
                 false => Sign::Pos,
             }
         );
-        assert_eq!(out.1, x.abs() as u128);
+        assert_eq!(out.1, x.unsigned_abs() as u128);
     }
 }
 
@@ -71,9 +184,10 @@ macro_rules! cast_unsigned {
             );
         }
         assert_eq!(
-            <BF128 as TryInto<$type>>::try_into(BigFloat::from_i32(-1).unwrap()),
-            Err(())
+            $type::try_from(BF128::from_i32(-1).unwrap()),
+            Err(BigFloatConversionError::Negative)
         );
+        assert_eq!($type::try_from(-BF128::zero()), Ok(0));
     };
 }
 #[test]
@@ -82,6 +196,7 @@ fn test_unsigned_casts() {
     cast_unsigned!(u16, from_u16);
     cast_unsigned!(u32, from_u32);
     cast_unsigned!(u64, from_u64);
+    cast_unsigned!(usize, from_usize);
 }
 
 macro_rules! cast_signed {
@@ -94,7 +209,13 @@ macro_rules! cast_signed {
                 <BF128 as TryInto<$type>>::try_into(BigFloat::$func(x).unwrap()).unwrap(),
                 x
             );
+            assert_eq!($type::try_from(BF128::$func(x).unwrap()), Ok(x));
         }
+        let too_small = BF128::$func($type::MIN).unwrap() - BF128::one();
+        assert_eq!(
+            $type::try_from(too_small),
+            Err(BigFloatConversionError::Overflow)
+        );
     };
 }
 
@@ -104,4 +225,238 @@ fn test_signed_casts() {
     cast_signed!(i16, from_i16);
     cast_signed!(i32, from_i32);
     cast_signed!(i64, from_i64);
+    cast_signed!(i128, from_i128);
+    cast_signed!(isize, from_isize);
+}
+
+#[test]
+fn test_integer_rounding() {
+    let values = [
+        0., 0.3, 0.5, 0.7, 1.5, 2.5, 3.5, 2.25, 7., -0.3, -0.5, -1.5, -2.5, -2.75, -7.,
+    ];
+    for x in values {
+        let num = BF256::from_f64(x).unwrap();
+        let even = x.round_ties_even() as i64;
+        let trunc = x.trunc() as i64;
+        let odd = match ((x - x.trunc()).abs() == 0.5, trunc % 2 == 0) {
+            (true, true) => trunc + x.signum() as i64,
+            (true, false) => trunc,
+            (false, _) => even,
+        };
+        let tests: Vec<(RoundingMode, i64)> = vec![
+            (RoundingMode::None, x.trunc() as i64),
+            (RoundingMode::ToZero, x.trunc() as i64),
+            (RoundingMode::FromZero, (x.abs().ceil() * x.signum()) as i64),
+            (RoundingMode::Up, x.ceil() as i64),
+            (RoundingMode::Down, x.floor() as i64),
+            (RoundingMode::ToEven, even),
+            (RoundingMode::ToOdd, odd),
+        ];
+        for (rm, expected) in tests {
+            assert_eq!(num.to_integer::<i64>(rm), Ok(expected), "{} {:?}", x, rm);
+            assert_eq!(
+                num.to_integer::<i8>(rm),
+                Ok(expected as i8),
+                "{} {:?}",
+                x,
+                rm
+            );
+        }
+    }
+    let big: BF256 = "340282366920938463463374607431768211455.5".parse().unwrap();
+    assert_eq!(big.to_integer::<u128>(RoundingMode::Down), Ok(u128::MAX));
+    assert_eq!(
+        big.to_integer::<u128>(RoundingMode::Up),
+        Err(BigFloatConversionError::Overflow)
+    );
+    assert_eq!(
+        BF256::from_f64(-0.5)
+            .unwrap()
+            .to_integer::<u32>(RoundingMode::Up),
+        Ok(0)
+    );
+    assert_eq!(
+        BF256::from_f64(-0.5)
+            .unwrap()
+            .to_integer::<u32>(RoundingMode::Down),
+        Err(BigFloatConversionError::Negative)
+    );
+    let nan = BF256::from_f64(f64::NAN).unwrap();
+    assert_eq!(
+        nan.to_integer::<i16>(RoundingMode::ToEven),
+        Err(BigFloatConversionError::NaN)
+    );
+    let num = BF256::from_f64(1234.).unwrap();
+    assert_eq!(num.to_int_checked::<u16>(), Ok(1234));
+    assert_eq!(num.to_int_checked::<i128>(), Ok(1234));
+    assert_eq!(num.to_int_checked::<usize>(), Ok(1234));
+    assert_eq!(
+        BF256::from_f64(-2.5)
+            .unwrap()
+            .to_integer::<isize>(RoundingMode::ToEven),
+        Ok(-2)
+    );
+    let past_usize = BF256::from_usize(usize::MAX).unwrap() + BF256::one();
+    assert_eq!(
+        usize::try_from(past_usize),
+        Err(BigFloatConversionError::Overflow)
+    );
+    assert_eq!(
+        num.to_int_checked::<u8>(),
+        Err(BigFloatConversionError::Overflow)
+    );
+    assert_eq!(
+        BF256::from_f64(12.5).unwrap().to_int_checked::<u64>(),
+        Err(BigFloatConversionError::NotInteger)
+    );
+}
+
+#[test]
+fn test_conversion_errors() {
+    let tests: Vec<(&str, BigFloatConversionError)> = vec![
+        ("2.5", BigFloatConversionError::NotInteger),
+        ("-0.5", BigFloatConversionError::NotInteger),
+        ("1e-40", BigFloatConversionError::NotInteger),
+        ("Inf", BigFloatConversionError::Infinite),
+        ("-Inf", BigFloatConversionError::Infinite),
+        ("1e40", BigFloatConversionError::Overflow),
+        ("-3", BigFloatConversionError::Negative),
+    ];
+    for (string, error) in tests {
+        let num: BF256 = string.parse().unwrap();
+        assert_eq!(u128::try_from(num), Err(error), "{}", string);
+    }
+    let nan = BF256::from_f64(f64::NAN).unwrap();
+    assert_eq!(i64::try_from(nan), Err(BigFloatConversionError::NaN));
+    let num = BF256::from_u16(300).unwrap();
+    assert_eq!(
+        u8::try_from(num.clone()),
+        Err(BigFloatConversionError::Overflow)
+    );
+    assert_eq!(
+        i8::try_from(-num.clone()),
+        Err(BigFloatConversionError::Overflow)
+    );
+    assert_eq!(i16::try_from(-num), Ok(-300));
+    assert_eq!(
+        BigFloatConversionError::Negative.to_string(),
+        "cannot convert a negative value to an unsigned integer"
+    );
+    let error: Box<dyn std::error::Error> = Box::new(BigFloatConversionError::NaN);
+    assert_eq!(error.to_string(), "cannot convert NaN to an integer");
+}
+
+#[test]
+fn test_context_casts() {
+    type BF128Up = BigFloat<ConstCtx<128, { RoundingMode::Up as u8 }>>;
+    type BF128Down = BigFloat<ConstCtx<128, { RoundingMode::Down as u8 }>>;
+    let third: BF256 = BF256::one() / BF256::from_u8(3).unwrap();
+    assert_eq!(
+        third.cast::<ConstCtx<128>>(),
+        BF128::one() / BF128::from_u8(3).unwrap()
+    );
+    // Rounding mode of the target context is used
+    let up = third.cast::<ConstCtx<128, { RoundingMode::Up as u8 }>>();
+    let down = third.cast::<ConstCtx<128, { RoundingMode::Down as u8 }>>();
+    assert!(up.cast::<ConstCtx<256>>() > third);
+    assert!(down.cast::<ConstCtx<256>>() < third);
+    assert_eq!(up, BF128Up::one() / BF128Up::from_u8(3).unwrap());
+    assert_eq!(down, BF128Down::one() / BF128Down::from_u8(3).unwrap());
+    // Promotion is exact
+    let promoted = BF128::pi().cast::<ConstCtx<1024>>();
+    assert_eq!(promoted.cast::<ConstCtx<128>>(), BF128::pi());
+    assert!(BF64::from_f64(f64::NAN)
+        .unwrap()
+        .cast::<ConstCtx<128>>()
+        .as_f64()
+        .is_nan());
+    assert_eq!(
+        BF64::from_f64(f64::NEG_INFINITY)
+            .unwrap()
+            .cast::<ConstCtx<128>>()
+            .as_f64(),
+        f64::NEG_INFINITY
+    );
+}
+
+#[test]
+fn test_matrix_context_casts() {
+    let mat: DMatrix<BF256> = DMatrix::from_fn(3, 2, |i, j| {
+        BF256::pi() * BF256::from_usize(i + 1).unwrap() / BF256::from_usize(j + 7).unwrap()
+    });
+    let demoted: DMatrix<BF128> = mat.clone().cast::<BF128>();
+    for (big, small) in mat.iter().zip(demoted.iter()) {
+        assert_eq!(big.cast::<ConstCtx<128>>(), *small);
+    }
+    let vec: Vector3<BF64> = Vector3::new(BF64::one(), BF64::pi(), BF64::e());
+    let promoted: Vector3<BF256> = vec.clone().cast::<BF256>();
+    assert!(promoted.x.is_one());
+    assert_eq!(promoted.cast::<BF64>(), vec);
+}
+
+#[test]
+fn test_exact_f64_conversion() {
+    type BF16 = BigFloat<ConstCtx<16>>;
+    let floats = [
+        0.1,
+        -1.0 / 3.0,
+        f64::MAX,
+        f64::MIN_POSITIVE,
+        f64::MIN_POSITIVE / 3.0,
+        5e-324,
+        -5e-324,
+        f64::EPSILON,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+    ];
+    for float in floats {
+        assert_eq!(BF16::from_f64_exact(float).as_f64(), float);
+        assert_eq!(BF16::from(float).as_f64(), float);
+        assert_eq!(BF256::from(float).as_f64(), float);
+        // Rounding to the context happens only once, without double rounding
+        let rounded = BF16::from_f64_exact(float).cast::<ConstCtx<16>>();
+        assert_eq!(BF16::from_f64(float).unwrap(), rounded);
+    }
+    assert!(BF16::from(f64::NAN).as_f64().is_nan());
+    let neg_zero = BF64::from(-0.0);
+    assert!(neg_zero.is_zero() && neg_zero.is_sign_negative());
+    assert!(BF64::from_f64(-0.0).unwrap().is_sign_negative());
+    assert!(BF64::from_f64(-0.0).unwrap().as_f64().is_sign_negative());
+    // Subnormals keep their value when rounded to the context
+    assert_eq!(BF64::from_f64(5e-324).unwrap().as_f64(), 5e-324);
+    assert_eq!(BF64::from_f64(3e-310).unwrap().as_f64(), 3e-310);
+    let floats32 = [0.1f32, f32::MAX, f32::MIN_POSITIVE / 7.0, -1e-45];
+    for float in floats32 {
+        assert_eq!(
+            BF16::from_f32_exact(float).to_f32_round(RoundingMode::ToEven),
+            float
+        );
+        assert_eq!(f32::from(BF16::from(float)), float);
+    }
+}
+
+#[test]
+fn test_f64_subset() {
+    use simba::scalar::SupersetOf;
+    type BF16 = BigFloat<ConstCtx<16>>;
+    let third = BF128::one() / BF128::from_u8(3).unwrap();
+    assert!(!SupersetOf::<f64>::is_in_subset(&third));
+    assert!(SupersetOf::<f64>::is_in_subset(&BF128::from(1.0 / 3.0)));
+    assert!(SupersetOf::<f64>::is_in_subset(&BF128::from(5e-324)));
+    assert!(SupersetOf::<f64>::is_in_subset(
+        &BF16::from_f64(0.1).unwrap()
+    ));
+    assert!(!SupersetOf::<f64>::is_in_subset(
+        &(BF128::from(f64::MAX) * 2.0)
+    ));
+    assert!(!SupersetOf::<f64>::is_in_subset(
+        &(BF128::from(5e-324) / 2.0)
+    ));
+    assert!(SupersetOf::<f64>::is_in_subset(&BF128::from(f64::INFINITY)));
+    assert!(SupersetOf::<f64>::is_in_subset(&BF128::from(f64::NAN)));
+    assert_eq!(nalgebra::try_convert::<BF128, f64>(third), None);
+    assert_eq!(
+        nalgebra::try_convert::<BF128, f64>(BF128::from(0.5)),
+        Some(0.5)
+    );
 }
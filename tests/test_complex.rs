@@ -0,0 +1,309 @@
+use astro_nalgebra::{BigComplex, BigFloat, ConstCtx, ParseBigFloatErrorKind};
+use nalgebra::{Complex, ComplexField, Matrix2, RealField};
+use num_traits::{FromPrimitive, One, Zero};
+
+type BF256 = BigFloat<ConstCtx<256>>;
+type BC256 = BigComplex<ConstCtx<256>>;
+
+fn to_complex64(z: &BC256) -> Complex<f64> {
+    Complex::new(z.re.as_f64(), z.im.as_f64())
+}
+fn from_complex64(z: Complex<f64>) -> BC256 {
+    BigComplex::new(
+        BF256::from_f64(z.re).unwrap(),
+        BF256::from_f64(z.im).unwrap(),
+    )
+}
+
+#[test]
+fn test_complex_parse_display() {
+    let tests: Vec<(&str, f64, f64)> = vec![
+        ("1+2i", 1., 2.),
+        ("1-2i", 1., -2.),
+        ("-1.5e-3-2.5e+2i", -0.0015, -250.),
+        ("1e+2+1e-2j", 100., 0.01),
+        ("3", 3., 0.),
+        ("-3i", 0., -3.),
+        ("i", 0., 1.),
+        ("-i", 0., -1.),
+        ("2-i", 2., -1.),
+        ("-Inf", f64::NEG_INFINITY, 0.),
+        ("0x1p-2-0x1.8p+1i", 0.25, -3.),
+        ("5@-1+2.5@+1i", 0.5, 25.),
+    ];
+    for (string, re, im) in tests {
+        let z: BC256 = string.parse().unwrap();
+        assert_eq!(z.re.as_f64(), re, "{}", string);
+        assert_eq!(z.im.as_f64(), im, "{}", string);
+    }
+    assert!("()i".parse::<BC256>().is_err());
+    // Error positions refer to the whole string
+    let err = " 1+2xi".parse::<BC256>().unwrap_err();
+    assert_eq!(
+        (err.kind(), err.position()),
+        (&ParseBigFloatErrorKind::InvalidDigit, 4)
+    );
+    let err = "1.5.2-2i".parse::<BC256>().unwrap_err();
+    assert_eq!(
+        (err.kind(), err.position()),
+        (&ParseBigFloatErrorKind::InvalidDigit, 3)
+    );
+    #[cfg(not(feature = "shortest-display"))]
+    {
+        assert_eq!("1-2i".parse::<BC256>().unwrap().to_string(), "1.e+0-2.e+0i");
+        assert_eq!("-1+0i".parse::<BC256>().unwrap().to_string(), "-1.e+0+0.0i");
+    }
+    #[cfg(feature = "shortest-display")]
+    {
+        assert_eq!("1-2i".parse::<BC256>().unwrap().to_string(), "1-2i");
+        assert_eq!("-1+0i".parse::<BC256>().unwrap().to_string(), "-1+0i");
+    }
+}
+
+#[test]
+fn test_complex_arithmetic() {
+    let a: BC256 = "3+4i".parse().unwrap();
+    let b: BC256 = "1-2i".parse().unwrap();
+    assert_eq!(&a + &b, "4+2i".parse().unwrap());
+    assert_eq!(&a - &b, "2+6i".parse().unwrap());
+    assert_eq!(&a * &b, "11-2i".parse().unwrap());
+    assert_eq!(&a / &b, "-1+2i".parse().unwrap());
+    assert_eq!(a.clone() % b.clone(), "0".parse().unwrap());
+    assert_eq!(
+        "7+3i".parse::<BC256>().unwrap() % "2+i".parse::<BC256>().unwrap(),
+        "1".parse().unwrap()
+    );
+    let mut c = a.clone();
+    c *= &b;
+    c /= b.clone();
+    assert_eq!(c, a);
+    assert_eq!(BC256::i() * BC256::i(), -BC256::one());
+    assert_eq!(
+        a.clone() * BF256::from_u8(2).unwrap(),
+        "6+8i".parse().unwrap()
+    );
+    let eps = BF256::from_f64(1e-70).unwrap();
+    assert!((a.clone().recip() * a - BC256::one()).modulus() < eps);
+}
+
+#[test]
+fn test_complex_exact_functions() {
+    let z: BC256 = "3+4i".parse().unwrap();
+    assert_eq!(z.clone().modulus(), BF256::from_u8(5).unwrap());
+    assert_eq!(z.clone().modulus_squared(), BF256::from_u8(25).unwrap());
+    assert_eq!(z.clone().norm1(), BF256::from_u8(7).unwrap());
+    assert_eq!(z.clone().conjugate(), "3-4i".parse().unwrap());
+    assert_eq!(z.clone().powi(2), "-7+24i".parse().unwrap());
+    assert_eq!(z.clone().powi(-1), z.clone().recip());
+    assert_eq!("-7+24i".parse::<BC256>().unwrap().sqrt(), z);
+    assert_eq!("-4".parse::<BC256>().unwrap().sqrt(), "2i".parse().unwrap());
+    assert_eq!(
+        "-4-0i".parse::<BC256>().unwrap().sqrt(),
+        "2i".parse().unwrap()
+    );
+    assert_eq!(
+        "-2i".parse::<BC256>().unwrap().sqrt(),
+        "1-i".parse().unwrap()
+    );
+    assert_eq!("27".parse::<BC256>().unwrap().cbrt(), "3".parse().unwrap());
+    assert_eq!(BC256::zero().exp(), BC256::one());
+    assert_eq!(BC256::one().ln(), BC256::zero());
+    assert_eq!("-1".parse::<BC256>().unwrap().argument(), BF256::pi());
+    assert_eq!(BC256::i().argument(), BF256::frac_pi_2());
+    assert_eq!(
+        "2.5-3.5i".parse::<BC256>().unwrap().floor(),
+        "2-4i".parse().unwrap()
+    );
+}
+
+#[test]
+fn test_complex_zero_pow() {
+    let zero = BC256::zero();
+    let two = BF256::from_u8(2).unwrap();
+    assert_eq!(zero.clone().powf(two.clone()), zero);
+    assert_eq!(zero.clone().powf(BF256::zero()), BC256::one());
+    let inf = zero.clone().powf(-two);
+    assert!(inf.re.as_f64() == f64::INFINITY && inf.im.is_zero());
+    assert_eq!(zero.clone().powc("0.5+3i".parse().unwrap()), zero);
+    assert_eq!(zero.clone().powc(BC256::zero()), BC256::one());
+    assert!(zero.clone().powc("-1+i".parse().unwrap()).re.as_f64() == f64::INFINITY);
+    // A purely imaginary exponent has no limit at zero
+    assert!(zero.clone().powc(BC256::i()).re.as_f64().is_nan());
+}
+
+#[test]
+fn test_complex_mul_add() {
+    // x^2 = 1 + 2^-199 + 2^-400 needs more than 256 bits, so rounding the product loses 2^-400
+    let x = BF256::one() + BF256::from_f64(2f64.powi(-200)).unwrap();
+    let rounded_square = x.clone() * x.clone();
+    let tiny = BF256::from_f64(2f64.powi(-400)).unwrap();
+    let real = BC256::from(x.clone());
+    let fused = real
+        .clone()
+        .mul_add(real, BC256::from(-rounded_square.clone()));
+    assert_eq!(fused, BC256::from(tiny.clone()));
+    // (ix)(ix) = -x^2 goes through the imaginary parts
+    let imag = BC256::new(BF256::zero(), x.clone());
+    let fused = imag
+        .clone()
+        .mul_add(imag, BC256::from(rounded_square.clone()));
+    assert_eq!(fused, BC256::from(-tiny));
+}
+
+#[test]
+fn test_complex_identities() {
+    // Values are chosen inside the principal range of every inverse function
+    let values: Vec<BC256> = ["1+0.5i", "-0.5+0.25i", "0.3-1.2i", "0.1-0.01i", "-1.2-0.4i"]
+        .iter()
+        .map(|s| s.parse().unwrap())
+        .collect();
+    let eps = BF256::from_f64(1e-70).unwrap();
+    for z in values {
+        // exp and ln are inverses
+        assert!((z.clone().ln().exp() - z.clone()).modulus() < eps);
+        // sin^2 + cos^2 = 1
+        let (sin, cos) = z.clone().sin_cos();
+        assert!((&sin * &sin + &cos * &cos - BC256::one()).modulus() < eps);
+        assert_eq!(sin, z.clone().sin());
+        assert_eq!(cos, z.clone().cos());
+        // inverse functions
+        assert!((z.clone().sin().asin() - z.clone()).modulus() < eps.clone() * z.clone().modulus());
+        assert!((z.clone().tanh().atanh() - z.clone()).modulus() < eps);
+        assert!((z.clone().sinh().asinh() - z.clone()).modulus() < eps);
+        // powers
+        let square = z.clone().sqrt();
+        assert!((&square * &square - z.clone()).modulus() < eps);
+        let cube = z.clone().cbrt();
+        assert!((cube.powi(3) - z.clone()).modulus() < eps);
+        let pow = z.clone().powc("0.5".parse().unwrap());
+        assert!((pow - z.clone().sqrt()).modulus() < eps);
+        let powf = z.clone().powf(BF256::from_u8(3).unwrap());
+        assert!((powf - z.clone().powi(3)).modulus() < eps.clone() * z.clone().modulus_squared());
+    }
+}
+
+#[test]
+fn test_complex_small_ln_1p_exp_m1() {
+    type BC1024 = BigComplex<ConstCtx<1024>>;
+    type BF1024 = BigFloat<ConstCtx<1024>>;
+    let values: Vec<BC256> = [
+        "1e-30+2e-30i",
+        "-3e-50+1e-60i",
+        "2e-5-1e-5i",
+        "1e-100+1e-100i",
+    ]
+    .iter()
+    .map(|s| s.parse().unwrap())
+    .collect();
+    let eps = BF256::from_f64(1e-70).unwrap();
+    for z in values {
+        // At 1024 bits the cancellation of the direct forms still leaves more than 256 bits
+        let wide: BC1024 = z.cast();
+        let ln_1p: BC256 = (wide.clone() + BC1024::one()).ln().cast();
+        let exp_m1: BC256 = (wide.exp() - BC1024::one()).cast();
+        let modulus = z.clone().modulus();
+        assert!((z.clone().ln_1p() - ln_1p).modulus() < eps.clone() * modulus.clone());
+        assert!((z.clone().exp_m1() - exp_m1).modulus() < eps.clone() * modulus.clone());
+        // The real functions of the real part
+        let wide: BF1024 = z.re.cast();
+        let ln_1p: BF256 = (wide.clone() + BF1024::one()).ln().cast();
+        let exp_m1: BF256 = (wide.exp() - BF1024::one()).cast();
+        assert!((z.re.clone().ln_1p() - ln_1p.clone()).abs() < eps.clone() * modulus.clone());
+        assert!((z.re.clone().exp_m1() - exp_m1).abs() < eps.clone() * modulus.clone());
+    }
+}
+
+macro_rules! mirror_complex {
+    ($nums:ident, $func:ident) => {
+        for num in $nums.iter() {
+            let big = to_complex64(&from_complex64(*num).$func());
+            let small = num.$func();
+            let diff = (big - small).modulus();
+            assert!(
+                diff <= 1e-13 * small.modulus().max(1.),
+                "Failed complex mirroring for ({}).{}(), {} != {}",
+                num,
+                stringify!($func),
+                big,
+                small,
+            );
+        }
+    };
+}
+
+#[test]
+fn test_complex_mirror_operations() {
+    let nums: Vec<Complex<f64>> = vec![
+        Complex::new(1., 2.),
+        Complex::new(-1., 2.),
+        Complex::new(-1., -2.),
+        Complex::new(0.5, -0.25),
+        Complex::new(0.3, 4.),
+        Complex::new(10., -7.),
+    ];
+    // Points on the real axis lie on the branch cuts of the inverse functions, where the sign of
+    // zero decides the branch
+    let real_axis: Vec<Complex<f64>> = vec![Complex::new(3., 0.), Complex::new(-3., 0.)];
+    mirror_complex!(real_axis, exp);
+    mirror_complex!(real_axis, ln);
+    mirror_complex!(real_axis, sqrt);
+    mirror_complex!(real_axis, cbrt);
+    mirror_complex!(real_axis, sin);
+    mirror_complex!(real_axis, cosh);
+    mirror_complex!(nums, exp);
+    mirror_complex!(nums, ln);
+    mirror_complex!(nums, sqrt);
+    mirror_complex!(nums, cbrt);
+    mirror_complex!(nums, exp2);
+    mirror_complex!(nums, log2);
+    mirror_complex!(nums, log10);
+    mirror_complex!(nums, sin);
+    mirror_complex!(nums, cos);
+    mirror_complex!(nums, tan);
+    mirror_complex!(nums, asin);
+    mirror_complex!(nums, acos);
+    mirror_complex!(nums, atan);
+    mirror_complex!(nums, sinh);
+    mirror_complex!(nums, cosh);
+    mirror_complex!(nums, tanh);
+    mirror_complex!(nums, asinh);
+    mirror_complex!(nums, acosh);
+    mirror_complex!(nums, atanh);
+    mirror_complex!(nums, recip);
+    mirror_complex!(nums, signum);
+    for num in nums.iter() {
+        let big = from_complex64(*num);
+        assert!((big.clone().argument().as_f64() - num.argument()).abs() < 1e-15);
+        assert!((big.clone().modulus().as_f64() - num.modulus()).abs() < 1e-13);
+        let pow = to_complex64(&big.clone().powc(from_complex64(Complex::new(0.5, 1.5))));
+        assert!((pow - num.powc(Complex::new(0.5, 1.5))).modulus() < 1e-13 * pow.modulus());
+        let hypot = big.clone().hypot(big.clone()).as_f64();
+        assert!((hypot - num.hypot(*num)).abs() < 1e-13 * hypot);
+    }
+}
+
+#[test]
+fn test_complex_eigenvalues() {
+    let zero = BF256::zero();
+    let one = BF256::one();
+    // Rotation by 90 degrees has eigenvalues i and -i
+    let rotation = Matrix2::new(zero.clone(), -one.clone(), one.clone(), zero.clone());
+    let promoted: Matrix2<BC256> = rotation.cast::<BC256>();
+    let schur = promoted.schur();
+    let mut eigenvalues: Vec<BC256> = schur.eigenvalues().unwrap().iter().cloned().collect();
+    eigenvalues.sort_by(|a, b| a.im.partial_cmp(&b.im).unwrap());
+    let eps = BF256::from_f64(1e-70).unwrap();
+    assert!((eigenvalues[0].clone() + BC256::i()).modulus() < eps);
+    assert!((eigenvalues[1].clone() - BC256::i()).modulus() < eps);
+
+    let mat: Matrix2<BC256> = Matrix2::new(
+        "1+i".parse().unwrap(),
+        "2".parse().unwrap(),
+        "-i".parse().unwrap(),
+        "3-2i".parse().unwrap(),
+    );
+    let inverse = mat.clone().try_inverse().unwrap();
+    let identity = inverse * mat;
+    assert!((identity[(0, 0)].clone() - BC256::one()).modulus() < eps);
+    assert!(identity[(1, 0)].clone().modulus() < eps);
+}
@@ -14,7 +14,7 @@ macro_rules! test_pi_fraction {
 }
 #[test]
 fn test_pis() {
-    assert_eq!("3.1415926535897932384626433832795028841971693993751058209749445923078164062862089986280348253421170679",&format!("{}",BigFloat::<ConstCtx<2048>>::pi().to_string())[0..102]);
+    assert_eq!("3.1415926535897932384626433832795028841971693993751058209749445923078164062862089986280348253421170679",&BigFloat::<ConstCtx<2048>>::pi().to_string()[0..102]);
     test_pi_fraction!(two_pi, 0.5);
     test_pi_fraction!(frac_pi_2, 2.0);
     test_pi_fraction!(frac_pi_3, 3.0);
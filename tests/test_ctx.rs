@@ -1,4 +1,5 @@
-use astro_nalgebra::{make_dyn_ctx, BigFloat, BigFloatCtx, ConstCtx, RoundingMode};
+use astro_nalgebra::{make_dyn_ctx, BigFloat, BigFloatCtx, ConstCtx, RoundingMode, ScopedCtx};
+use nalgebra::{ComplexField, RealField};
 
 make_dyn_ctx!(DynCtx1, DYN_CTX_1);
 #[test]
@@ -63,3 +64,42 @@ fn test_const_rounding_modes() {
     test_const_rm!(RoundingMode::ToEven);
     test_const_rm!(RoundingMode::ToOdd);
 }
+
+#[test]
+fn test_scoped_precision() {
+    assert!(!ScopedCtx::is_set());
+    let outer = ScopedCtx::push(128, RoundingMode::ToEven);
+    assert_eq!(ScopedCtx::get_prec(), 128);
+    let low: BigFloat<ScopedCtx> = "2".parse::<BigFloat<ScopedCtx>>().unwrap().sqrt();
+    let high = ScopedCtx::with_precision(512, RoundingMode::Up, || {
+        assert_eq!(ScopedCtx::get_prec(), 512);
+        assert_eq!(ScopedCtx::get_rm(), RoundingMode::Up);
+        let two: BigFloat<ScopedCtx> = "2".parse().unwrap();
+        assert_eq!(two.clone().sqrt().as_f64(), 2f64.sqrt());
+        two.sqrt()
+    });
+    assert_eq!(ScopedCtx::get_prec(), 128);
+    assert_eq!(ScopedCtx::get_rm(), RoundingMode::ToEven);
+    assert_ne!(low, high);
+    // Constants are cached per precision, so pi differs between scopes
+    let pi_128 = BigFloat::<ScopedCtx>::pi();
+    let pi_256 = ScopedCtx::with_precision(256, RoundingMode::ToEven, BigFloat::<ScopedCtx>::pi);
+    assert_ne!(pi_128, pi_256);
+    assert_eq!(
+        pi_128.to_string(),
+        BigFloat::<ConstCtx<128, 32>>::pi().to_string()
+    );
+    drop(outer);
+    assert!(!ScopedCtx::is_set());
+}
+
+#[test]
+fn test_scoped_precision_per_thread() {
+    let _guard = ScopedCtx::push(64, RoundingMode::None);
+    let handle = std::thread::spawn(|| {
+        assert!(!ScopedCtx::is_set());
+        ScopedCtx::with_precision(1024, RoundingMode::None, ScopedCtx::get_prec)
+    });
+    assert_eq!(handle.join().unwrap(), 1024);
+    assert_eq!(ScopedCtx::get_prec(), 64);
+}
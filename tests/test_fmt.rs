@@ -0,0 +1,421 @@
+use astro_nalgebra::{BigComplex, BigFloat, ConstCtx, RoundingMode};
+use nalgebra::{ComplexField, Matrix2, RealField};
+use num_traits::{FromPrimitive, Num};
+
+type BF128 = BigFloat<ConstCtx<128>>;
+type BF128Even = BigFloat<ConstCtx<128, { RoundingMode::ToEven as u8 }>>;
+type BF256 = BigFloat<ConstCtx<256>>;
+
+/// Formats a BigFloat and the f64 it was created from with the same format string and checks
+/// that the outputs match
+macro_rules! mirror_format {
+    ($floats:ident, $($formatter:literal),*) => {
+        for float in $floats.iter() {
+            let big = BF256::from_f64(*float).unwrap();
+            $(
+                assert_eq!(
+                    format!($formatter, big),
+                    format!($formatter, float),
+                    "Failed format {} for {}",
+                    $formatter,
+                    float
+                );
+            )*
+        }
+    };
+}
+
+#[test]
+fn test_mirror_f64_format() {
+    let floats: Vec<f64> = vec![
+        0.,
+        1.,
+        -1.,
+        0.5,
+        0.125,
+        2.5,
+        -2.5,
+        3.75,
+        0.1,
+        1e-10,
+        123456.789,
+        -9.9996,
+        1e22,
+        1e-7,
+        6.02214076e23,
+        0.3,
+    ];
+    mirror_format!(
+        floats,
+        "{:.0}",
+        "{:.1}",
+        "{:.2}",
+        "{:.3}",
+        "{:.20}",
+        "{:.0e}",
+        "{:.3e}",
+        "{:.10E}",
+        "{:12.3}",
+        "{:<12.3}",
+        "{:^12.3}",
+        "{:>12.3}",
+        "{:*^12.3}",
+        "{:+.2}",
+        "{:+08.2}",
+        "{:08.2}",
+        "{:+.4e}",
+        "{:015.3e}"
+    );
+    // Without a precision, exponential notation prints the shortest digits at the precision of the
+    // context, which are those of f64 only when the value is exact
+    let exact: Vec<f64> = vec![0., 1., -2.5, 0.125, 1e22, 3.75, 1024.];
+    mirror_format!(exact, "{:e}", "{:E}", "{:+e}", "{:10e}");
+}
+
+#[test]
+fn test_display_precision() {
+    let pi = BF256::pi();
+    assert_eq!(
+        format!("{:.50}", pi),
+        "3.14159265358979323846264338327950288419716939937511"
+    );
+    assert_eq!(
+        format!("{:.40e}", pi),
+        "3.1415926535897932384626433832795028841972e0"
+    );
+    assert_eq!(format!("{:.0}", pi), "3");
+    assert_eq!(format!("{:#.0}", pi), "3.");
+    assert_eq!(format!("{:#.0e}", pi), "3.e0");
+    let third = BF256::from_u8(1).unwrap() / BF256::from_u8(3).unwrap();
+    assert_eq!(format!("{:.5}", third), "0.33333");
+    assert_eq!(format!("{:.5}", -third.clone()), "-0.33333");
+    assert_eq!(format!("{:.3e}", third), "3.333e-1");
+    let big: BF256 = "99999.9999999".parse().unwrap();
+    assert_eq!(format!("{:.3}", big), "100000.000");
+    assert_eq!(format!("{:.3e}", big), "1.000e5");
+    // Without a precision the shortest digits that parse back to the same value are printed
+    let float: BF128Even = "1.1".parse().unwrap();
+    assert_eq!(format!("{:e}", float), "1.1e0");
+    assert_eq!(format!("{:E}", -float.clone()), "-1.1E0");
+    let third = BF128Even::from_u8(1).unwrap() / BF128Even::from_u8(3).unwrap();
+    let printed = format!("{:e}", third);
+    assert_eq!(printed, "3.33333333333333333333333333333333333334e-1");
+    assert_eq!(printed.parse::<BF128Even>().unwrap(), third);
+    // Display without a precision honors padding and sign flags
+    let float: BF128 = "1.1".parse().unwrap();
+    #[cfg(not(feature = "shortest-display"))]
+    {
+        assert_eq!(format!("{}", float), "1.1e+0");
+        assert_eq!(format!("{:>8}", float), "  1.1e+0");
+        assert_eq!(format!("{:+}", float), "+1.1e+0");
+    }
+    #[cfg(feature = "shortest-display")]
+    {
+        assert_eq!(format!("{}", float), "1.1");
+        assert_eq!(format!("{:>8}", float), "     1.1");
+        assert_eq!(format!("{:+}", -float), "-1.1");
+    }
+}
+
+#[test]
+fn test_non_finite_format() {
+    let inf = BF128::from_f64(f64::INFINITY).unwrap();
+    let nan = BF128::from_f64(f64::NAN).unwrap();
+    assert_eq!(format!("{:.3}", inf), "Inf");
+    assert_eq!(format!("{:e}", -inf.clone()), "-Inf");
+    assert_eq!(format!("{:+}", inf), "+Inf");
+    assert_eq!(format!("{:>5}", nan), "  NaN");
+    assert_eq!(format!("{:x}", nan), "NaN");
+}
+
+#[test]
+fn test_lower_hex() {
+    let tests: Vec<(f64, &str)> = vec![
+        (1., "1p+0"),
+        (0., "0p+0"),
+        (-2.5, "-1.4p+1"),
+        (0.1, "1.999999999999ap-4"),
+        (std::f64::consts::PI, "1.921fb54442d18p+1"),
+        (1e-300, "1.56e1fc2f8f359p-997"),
+    ];
+    for (float, hex) in tests {
+        let big = BF128::from_f64(float).unwrap();
+        assert_eq!(format!("{:x}", big), hex);
+    }
+    let pi = BF128::pi();
+    assert_eq!(
+        format!("{:#x}", pi),
+        "0x1.921fb54442d18469898cc51701b839a2p+1"
+    );
+    assert_eq!(format!("{:.3x}", pi), "1.922p+1");
+    assert_eq!(format!("{:#.0x}", pi), "0x1p+2");
+    assert_eq!(format!("{:+#.1x}", pi), "+0x1.9p+1");
+    // Rounding up carries into the exponent
+    let almost_two = BF128::from_f64(1.9999).unwrap();
+    assert_eq!(format!("{:.2x}", almost_two), "1.00p+1");
+    assert_eq!(format!("{:#012.1x}", -pi), "-0x0001.9p+1");
+}
+
+#[test]
+fn test_upper_hex() {
+    let big = BF128::from_f64(1e10).unwrap();
+    assert_eq!(format!("{:x}", big), "1.2a05f2p+33");
+    assert_eq!(format!("{:X}", big), "1.2A05F2P+33");
+    let pi = BF128::pi();
+    assert_eq!(
+        format!("{:#X}", pi),
+        "0X1.921FB54442D18469898CC51701B839A2P+1"
+    );
+    assert_eq!(format!("{:.3X}", -pi.clone()), "-1.922P+1");
+    assert_eq!(format!("{:#012.1X}", pi), "0X00001.9P+1");
+    assert_eq!(format!("{:X}", BF128::from_f64(0.).unwrap()), "0P+0");
+}
+
+#[test]
+fn test_matrix_format() {
+    let mat = Matrix2::new(
+        BF256::pi(),
+        BF256::e(),
+        -BF256::ln_2(),
+        BF256::from_u8(100).unwrap(),
+    );
+    let printed = format!("{:.3}", mat);
+    assert!(printed.contains("  3.142   2.718 "), "{}", printed);
+    assert!(printed.contains(" -0.693 100.000 "), "{}", printed);
+}
+
+#[test]
+fn test_complex_format() {
+    let z = BigComplex::new(BF256::pi(), -BF256::e());
+    assert_eq!(format!("{:.3}", z), "3.142-2.718i");
+    assert_eq!(format!("{:+.1}", z), "+3.1-2.7i");
+    assert_eq!(format!("{:>12.2}", -z), " -3.14+2.72i");
+}
+
+/// Evaluates a string produced by to_string_radix as an f64
+fn eval_radix(string: &str, radix: u32) -> f64 {
+    let (negative, string) = match string.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, string),
+    };
+    let separator = if radix == 10 { 'e' } else { '@' };
+    let (mantissa, exp) = string.split_once(separator).unwrap();
+    let mut val = 0.;
+    let mut frac_digits = 0;
+    for c in mantissa.chars() {
+        if c == '.' {
+            frac_digits = mantissa.len() - 2;
+            continue;
+        }
+        val = val * radix as f64 + c.to_digit(radix).unwrap() as f64;
+    }
+    let exp: i32 = exp.parse().unwrap();
+    let val = val * (radix as f64).powi(exp - frac_digits as i32);
+    if negative {
+        -val
+    } else {
+        val
+    }
+}
+
+#[test]
+fn test_to_string_radix() {
+    let values: Vec<BF256> = ["3.14159", "-0.001234", "1e30", "-7", "0.5", "1e10"]
+        .iter()
+        .map(|s| s.parse().unwrap())
+        .collect();
+    for radix in 2..=36 {
+        for val in values.iter() {
+            let string = val.to_string_radix(radix, 20, RoundingMode::ToEven);
+            let float = eval_radix(&string, radix);
+            assert!(
+                (float - val.as_f64()).abs() <= 1e-5 * val.as_f64().abs(),
+                "{} in radix {} gave {}",
+                val,
+                radix,
+                string
+            );
+        }
+    }
+    // Enough digits parse back to the same number in the same radix
+    for val in values.iter() {
+        for radix in [2u32, 8, 10, 16] {
+            let digits = 256 / radix.ilog2() as usize + 2;
+            let string = val.to_string_radix(radix, digits, RoundingMode::ToEven);
+            let parsed = BF256::from_str_radix(&string, radix).unwrap();
+            assert_eq!(&parsed, val, "{} in radix {}", string, radix);
+        }
+    }
+    let x: BF256 = "255.5".parse().unwrap();
+    assert_eq!(x.to_string_radix(16, 4, RoundingMode::ToEven), "f.f80@1");
+    assert_eq!(x.to_string_radix(16, 2, RoundingMode::ToEven), "1.0@2");
+    assert_eq!(x.to_string_radix(16, 2, RoundingMode::ToZero), "f.f@1");
+    assert_eq!(
+        x.to_string_radix(2, 9, RoundingMode::ToEven),
+        "1.11111111@7"
+    );
+    assert_eq!(x.to_string_radix(2, 8, RoundingMode::ToEven), "1.0000000@8");
+    assert_eq!(x.to_string_radix(8, 5, RoundingMode::ToEven), "3.7740@2");
+    assert_eq!(x.to_string_radix(36, 3, RoundingMode::ToEven), "7.3i@1");
+    let neg = -x;
+    assert_eq!(neg.to_string_radix(10, 3, RoundingMode::Up), "-2.55e2");
+    assert_eq!(neg.to_string_radix(10, 3, RoundingMode::Down), "-2.56e2");
+    assert_eq!(neg.to_string_radix(10, 1, RoundingMode::ToEven), "-3e2");
+    let zero = BF256::from_u8(0).unwrap();
+    assert_eq!(zero.to_string_radix(10, 3, RoundingMode::ToEven), "0.00e0");
+    let inf = BF256::from_f64(f64::NEG_INFINITY).unwrap();
+    assert_eq!(inf.to_string_radix(16, 3, RoundingMode::ToEven), "-Inf");
+}
+
+#[test]
+fn test_huge_exponent_format() {
+    // Close to both ends of the exponent range, where radix^scale has about 2^31 bits
+    let big = BF256::from_u8(2).unwrap().powi(2_000_000_000);
+    assert_eq!(format!("{:.10e}", big), "2.1279547587e602059991");
+    assert_eq!(
+        big.to_string_radix(10, 11, RoundingMode::FromZero),
+        "2.1279547588e602059991"
+    );
+    let tiny = BF256::from_u8(2).unwrap().powi(-2_000_000_000);
+    assert_eq!(format!("{:.10E}", tiny), "4.6993480284E-602059992");
+    assert_eq!(
+        tiny.to_string_radix(10, 11, RoundingMode::Up),
+        "4.6993480285e-602059992"
+    );
+    assert_eq!(
+        (-tiny).to_string_radix(2, 3, RoundingMode::ToEven),
+        "-1.00@-2000000000"
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_to_string_radix_invalid() {
+    BF128::from_u8(1)
+        .unwrap()
+        .to_string_radix(37, 3, RoundingMode::ToEven);
+}
+
+#[test]
+fn test_to_decimal_string() {
+    let third = BF256::from_u8(1).unwrap() / BF256::from_u8(3).unwrap();
+    assert_eq!(third.to_decimal_string(5), "3.3333e-1");
+    assert_eq!(third.to_decimal_string(1), "3e-1");
+    let two_thirds = BF256::from_u8(2).unwrap() / BF256::from_u8(3).unwrap();
+    assert_eq!(two_thirds.to_decimal_string(4), "6.667e-1");
+    // Ties round to even
+    assert_eq!(
+        BF256::from_f64(0.125).unwrap().to_decimal_string(2),
+        "1.2e-1"
+    );
+    assert_eq!(
+        BF256::from_f64(0.375).unwrap().to_decimal_string(2),
+        "3.8e-1"
+    );
+    assert_eq!(BF256::from_f64(9.5).unwrap().to_decimal_string(1), "1e1");
+    let pi = BF256::pi();
+    for digits in 1..70 {
+        assert_eq!(
+            pi.to_decimal_string(digits),
+            format!("{:.*e}", digits - 1, pi)
+        );
+    }
+    // 74 digits of pi, the 76 significant digits of a 256 bit number are correct
+    assert_eq!(
+        pi.to_decimal_string(74),
+        "3.1415926535897932384626433832795028841971693993751058209749445923078164063e0"
+    );
+}
+
+/// Checks that the shortest string of every value parses back to the same value and that one
+/// digit less does not
+macro_rules! shortest_round_trip {
+    ($type:ty, $values:expr) => {
+        for val in $values.iter() {
+            let val: $type = val.cast();
+            let string = val.to_shortest_string();
+            assert_eq!(string.parse::<$type>().unwrap(), val, "{}", string);
+            let digits = string
+                .trim_start_matches(['-', '0', '.'])
+                .split('e')
+                .next()
+                .unwrap()
+                .replace('.', "");
+            let digits = digits.trim_end_matches('0');
+            if digits.len() > 1 {
+                let shorter = val.to_decimal_string(digits.len() - 1);
+                assert_ne!(shorter.parse::<$type>().unwrap(), val, "{}", string);
+            }
+        }
+    };
+}
+
+#[test]
+fn test_shortest_round_trip() {
+    use rand::{Rng, SeedableRng};
+    type BF64 = BigFloat<ConstCtx<64>>;
+    type BF128Up = BigFloat<ConstCtx<128, { RoundingMode::Up as u8 }>>;
+    type BF512Even = BigFloat<ConstCtx<512, { RoundingMode::ToEven as u8 }>>;
+    let mut rng = rand::rngs::StdRng::seed_from_u64(14);
+    let values: Vec<BF512Even> = (0..200)
+        .map(|_| {
+            let mantissa =
+                BF512Even::from_u64(rng.gen()).unwrap() / BF512Even::from_u64(rng.gen()).unwrap();
+            let scale = BF512Even::from_u8(10).unwrap().powi(rng.gen_range(-40..40));
+            let sign = if rng.gen() { 1i8 } else { -1i8 };
+            mantissa * scale * sign
+        })
+        .chain(
+            ["0.1", "1", "-2.5", "1e21", "1e-8", "123.456", "3e100"]
+                .iter()
+                .map(|s| s.parse().unwrap()),
+        )
+        .collect();
+    shortest_round_trip!(BF64, values);
+    shortest_round_trip!(BF128, values);
+    shortest_round_trip!(BF128Even, values);
+    shortest_round_trip!(BF128Up, values);
+    shortest_round_trip!(BF512Even, values);
+}
+
+#[test]
+fn test_huge_exponent_shortest() {
+    // Far outside the f64 range and close to both ends of the exponent range
+    let two = BF128Even::from_u8(2).unwrap();
+    let ten = BF128Even::from_u8(10).unwrap();
+    let values = [
+        two.clone().powi(2_000_000_000),
+        -two.powi(-2_000_000_000),
+        ten.powi(1_000_000) / BF128Even::from_u8(3).unwrap(),
+    ];
+    shortest_round_trip!(BF128, values);
+    shortest_round_trip!(BF128Even, values);
+}
+
+#[test]
+fn test_shortest_string() {
+    let tests: Vec<(&str, &str)> = vec![
+        ("0.1", "0.1"),
+        ("-0.001", "-0.001"),
+        ("1.5e-7", "0.00000015"),
+        ("1.5e-8", "1.5e-8"),
+        ("100", "100"),
+        ("123.25", "123.25"),
+        ("1e20", "100000000000000000000"),
+        ("1e21", "1e21"),
+        ("-6.02214076e23", "-6.02214076e23"),
+        ("Inf", "Inf"),
+        ("-Inf", "-Inf"),
+        ("0", "0"),
+    ];
+    for (string, shortest) in tests {
+        let val: BF128Even = string.parse().unwrap();
+        assert_eq!(val.to_shortest_string(), shortest);
+    }
+    let neg_zero = -BF128::from_u8(0).unwrap();
+    assert_eq!(neg_zero.to_shortest_string(), "-0");
+    assert_eq!(
+        BF128Even::pi().to_shortest_string(),
+        "3.1415926535897932384626433832795028842"
+    );
+}
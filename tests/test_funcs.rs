@@ -1,6 +1,6 @@
 use astro_nalgebra::{BigFloat, ConstCtx};
 use nalgebra::{ComplexField, RealField};
-use num_traits::FromPrimitive;
+use num_traits::{FromPrimitive, Zero};
 use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
 
 type BF256 = BigFloat<ConstCtx<256>>;
@@ -8,7 +8,9 @@ type BF256 = BigFloat<ConstCtx<256>>;
 macro_rules! test_function {
     ($one:literal, $two:literal, $op:tt, $res:literal) => {
         assert_eq!(
-            $one.parse::<BF256>().unwrap().$op($two.parse().unwrap()),
+            $one.parse::<BF256>()
+                .unwrap()
+                .$op($two.parse::<BF256>().unwrap()),
             $res.parse::<BF256>().unwrap()
         );
     };
@@ -29,6 +31,99 @@ fn test_basic_operations() {
     test_function!("8", "4", div, "2");
 }
 
+macro_rules! test_ref_operation {
+    ($one:ident, $two:ident, $op:tt, $op_assign:tt) => {
+        let owned = $one.clone() $op $two.clone();
+        assert_eq!(&$one $op &$two, owned);
+        assert_eq!($one.clone() $op &$two, owned);
+        assert_eq!(&$one $op $two.clone(), owned);
+        let mut assigned = $one.clone();
+        assigned $op_assign &$two;
+        assert_eq!(assigned, owned);
+    };
+}
+
+#[test]
+fn test_reference_operations() {
+    let one: BF256 = "45.5".parse().unwrap();
+    let two: BF256 = "-2.25".parse().unwrap();
+    test_ref_operation!(one, two, +, +=);
+    test_ref_operation!(one, two, -, -=);
+    test_ref_operation!(one, two, *, *=);
+    test_ref_operation!(one, two, /, /=);
+    test_ref_operation!(one, two, %, %=);
+    assert_eq!(-&one, -one.clone());
+}
+
+macro_rules! test_prim_operation {
+    ($big:ident, $prim:expr, $op:tt, $op_assign:tt) => {
+        let converted = BF256::from_f64($prim as f64).unwrap();
+        assert_eq!($big.clone() $op $prim, $big.clone() $op converted.clone());
+        assert_eq!(&$big $op $prim, $big.clone() $op converted.clone());
+        assert_eq!($prim $op $big.clone(), converted.clone() $op $big.clone());
+        assert_eq!($prim $op &$big, converted.clone() $op $big.clone());
+        let mut assigned = $big.clone();
+        assigned $op_assign $prim;
+        assert_eq!(assigned, $big.clone() $op converted);
+    };
+    ($big:ident, $prim:expr) => {
+        test_prim_operation!($big, $prim, +, +=);
+        test_prim_operation!($big, $prim, -, -=);
+        test_prim_operation!($big, $prim, *, *=);
+        test_prim_operation!($big, $prim, /, /=);
+        test_prim_operation!($big, $prim, %, %=);
+    };
+}
+
+#[test]
+fn test_primitive_operations() {
+    let big: BF256 = "45.5".parse().unwrap();
+    test_prim_operation!(big, 2.5f64);
+    test_prim_operation!(big, -0.75f32);
+    test_prim_operation!(big, -3i8);
+    test_prim_operation!(big, 7i16);
+    test_prim_operation!(big, -11i32);
+    test_prim_operation!(big, 13i64);
+    test_prim_operation!(big, -17i128);
+    test_prim_operation!(big, 3u8);
+    test_prim_operation!(big, 5u16);
+    test_prim_operation!(big, 9u32);
+    test_prim_operation!(big, 6u64);
+    test_prim_operation!(big, 21u128);
+    // Literals without a suffix fall back to f64 and i32
+    assert_eq!(big.clone() * 2.0, "91".parse::<BF256>().unwrap());
+    assert_eq!(1 + big.clone(), "46.5".parse::<BF256>().unwrap());
+    let huge = u128::MAX;
+    assert_eq!(
+        BF256::from_u128(0).unwrap() + huge,
+        BF256::from_u128(huge).unwrap()
+    );
+}
+
+#[test]
+fn test_fused_multiply_add() {
+    type BF64Even = BigFloat<ConstCtx<64, { astro_nalgebra::RoundingMode::ToEven as u8 }>>;
+    // x = 1 + 2^-63 uses every bit of the mantissa, so x * x = 1 + 2^-62 + 2^-126 is inexact
+    let tiny = BF64Even::from_f64(2f64.powi(-63)).unwrap();
+    let x = BF64Even::from_f64(1.0).unwrap() + tiny.clone();
+    let rounded_square = x.clone() * x.clone();
+    let expected = BF64Even::from_f64(2f64.powi(-126)).unwrap();
+    assert_eq!(x.fms(&x, &rounded_square), expected);
+    assert_eq!(x.fma(&x, &-rounded_square.clone()), expected);
+    assert_eq!(
+        x.clone().mul_add(x.clone(), -rounded_square.clone()),
+        expected
+    );
+    // Rounding twice loses the low bits entirely
+    assert!((x.clone() * x.clone() - rounded_square).is_zero());
+
+    let three: BF256 = "3".parse().unwrap();
+    let four: BF256 = "4".parse().unwrap();
+    let five: BF256 = "5".parse().unwrap();
+    assert_eq!(three.fma(&four, &five), "17".parse::<BF256>().unwrap());
+    assert_eq!(three.fms(&four, &five), "7".parse::<BF256>().unwrap());
+}
+
 #[test]
 fn test_functions() {
     test_function!("1", ln, "0");
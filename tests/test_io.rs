@@ -0,0 +1,217 @@
+#![cfg(feature = "io")]
+use astro_nalgebra::io::{
+    read_csv, read_matrix_market, write_csv, write_matrix_market, EntryFormat, MatrixMarketLayout,
+    MatrixReadErrorKind,
+};
+use astro_nalgebra::{BigFloat, ConstCtx, ParseBigFloatErrorKind, RoundingMode};
+use nalgebra::{DMatrix, RealField};
+use num_traits::Zero;
+
+type BF128 = BigFloat<ConstCtx<128, { RoundingMode::ToEven as u8 }>>;
+type BF256 = BigFloat<ConstCtx<256, { RoundingMode::ToEven as u8 }>>;
+
+fn matrix() -> DMatrix<BF128> {
+    let entries = ["0", "-2.5", "0.1", "1e-300", "0", "-6.02214076e23"];
+    let mut mat = DMatrix::from_iterator(2, 3, entries.iter().map(|s| s.parse().unwrap()));
+    mat[(0, 2)] = BF128::pi();
+    mat
+}
+
+fn read_mm(file: &str) -> DMatrix<BF128> {
+    read_matrix_market(file.as_bytes()).unwrap()
+}
+
+macro_rules! assert_kind {
+    ($result:expr, $kind:pat) => {
+        let err = $result.unwrap_err();
+        assert!(matches!(err.kind(), $kind), "unexpected error {}", err);
+    };
+}
+
+fn position<T: std::fmt::Debug>(
+    result: Result<T, astro_nalgebra::io::MatrixReadError>,
+) -> (usize, usize) {
+    let err = result.unwrap_err();
+    (err.line(), err.column())
+}
+
+#[test]
+fn test_round_trip() {
+    let mat = matrix();
+    for format in [EntryFormat::Decimal, EntryFormat::Hex] {
+        for layout in [MatrixMarketLayout::Dense, MatrixMarketLayout::Coordinate] {
+            let mut file = Vec::new();
+            write_matrix_market(&mut file, &mat, layout, format).unwrap();
+            assert_eq!(
+                read_matrix_market::<_, _>(&file[..]).ok(),
+                Some(mat.clone())
+            );
+        }
+        let mut file = Vec::new();
+        write_csv(&mut file, &mat, format).unwrap();
+        assert_eq!(read_csv::<_, _>(&file[..]).ok(), Some(mat.clone()));
+    }
+}
+
+#[test]
+fn test_hex_keeps_every_bit() {
+    let mat = DMatrix::from_element(1, 1, BF256::pi());
+    let mut file = Vec::new();
+    write_csv(&mut file, &mat, EntryFormat::Hex).unwrap();
+    let read: DMatrix<BF256> = read_csv(&file[..]).unwrap();
+    assert_eq!(read, mat);
+    // A lower precision context rounds the exact entry once
+    let read: DMatrix<BF128> = read_csv(&file[..]).unwrap();
+    assert_eq!(read[(0, 0)], BF128::pi());
+}
+
+#[test]
+fn test_write_matrix_market() {
+    let mut mat = DMatrix::from_element(2, 2, BF128::zero());
+    mat[(1, 0)] = "0.1".parse().unwrap();
+    mat[(0, 1)] = "-3".parse().unwrap();
+    let mut file = Vec::new();
+    write_matrix_market(
+        &mut file,
+        &mat,
+        MatrixMarketLayout::Dense,
+        EntryFormat::Decimal,
+    )
+    .unwrap();
+    assert_eq!(
+        String::from_utf8(file).unwrap(),
+        "%%MatrixMarket matrix array real general\n2 2\n0\n0.1\n-3\n0\n"
+    );
+    let mut file = Vec::new();
+    write_matrix_market(
+        &mut file,
+        &mat,
+        MatrixMarketLayout::Coordinate,
+        EntryFormat::Hex,
+    )
+    .unwrap();
+    assert_eq!(
+        String::from_utf8(file).unwrap(),
+        "%%MatrixMarket matrix coordinate real general\n2 2 2\n2 1 0x1.9999999999999999999999999999999ap-4\n1 2 -0x1.8p+1\n"
+    );
+}
+
+#[test]
+fn test_read_matrix_market() {
+    let dense = read_mm("%%MatrixMarket matrix array real general\n% comment\n\n2 2\n1\n2\n3 4\n");
+    assert_eq!(dense[(1, 0)].as_f64(), 2.);
+    assert_eq!(dense[(0, 1)].as_f64(), 3.);
+    let symmetric = read_mm("%%MatrixMarket matrix array real symmetric\n2 2\n1\n2\n3\n");
+    assert_eq!(symmetric[(0, 1)].as_f64(), 2.);
+    assert_eq!(symmetric[(1, 1)].as_f64(), 3.);
+    let skew = read_mm("%%MATRIXMARKET Matrix Array Integer Skew-Symmetric\n2 2\n5\n");
+    assert_eq!(skew[(1, 0)].as_f64(), 5.);
+    assert_eq!(skew[(0, 1)].as_f64(), -5.);
+    assert!(skew[(0, 0)].is_zero());
+    let pattern = read_mm("%%MatrixMarket matrix coordinate pattern general\n2 3 2\n1 3\n2 1\n");
+    assert_eq!(pattern.shape(), (2, 3));
+    assert_eq!(pattern[(0, 2)].as_f64(), 1.);
+    assert_eq!(pattern[(1, 0)].as_f64(), 1.);
+    assert_eq!(pattern.iter().filter(|val| val.is_zero()).count(), 4);
+    let tenth = read_mm("%%MatrixMarket matrix coordinate real general\n1 1 1\n1 1 0.1\n");
+    assert_eq!(tenth[(0, 0)], "0.1".parse::<BF128>().unwrap());
+}
+
+#[test]
+fn test_matrix_market_errors() {
+    let read = |file: &str| read_matrix_market::<ConstCtx<128>, _>(file.as_bytes());
+    assert_kind!(read(""), MatrixReadErrorKind::InvalidHeader);
+    assert_eq!(position(read("")), (1, 1));
+    assert_kind!(
+        read("%%MatrixMarket matrix array complex general\n"),
+        MatrixReadErrorKind::InvalidHeader
+    );
+    assert_eq!(
+        position(read("%%MatrixMarket matrix array complex general\n")),
+        (1, 29)
+    );
+    assert_eq!(
+        position(read("%%MatrixMarket matrix array real\n")),
+        (1, 33)
+    );
+    assert_kind!(
+        read("%%MatrixMarket matrix array real general\n"),
+        MatrixReadErrorKind::InvalidSize
+    );
+    assert_kind!(
+        read("%%MatrixMarket matrix array real general\n2 x\n"),
+        MatrixReadErrorKind::InvalidSize
+    );
+    assert_eq!(
+        position(read("%%MatrixMarket matrix array real general\n2 x\n")),
+        (2, 3)
+    );
+    assert_kind!(
+        read("%%MatrixMarket matrix array real symmetric\n2 3\n"),
+        MatrixReadErrorKind::InvalidSize
+    );
+    assert_kind!(
+        read("%%MatrixMarket matrix array real general\n1 2\n1\n"),
+        MatrixReadErrorKind::WrongEntryCount
+    );
+    assert_eq!(
+        position(read("%%MatrixMarket matrix array real general\n1 2\n1\n")),
+        (4, 1)
+    );
+    assert_eq!(
+        position(read("%%MatrixMarket matrix array real general\n1 1\n1 2\n")),
+        (3, 3)
+    );
+
+    let coordinate = "%%MatrixMarket matrix coordinate real general\n2 2 1\n";
+    assert_kind!(
+        read(&format!("{coordinate}3 1 1\n")),
+        MatrixReadErrorKind::InvalidIndex
+    );
+    assert_eq!(position(read(&format!("{coordinate}1 0 1\n"))), (3, 3));
+    assert_kind!(
+        read(&format!("{coordinate}1 1\n")),
+        MatrixReadErrorKind::WrongEntryCount
+    );
+    assert_kind!(
+        read(&format!("{coordinate}1 1 1\n2 2 2\n")),
+        MatrixReadErrorKind::WrongEntryCount
+    );
+    assert_eq!(
+        position(read(&format!("{coordinate}1 1 1\n2 2 2\n"))),
+        (4, 1)
+    );
+    assert_kind!(read(coordinate), MatrixReadErrorKind::WrongEntryCount);
+
+    let err = read(&format!("{coordinate}1 1   1.5x\n")).unwrap_err();
+    assert_eq!((err.line(), err.column()), (3, 10));
+    match err.kind() {
+        MatrixReadErrorKind::InvalidNumber(parse) => {
+            assert_eq!(parse.kind(), &ParseBigFloatErrorKind::InvalidDigit);
+            assert_eq!(parse.position(), 3);
+        }
+        kind => panic!("unexpected error kind {:?}", kind),
+    }
+    assert_eq!(
+        err.to_string(),
+        "invalid number: invalid digit at byte 3 at line 3, column 10"
+    );
+}
+
+#[test]
+fn test_read_csv() {
+    let mat: DMatrix<BF128> =
+        read_csv("\n 1 ,\"2\",0x1p-2\r\n\n-4,  5e1,\t inf\n".as_bytes()).unwrap();
+    assert_eq!(mat.shape(), (2, 3));
+    let values: Vec<f64> = mat.transpose().iter().map(|val| val.as_f64()).collect();
+    assert_eq!(values, [1., 2., 0.25, -4., 50., f64::INFINITY]);
+    let empty: DMatrix<BF128> = read_csv("".as_bytes()).unwrap();
+    assert_eq!(empty.shape(), (0, 0));
+
+    let read = |file: &str| read_csv::<ConstCtx<128>, _>(file.as_bytes());
+    assert_eq!(position(read("1,2\n3,4,5\n")), (2, 5));
+    assert_eq!(position(read("1,2\n3\n")), (2, 2));
+    assert_eq!(position(read("1, \"2.5.\"\n")), (1, 8));
+    assert_eq!(position(read("1,,2\n")), (1, 3));
+    assert_kind!(read("1,2\n3\n"), MatrixReadErrorKind::WrongEntryCount);
+}
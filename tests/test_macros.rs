@@ -0,0 +1,89 @@
+use astro_nalgebra::{bf, bmat, bvec, BigFloat, ConstCtx, RoundingMode};
+use nalgebra::{Matrix2, Matrix2x3, Vector3};
+
+type BF256 = BigFloat<ConstCtx<256>>;
+type BF64Even = BigFloat<ConstCtx<64, { RoundingMode::ToEven as u8 }>>;
+
+fn parsed(string: &str) -> BF256 {
+    string.parse().unwrap()
+}
+
+#[test]
+fn test_bf_literals() {
+    let tests: Vec<(BF256, &str)> = vec![
+        (bf!(1), "1"),
+        (bf!(-2), "-2"),
+        (bf!(1.5), "1.5"),
+        (bf!(-1.5e-3), "-0.0015"),
+        (bf!(+6.02214076E23), "6.02214076e23"),
+        (bf!(1_000_000.000_1), "1000000.0001"),
+        (bf!(.5), "0.5"),
+        (bf!(2.5@2), "250"),
+        (bf!("0x1.8p3"), "12"),
+        (bf!("-0x.1"), "-0.0625"),
+        (bf!(inf), "inf"),
+        (bf!(-Infinity), "-inf"),
+    ];
+    for (float, string) in tests {
+        assert_eq!(float, parsed(string), "{}", string);
+    }
+    let nan: BF256 = bf!(NaN);
+    assert!(nan.as_f64().is_nan());
+    let pi: BF256 = bf!("0x1.921fb54442d18p+1");
+    assert_eq!(pi.as_f64(), std::f64::consts::PI);
+}
+
+#[test]
+fn test_bf_inference() {
+    fn square(x: BF256) -> BF256 {
+        x.clone() * x
+    }
+    assert_eq!(square(bf!(1.5)), bf!(2.25));
+    // The literal is rounded with the context of the inferred type
+    let tenth: BF64Even = bf!(0.1);
+    assert_eq!(tenth, "0.1".parse::<BF64Even>().unwrap());
+    assert_eq!(tenth.as_f64(), 0.1);
+}
+
+#[test]
+fn test_literal_validation() {
+    for valid in [
+        "1",
+        "- 1.5e-3",
+        "1_000",
+        "\"0x1.8p+3\"",
+        "-inf",
+        "NaN",
+        "2.5 @ -2",
+        "0x1P3",
+        "1.",
+    ] {
+        assert!(astro_nalgebra::__is_valid_literal(valid), "{}", valid);
+    }
+    for invalid in [
+        "", "-", "1.2.3", "1e", "1e+", "--1", "0x", "0x1p", "0x1e+3", "abc", "1.5f64", "x", "1 2e",
+        "\"1.5", "infx", "1 2", "in f", "- - 1", "0x1@2", "1e5 @ 2",
+    ] {
+        assert!(!astro_nalgebra::__is_valid_literal(invalid), "{}", invalid);
+    }
+}
+
+#[test]
+fn test_bvec_bmat() {
+    let vec: Vector3<BF256> = bvec![1, -2.5, "0x1p-3"];
+    assert_eq!(
+        vec,
+        Vector3::new(parsed("1"), parsed("-2.5"), parsed("0.125"))
+    );
+    let trailing: Vector3<BF256> = bvec![1, 2, 3,];
+    assert_eq!(trailing.z, bf!(3));
+    let mat: Matrix2x3<BF256> = bmat![
+        1, 2, 3;
+        4, 5.5, -6e2;
+    ];
+    assert_eq!(mat[(0, 2)], bf!(3));
+    assert_eq!(mat[(1, 0)], bf!(4));
+    assert_eq!(mat[(1, 2)], bf!(-600));
+    let square: Matrix2<BF256> = bmat![1, 2; 3, 4];
+    assert_eq!(square.transpose(), bmat![1, 3; 2, 4]);
+}
@@ -8,8 +8,8 @@ type BF128 = BigFloat<ConstCtx<128>>;
 
 #[test]
 fn test_vec3() {
-    let strs = vec!["1.2345678901234567890123456789e-1", "1.e+1", "Inf"];
-    let outs = vec!["1.2345678901234567890123456789e+0", "1.e+2", "Inf"];
+    let strs = ["1.2345678901234567890123456789e-1", "1.e+1", "Inf"];
+    let outs = ["1.2345678901234567890123456789e+0", "1.e+2", "Inf"];
     let mut vec: Vector3<BF128> = Vector3::new(
         strs[0].parse().unwrap(),
         strs[1].parse().unwrap(),
@@ -19,9 +19,12 @@ fn test_vec3() {
     assert_eq!(vec.x, outs[0].parse().unwrap());
     assert_eq!(vec.y, outs[1].parse().unwrap());
     assert_eq!(vec.z, outs[2].parse().unwrap());
-    assert_eq!(vec.x.to_string(), outs[0]);
-    assert_eq!(vec.y.to_string(), outs[1]);
-    assert_eq!(vec.z.to_string(), outs[2]);
+    #[cfg(not(feature = "shortest-display"))]
+    {
+        assert_eq!(vec.x.to_string(), outs[0]);
+        assert_eq!(vec.y.to_string(), outs[1]);
+        assert_eq!(vec.z.to_string(), outs[2]);
+    }
 }
 
 #[test]
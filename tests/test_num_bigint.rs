@@ -0,0 +1,86 @@
+#![cfg(feature = "num-bigint")]
+use astro_nalgebra::{BigFloat, BigFloatConversionError, ConstCtx, RoundingMode};
+use nalgebra::RealField;
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::{FromPrimitive, One, Zero};
+
+type BF128 = BigFloat<ConstCtx<128>>;
+type BF128Even = BigFloat<ConstCtx<128, { RoundingMode::ToEven as u8 }>>;
+type BF256 = BigFloat<ConstCtx<256>>;
+
+#[test]
+fn test_bigint_round_trip() {
+    let tests = [
+        "0",
+        "1",
+        "-1",
+        "18446744073709551616",
+        "-340282366920938463463374607431768211457",
+        "123456789012345678901234567890123456789012345678901234567890",
+    ];
+    for string in tests {
+        let int: BigInt = string.parse().unwrap();
+        let float = BF256::from(&int);
+        assert_eq!(float, string.parse::<BF256>().unwrap(), "{}", string);
+        assert_eq!(BigInt::try_from(&float), Ok(int.clone()), "{}", string);
+        assert_eq!(BigInt::try_from(float), Ok(int), "{}", string);
+    }
+    let power: BigInt = BigInt::one() << 1000;
+    assert_eq!(BigInt::try_from(BF128::from(power.clone())), Ok(power));
+}
+
+#[test]
+fn test_bigint_rounding() {
+    // 2^128 + 1 needs 129 bits
+    let int: BigInt = (BigInt::one() << 128) + 1;
+    let float = BF128::from(&int);
+    assert_eq!(BigInt::try_from(&float), Ok(BigInt::one() << 128));
+    let int: BigInt = (BigInt::one() << 129) - 1;
+    assert_eq!(
+        BigInt::try_from(BF128Even::from(&int)),
+        Ok(BigInt::one() << 129)
+    );
+}
+
+#[test]
+fn test_bigint_errors() {
+    let half = BF128::from_f64(0.5).unwrap();
+    assert_eq!(
+        BigInt::try_from(half),
+        Err(BigFloatConversionError::NotInteger)
+    );
+    let nan = BF128::from_f64(f64::NAN).unwrap();
+    assert_eq!(BigInt::try_from(&nan), Err(BigFloatConversionError::NaN));
+    assert_eq!(
+        BigRational::try_from(nan),
+        Err(BigFloatConversionError::NaN)
+    );
+    let inf = BF128::from_f64(f64::INFINITY).unwrap();
+    assert_eq!(
+        BigRational::try_from(inf),
+        Err(BigFloatConversionError::Infinite)
+    );
+}
+
+#[test]
+fn test_bigrational_conversions() {
+    let third = BigRational::new(BigInt::from(1), BigInt::from(3));
+    assert_eq!(
+        BF128::from(&third),
+        BF128::one() / BF128::from_u8(3).unwrap()
+    );
+    let neg = BigRational::new(BigInt::from(-7), BigInt::from(4));
+    assert_eq!(BF128::from(neg.clone()), "-1.75".parse().unwrap());
+    assert_eq!(BigRational::try_from(BF128::from(&neg)), Ok(neg));
+    assert_eq!(BF128::from(BigRational::zero()), BF128::zero());
+    // Exact conversion of a value with a power of two denominator
+    let pi = BF128::pi();
+    let ratio = BigRational::try_from(&pi).unwrap();
+    assert_eq!(ratio.denom().magnitude().count_ones(), 1);
+    assert_eq!(BF128::from(&ratio), pi);
+    let big = BF128::from_f64(1e30).unwrap();
+    let ratio = BigRational::try_from(&big).unwrap();
+    assert!(ratio.is_integer());
+    assert_eq!(BF128::from(ratio), big);
+}
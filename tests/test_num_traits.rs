@@ -0,0 +1,181 @@
+use astro_nalgebra::{BigFloat, ConstCtx, RoundingMode};
+use nalgebra::{ComplexField, RealField};
+use num_traits::{Bounded, Euclid, FloatConst, FromPrimitive, Inv, MulAdd, Pow, ToPrimitive};
+
+type BF256 = BigFloat<ConstCtx<256>>;
+
+macro_rules! test_float_const {
+    ($name:ident, $val:expr) => {
+        assert_eq!(
+            <BF256 as FloatConst>::$name(),
+            $val,
+            "Failed FloatConst::{}",
+            stringify!($name)
+        );
+        assert_eq!(
+            <BF256 as FloatConst>::$name().as_f64(),
+            <f64 as FloatConst>::$name(),
+            "Failed f64 mirroring for FloatConst::{}",
+            stringify!($name)
+        );
+    };
+}
+
+#[test]
+fn test_float_consts() {
+    let two = BF256::from_u8(2).unwrap();
+    test_float_const!(E, BF256::e());
+    test_float_const!(FRAC_1_PI, BF256::frac_1_pi());
+    test_float_const!(
+        FRAC_1_SQRT_2,
+        BF256::from_u8(1).unwrap() / two.clone().sqrt()
+    );
+    test_float_const!(FRAC_2_PI, BF256::frac_2_pi());
+    test_float_const!(FRAC_2_SQRT_PI, BF256::frac_2_sqrt_pi());
+    test_float_const!(FRAC_PI_2, BF256::frac_pi_2());
+    test_float_const!(FRAC_PI_3, BF256::frac_pi_3());
+    test_float_const!(FRAC_PI_4, BF256::frac_pi_4());
+    test_float_const!(FRAC_PI_6, BF256::frac_pi_6());
+    test_float_const!(FRAC_PI_8, BF256::frac_pi_8());
+    test_float_const!(LN_10, BF256::ln_10());
+    test_float_const!(LN_2, BF256::ln_2());
+    test_float_const!(LOG10_E, BF256::log10_e());
+    test_float_const!(LOG2_E, BF256::log2_e());
+    test_float_const!(PI, BF256::pi());
+    test_float_const!(SQRT_2, two.sqrt());
+    test_float_const!(TAU, BF256::two_pi());
+    test_float_const!(LOG10_2, BF256::ln_2() / BF256::ln_10());
+    test_float_const!(LOG2_10, BF256::ln_10() / BF256::ln_2());
+}
+
+#[test]
+fn test_bounded() {
+    assert_eq!(
+        <BF256 as Bounded>::max_value(),
+        <BF256 as RealField>::max_value().unwrap()
+    );
+    assert_eq!(
+        <BF256 as Bounded>::min_value(),
+        <BF256 as RealField>::min_value().unwrap()
+    );
+}
+
+#[test]
+fn test_to_primitive() {
+    type Expected = (&'static str, Option<i64>, Option<u64>, Option<i8>);
+    let tests: Vec<Expected> = vec![
+        ("0", Some(0), Some(0), Some(0)),
+        ("1", Some(1), Some(1), Some(1)),
+        ("2.9", Some(2), Some(2), Some(2)),
+        ("-0.5", Some(0), Some(0), Some(0)),
+        ("-1.5", Some(-1), None, Some(-1)),
+        ("-128", Some(-128), None, Some(-128)),
+        ("-129", Some(-129), None, None),
+        ("1e-30", Some(0), Some(0), Some(0)),
+        ("18446744073709551615", None, Some(u64::MAX), None),
+        ("-9223372036854775808", Some(i64::MIN), None, None),
+        ("1e40", None, None, None),
+        ("Inf", None, None, None),
+    ];
+    for (string, as_i64, as_u64, as_i8) in tests {
+        let num: BF256 = string.parse().unwrap();
+        assert_eq!(num.to_i64(), as_i64, "{}.to_i64()", string);
+        assert_eq!(num.to_u64(), as_u64, "{}.to_u64()", string);
+        assert_eq!(num.to_i8(), as_i8, "{}.to_i8()", string);
+    }
+    let big: BF256 = "170141183460469231731687303715884105727".parse().unwrap();
+    assert_eq!(big.to_i128(), Some(i128::MAX));
+    assert_eq!((-big.clone() - 1i32).to_i128(), Some(i128::MIN));
+    assert_eq!((big.clone() + 1i32).to_i128(), None);
+    assert_eq!((big + 1i32).to_u128(), Some(1 << 127));
+    assert_eq!(BF256::pi().to_f64(), Some(std::f64::consts::PI));
+    assert_eq!(BF256::pi().to_f32(), Some(std::f32::consts::PI));
+    assert!(BF256::from_f64(f64::NAN).unwrap().to_i32().is_none());
+}
+
+#[test]
+fn test_num_cast() {
+    let cast = |n: BF256| n;
+    assert_eq!(
+        cast(num_traits::cast(2.5f64).unwrap()),
+        "2.5".parse().unwrap()
+    );
+    assert_eq!(cast(num_traits::cast(-7i8).unwrap()), "-7".parse().unwrap());
+    // Integers beyond 2^53 are not rounded through f64
+    assert_eq!(
+        cast(num_traits::cast(u128::MAX).unwrap()),
+        BF256::from_u128(u128::MAX).unwrap()
+    );
+    assert_eq!(
+        cast(num_traits::cast(i64::MIN + 1).unwrap()),
+        BF256::from_i64(i64::MIN + 1).unwrap()
+    );
+    assert!(cast(num_traits::cast(f64::INFINITY).unwrap())
+        .as_f64()
+        .is_infinite());
+    let back: i32 = num_traits::cast(BF256::from_i32(-40).unwrap()).unwrap();
+    assert_eq!(back, -40);
+}
+
+#[test]
+fn test_inv_pow_mul_add() {
+    let four: BF256 = "4".parse().unwrap();
+    assert_eq!((&four).inv(), "0.25".parse().unwrap());
+    assert_eq!(four.clone().inv(), "0.25".parse().unwrap());
+    assert_eq!(four.clone().pow(3i32), "64".parse().unwrap());
+    assert_eq!(four.clone().pow(-1i32), "0.25".parse().unwrap());
+    assert_eq!(four.clone().pow(3u32), "64".parse().unwrap());
+    let eps = BF256::from_f64(1e-70).unwrap();
+    let sqrt = four.clone().pow(BF256::from_f64(0.5).unwrap());
+    assert!((sqrt - BF256::from_u8(2).unwrap()).abs() < eps);
+    let pow = four.clone().pow(&BF256::from_f64(1.5).unwrap());
+    assert!((pow - BF256::from_u8(8).unwrap()).abs() < eps);
+    assert_eq!(
+        MulAdd::mul_add(four.clone(), four.clone(), BF256::from_u8(1).unwrap()),
+        "17".parse().unwrap()
+    );
+}
+
+#[test]
+fn test_euclid() {
+    let nums = [7.5, -7.5, 3.0, -3.0, 0.25, -10.0];
+    for a in nums {
+        for b in nums {
+            let big_a = BF256::from_f64(a).unwrap();
+            let big_b = BF256::from_f64(b).unwrap();
+            assert_eq!(
+                big_a.div_euclid(&big_b).as_f64(),
+                a.div_euclid(b),
+                "{} div_euclid {}",
+                a,
+                b
+            );
+            assert_eq!(
+                big_a.rem_euclid(&big_b).as_f64(),
+                a.rem_euclid(b),
+                "{} rem_euclid {}",
+                a,
+                b
+            );
+        }
+    }
+}
+
+#[test]
+fn test_euclid_large_ratio() {
+    type BF128Down = BigFloat<ConstCtx<128, { RoundingMode::Down as u8 }>>;
+    type BF512Down = BigFloat<ConstCtx<512, { RoundingMode::Down as u8 }>>;
+    // The quotients need one bit more than the precision, so rounding them before taking the
+    // integer part can step past the floor
+    let big = BF128Down::from_u32(3).unwrap() * BF128Down::from_u32(2).unwrap().pow(126u32);
+    for k in [1, 2] {
+        let a = big.clone() + BF128Down::from_u32(k).unwrap();
+        for b in [1.5, -1.5] {
+            let b = BF128Down::from_f64(b).unwrap();
+            let q = a.div_euclid(&b);
+            let r = a.rem_euclid(&b);
+            let sum: BF512Down = q.cast() * b.cast() + r.cast();
+            assert_eq!(sum, a.cast(), "{} div_euclid {}", a, b);
+        }
+    }
+}
@@ -1,7 +1,9 @@
-use astro_nalgebra::{self, BigFloat, ConstCtx};
-use num_traits::Num;
+use astro_nalgebra::{self, BigFloat, ConstCtx, ParseBigFloatErrorKind, RoundingMode};
+use nalgebra::{ComplexField, RealField};
+use num_traits::{FromPrimitive, Num, One};
 
 type BF128 = BigFloat<ConstCtx<128>>;
+type BF128Even = BigFloat<ConstCtx<128, { RoundingMode::ToEven as u8 }>>;
 
 macro_rules! base_parse_tests {
     ($tests:ident, $base:literal, $formatter:literal) => {
@@ -16,6 +18,7 @@ macro_rules! base_parse_tests {
 
 #[test]
 fn test_string_parse_base10() {
+    #[cfg(not(feature = "shortest-display"))]
     let tests: Vec<(&str, &str, f64)> = vec![
         ("1", "1.e+0", 1.),
         ("0", "0.0", 0.),
@@ -26,9 +29,23 @@ fn test_string_parse_base10() {
         ("Inf", "Inf", f64::INFINITY),
         ("-Inf", "-Inf", f64::NEG_INFINITY),
     ];
+    #[cfg(feature = "shortest-display")]
+    let tests: Vec<(&str, &str, f64)> = vec![
+        ("1", "1", 1.),
+        ("0", "0", 0.),
+        ("10000", "10000", 10000.),
+        ("1.1", "1.1", 1.1),
+        ("1.5", "1.5", 1.5),
+        ("0.5", "0.5", 0.5),
+        ("Inf", "Inf", f64::INFINITY),
+        ("-Inf", "-Inf", f64::NEG_INFINITY),
+    ];
     base_parse_tests!(tests, 10, "{}");
     let float: BF128 = "1.00000000000000000001".parse().unwrap();
+    #[cfg(not(feature = "shortest-display"))]
     assert_eq!("1.00000000000000000001e+0", float.to_string());
+    #[cfg(feature = "shortest-display")]
+    assert_eq!("1.00000000000000000001", float.to_string());
 }
 
 #[test]
@@ -67,14 +84,14 @@ fn test_string_parse_base8() {
 #[test]
 fn test_string_parse_base16() {
     let tests: Vec<(&str, &str, f64)> = vec![
-        ("1", "1._e+0", 1.),
-        ("0", "0.0", 0.),
-        ("10000", "1._e+4", 65536.),
-        ("1.1", "1.1_e+0", 1.0625),
-        ("1.11000", "1.11_e+0", 1.06640625),
-        ("0.1", "1._e-1", 0.0625),
-        ("A", "A._e+0", 10.),
-        ("FF", "F.F_e+1", 255.),
+        ("1", "1P+0", 1.),
+        ("0", "0P+0", 0.),
+        ("10000", "1P+16", 65536.),
+        ("1.1", "1.1P+0", 1.0625),
+        ("1.11000", "1.11P+0", 1.06640625),
+        ("0.1", "1P-4", 0.0625),
+        ("A", "1.4P+3", 10.),
+        ("FF", "1.FEP+7", 255.),
         ("Inf", "Inf", f64::INFINITY),
         ("-Inf", "-Inf", f64::NEG_INFINITY),
     ];
@@ -83,17 +100,193 @@ fn test_string_parse_base16() {
 
 #[test]
 fn test_string_parse_errors() {
-    for x in 0..=26 {
-        if x == 2 || x == 8 || x == 10 || x == 16 {
-            continue;
+    for x in [0, 1, 37, 64, u32::MAX] {
+        let err = BF128::from_str_radix("123", x).unwrap_err();
+        assert_eq!(err.kind(), &ParseBigFloatErrorKind::InvalidRadix);
+    }
+    let tests: Vec<(&str, u32, ParseBigFloatErrorKind, usize)> = vec![
+        ("", 10, ParseBigFloatErrorKind::Empty, 0),
+        ("()", 10, ParseBigFloatErrorKind::InvalidDigit, 0),
+        ("-", 10, ParseBigFloatErrorKind::InvalidDigit, 1),
+        (".", 10, ParseBigFloatErrorKind::InvalidDigit, 1),
+        ("+.e5", 10, ParseBigFloatErrorKind::InvalidDigit, 2),
+        ("12x4", 10, ParseBigFloatErrorKind::InvalidDigit, 2),
+        ("1.2.3", 10, ParseBigFloatErrorKind::InvalidDigit, 3),
+        ("102", 2, ParseBigFloatErrorKind::InvalidDigit, 2),
+        ("1.5g", 16, ParseBigFloatErrorKind::InvalidDigit, 3),
+        ("1e", 10, ParseBigFloatErrorKind::InvalidExponent, 2),
+        ("1.5e+", 10, ParseBigFloatErrorKind::InvalidExponent, 5),
+        ("1e5x", 10, ParseBigFloatErrorKind::InvalidExponent, 3),
+        ("1e2.5", 10, ParseBigFloatErrorKind::InvalidExponent, 3),
+        ("1e12", 2, ParseBigFloatErrorKind::InvalidExponent, 3),
+        ("A_e", 16, ParseBigFloatErrorKind::InvalidExponent, 3),
+        ("1.5 ", 10, ParseBigFloatErrorKind::TrailingCharacters, 3),
+        ("1e5,", 10, ParseBigFloatErrorKind::TrailingCharacters, 3),
+        ("1_", 10, ParseBigFloatErrorKind::TrailingCharacters, 1),
+        ("Infx", 10, ParseBigFloatErrorKind::TrailingCharacters, 3),
+        ("nan!", 10, ParseBigFloatErrorKind::TrailingCharacters, 3),
+        (
+            "1.5\u{b5}",
+            10,
+            ParseBigFloatErrorKind::TrailingCharacters,
+            3,
+        ),
+        ("1e2", 12, ParseBigFloatErrorKind::InvalidDigit, 1),
+        ("z", 35, ParseBigFloatErrorKind::InvalidDigit, 0),
+        ("1@", 20, ParseBigFloatErrorKind::InvalidExponent, 2),
+        ("1@1a", 20, ParseBigFloatErrorKind::InvalidExponent, 3),
+        ("0x", 10, ParseBigFloatErrorKind::InvalidDigit, 2),
+        ("-0x.p1", 16, ParseBigFloatErrorKind::InvalidDigit, 4),
+        ("0x1g", 10, ParseBigFloatErrorKind::InvalidDigit, 3),
+        ("0x1p", 10, ParseBigFloatErrorKind::InvalidExponent, 4),
+        ("0x1pa", 10, ParseBigFloatErrorKind::InvalidExponent, 4),
+        ("0x1_e1", 16, ParseBigFloatErrorKind::TrailingCharacters, 3),
+    ];
+    for (string, radix, kind, position) in tests {
+        let err = BF128::from_str_radix(string, radix).unwrap_err();
+        assert_eq!(
+            (err.kind(), err.position()),
+            (&kind, position),
+            "{:?}",
+            string
+        );
+    }
+}
+
+#[test]
+fn test_string_parse_special() {
+    for string in ["nan", "NaN", "NAN", "-nan", "+NaN"] {
+        assert!(
+            string.parse::<BF128>().unwrap().as_f64().is_nan(),
+            "{}",
+            string
+        );
+    }
+    for string in ["inf", "Inf", "+INF", "infinity", "Infinity"] {
+        let float: BF128 = string.parse().unwrap();
+        assert_eq!(float.as_f64(), f64::INFINITY, "{}", string);
+    }
+    for string in ["-inf", "-Inf", "-INFINITY"] {
+        let float: BF128 = string.parse().unwrap();
+        assert_eq!(float.as_f64(), f64::NEG_INFINITY, "{}", string);
+    }
+    // Special values parse the same way in every radix
+    assert!(BF128::from_str_radix("NaN", 16).unwrap().as_f64().is_nan());
+    assert_eq!(
+        BF128::from_str_radix("-Inf", 2).unwrap().as_f64(),
+        f64::NEG_INFINITY
+    );
+    // A number that starts like a special value is not one
+    assert_eq!(BF128::from_str_radix("a", 16).unwrap().as_f64(), 10.);
+}
+
+#[test]
+fn test_parse_error_display() {
+    let err = "12x4".parse::<BF128>().unwrap_err();
+    assert_eq!(err.to_string(), "invalid digit at byte 2");
+    let err = "1e+".parse::<BF128>().unwrap_err();
+    assert_eq!(err.to_string(), "invalid exponent at byte 3");
+    let err = "1 ".parse::<BF128>().unwrap_err();
+    assert_eq!(err.to_string(), "unexpected trailing characters at byte 1");
+    let err = "".parse::<BF128>().unwrap_err();
+    assert_eq!(err.to_string(), "cannot parse number from empty string");
+    let err = BF128::from_str_radix("1", 37).unwrap_err();
+    assert_eq!(err.to_string(), "invalid radix");
+    let err: Box<dyn std::error::Error> = Box::new(err);
+    assert_eq!(err.to_string(), "invalid radix");
+}
+
+#[test]
+fn test_string_parse_any_radix() {
+    let tests: Vec<(&str, u32, f64)> = vec![
+        ("12", 3, 5.),
+        ("-2.1e-1", 3, -7. / 9.),
+        ("1e2", 3, 9.),
+        ("1.1@3", 2, 12.),
+        ("1.5@2", 10, 150.),
+        ("7.7@-1", 8, 0.984375),
+        ("a.8@1", 16, 168.),
+        ("a.8", 12, 10.666666666666666),
+        ("z@1", 36, 1260.),
+        ("ZZ.I", 36, 1295.5),
+        ("-0", 7, -0.),
+        ("000.000", 30, 0.),
+        ("1@99999999999999999999", 7, f64::INFINITY),
+        ("-1@999999999999", 36, f64::NEG_INFINITY),
+        ("1@-999999999999", 36, 0.),
+        ("nan", 36, f64::NAN),
+        ("inf", 36, f64::INFINITY),
+    ];
+    for (string, radix, num) in tests {
+        let float = BF128Even::from_str_radix(string, radix).unwrap().as_f64();
+        match num.is_nan() {
+            true => assert!(float.is_nan(), "{}", string),
+            false => assert_eq!(float, num, "{}", string),
         }
         assert_eq!(
-            BF128::from_str_radix("123", x),
-            Err(astro_nalgebra::ParseBigFloatError::InvalidRadix)
+            float.is_sign_negative(),
+            num.is_sign_negative(),
+            "{}",
+            string
         );
     }
+    // The parsed value is correctly rounded
+    let third = BF128Even::one() / BF128Even::from_u8(3).unwrap();
+    assert_eq!(BF128Even::from_str_radix("0.1", 3).unwrap(), third);
+    assert_eq!(BF128Even::from_str_radix("0.c", 36).unwrap(), third);
+    let tenth = BF128Even::one() / BF128Even::from_u8(10).unwrap();
+    assert_eq!(BF128Even::from_str_radix("1@-1", 10).unwrap(), tenth);
+    assert_eq!(BF128Even::from_str_radix("0.1", 10).unwrap(), tenth);
+}
+
+#[test]
+fn test_to_string_radix_round_trip() {
+    let values: Vec<BF128Even> = vec![
+        BF128Even::pi(),
+        -BF128Even::e() * BF128Even::from_u64(123456789).unwrap(),
+        BF128Even::from_u8(10).unwrap().powi(-40),
+    ];
+    // Radix 2 and 8 are left out because their `e` exponent is read in that radix
+    for radix in (3..=36).filter(|radix| *radix != 8) {
+        let digits = (128. / (radix as f64).log2()).ceil() as usize + 1;
+        for val in values.iter() {
+            let string = val.to_string_radix(radix, digits, RoundingMode::ToEven);
+            let parsed = BF128Even::from_str_radix(&string, radix).unwrap();
+            assert_eq!(&parsed, val, "{} in radix {}", string, radix);
+        }
+    }
+}
+
+#[test]
+fn test_string_parse_hex_literal() {
+    let tests: Vec<(&str, f64)> = vec![
+        ("0x1.921fb54442d18p+1", std::f64::consts::PI),
+        ("-0x1.5bf0a8b145769p1", -std::f64::consts::E),
+        ("0x1p-1074", 5e-324),
+        ("-0x1.fffffffffffffp+1023", f64::MIN),
+        ("0X.8P1", 1.),
+        ("0x10", 16.),
+        ("+0xA.8", 10.5),
+        ("0x1.8e3", 1.5554199),
+        ("-0x0p0", -0.),
+    ];
+    for (string, num) in tests {
+        let float: BF128Even = string.parse().unwrap();
+        assert!((float.as_f64() - num).abs() <= 1e-7, "{}", string);
+        assert_eq!(float.as_f64().is_sign_negative(), num.is_sign_negative());
+        if string != "0x1.8e3" {
+            assert_eq!(float.as_f64(), num, "{}", string);
+            let float = BF128Even::from_str_radix(string, 16).unwrap();
+            assert_eq!(float.as_f64(), num, "{}", string);
+        }
+    }
+    // Literals with more bits than the precision are rounded with the context rounding mode
+    type BF64Even = BigFloat<ConstCtx<64, { RoundingMode::ToEven as u8 }>>;
+    type BF64Zero = BigFloat<ConstCtx<64, { RoundingMode::ToZero as u8 }>>;
+    let long = "0x1.ffffffffffffffff8p0";
+    assert_eq!(long.parse::<BF64Even>().unwrap().as_f64(), 2.);
     assert_eq!(
-        BF128::from_str_radix("()", 10),
-        Err(astro_nalgebra::ParseBigFloatError::InvalidNumber)
+        long.parse::<BF64Zero>().unwrap(),
+        "0x1.fffffffffffffffep0".parse::<BF64Zero>().unwrap()
     );
 }
@@ -0,0 +1,154 @@
+#![cfg(feature = "serde")]
+use astro_nalgebra::{BigFloat, ConstCtx, RoundingMode};
+use nalgebra::{RealField, Vector2};
+use num_traits::{FromPrimitive, One, Zero};
+use serde::{Deserialize, Serialize};
+
+type BF128 = BigFloat<ConstCtx<128, { RoundingMode::ToEven as u8 }>>;
+type BF64 = BigFloat<ConstCtx<64, { RoundingMode::ToEven as u8 }>>;
+type BF256 = BigFloat<ConstCtx<256, { RoundingMode::ToEven as u8 }>>;
+
+fn values() -> Vec<BF128> {
+    [
+        "0",
+        "-0",
+        "1",
+        "-2.5",
+        "0.1",
+        "1e-300",
+        "-6.02214076e23",
+        "Inf",
+        "-Inf",
+    ]
+    .iter()
+    .map(|string| string.parse().unwrap())
+    .chain([BF128::pi(), -BF128::e()])
+    .collect()
+}
+
+#[test]
+fn test_json_round_trip() {
+    for val in values() {
+        let json = serde_json::to_string(&val).unwrap();
+        assert_eq!(json, format!("\"{}\"", val.to_shortest_string()));
+        let back: BF128 = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, val, "{}", json);
+        assert_eq!(back.is_sign_negative(), val.is_sign_negative(), "{}", json);
+    }
+    assert_eq!(
+        serde_json::to_string(&BF128::pi()).unwrap(),
+        "\"3.1415926535897932384626433832795028842\""
+    );
+    let nan: BF128 =
+        serde_json::from_str(&serde_json::to_string(&(BF128::zero() / BF128::zero())).unwrap())
+            .unwrap();
+    assert!(nan.as_f64().is_nan());
+}
+
+#[test]
+fn test_bincode_round_trip() {
+    for val in values() {
+        let bytes = bincode::serialize(&val).unwrap();
+        let back: BF128 = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(back, val);
+        assert_eq!(back.is_sign_negative(), val.is_sign_negative());
+    }
+    // A sign byte, the exponent, the number of words and the words
+    let bytes = bincode::serialize(&BF128::pi()).unwrap();
+    assert_eq!(bytes.len(), 1 + 4 + 8 + 2 * 8);
+    let bytes = bincode::serialize(&"0.75".parse::<BF128>().unwrap()).unwrap();
+    assert_eq!(bytes.len(), 1 + 4 + 8 + 8);
+    let nan: BF128 =
+        bincode::deserialize(&bincode::serialize(&(BF128::zero() / BF128::zero())).unwrap())
+            .unwrap();
+    assert!(nan.as_f64().is_nan());
+}
+
+#[test]
+fn test_deserialize_rounds_to_context() {
+    let pi = BF256::pi();
+    // The compact form keeps every bit, so reading it with less precision rounds once
+    let bytes = bincode::serialize(&pi).unwrap();
+    let rounded: BF64 = bincode::deserialize(&bytes).unwrap();
+    assert_eq!(
+        rounded,
+        pi.cast::<ConstCtx<64, { RoundingMode::ToEven as u8 }>>()
+    );
+    let json = serde_json::to_string(&pi).unwrap();
+    let rounded: BF64 = serde_json::from_str(&json).unwrap();
+    assert_eq!(rounded, BF64::pi());
+}
+
+#[test]
+fn test_json_numbers() {
+    let tests: Vec<(&str, &str)> = vec![
+        ("0.1", "0.1"),
+        ("-1.5e-7", "-1.5e-7"),
+        ("12345678901234567890", "12345678901234567890"),
+        ("-42", "-42"),
+        ("\"0x1.8p3\"", "12"),
+        (
+            "\"1.00000000000000000000000001\"",
+            "1.00000000000000000000000001",
+        ),
+        ("[-1, 1, [13835058055282163712]]", "-1.5"),
+    ];
+    for (json, string) in tests {
+        let val: BF128 = serde_json::from_str(json).unwrap();
+        assert_eq!(val, string.parse::<BF128>().unwrap(), "{}", json);
+    }
+    for json in ["\"1.2.3\"", "true", "[2, 0, []]", "[1, 0]", "null"] {
+        assert!(serde_json::from_str::<BF128>(json).is_err(), "{}", json);
+    }
+    let err = serde_json::from_str::<BF128>("\"12x\"").unwrap_err();
+    assert!(
+        err.to_string().contains("invalid digit at byte 2"),
+        "{}",
+        err
+    );
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Helpers {
+    #[serde(with = "astro_nalgebra::serde::decimal")]
+    decimal: BF128,
+    #[serde(with = "astro_nalgebra::serde::hex")]
+    hex: BF128,
+    #[serde(with = "astro_nalgebra::serde::compact")]
+    compact: BF128,
+    default: BF128,
+}
+
+#[test]
+fn test_with_helpers() {
+    let val = Helpers {
+        decimal: "0.1".parse().unwrap(),
+        hex: "-12".parse().unwrap(),
+        compact: "0.75".parse().unwrap(),
+        default: "2".parse().unwrap(),
+    };
+    let json = serde_json::to_string(&val).unwrap();
+    assert_eq!(
+        json,
+        r#"{"decimal":"0.1","hex":"-0x1.8p+3","compact":[1,0,[13835058055282163712]],"default":"2"}"#
+    );
+    assert_eq!(serde_json::from_str::<Helpers>(&json).unwrap(), val);
+    let bytes = bincode::serialize(&val).unwrap();
+    assert_eq!(bincode::deserialize::<Helpers>(&bytes).unwrap(), val);
+    // Hexadecimal strings are exact in any context
+    let third = BF256::one() / BF256::from_u8(3).unwrap();
+    let json = serde_json::to_string(&Hex(third.clone())).unwrap();
+    let Hex(back) = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, third);
+}
+
+#[derive(Serialize, Deserialize)]
+struct Hex(#[serde(with = "astro_nalgebra::serde::hex")] BF256);
+
+#[test]
+fn test_nalgebra_serde() {
+    let vec = Vector2::<BF128>::new("1.5".parse().unwrap(), "-0.25".parse().unwrap());
+    let json = serde_json::to_string(&vec).unwrap();
+    assert_eq!(json, r#"["1.5","-0.25"]"#);
+    assert_eq!(serde_json::from_str::<Vector2<BF128>>(&json).unwrap(), vec);
+}
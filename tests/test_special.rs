@@ -0,0 +1,819 @@
+use astro_nalgebra::{BigFloat, ConstCtx, RoundingMode, Sign};
+use nalgebra::{ComplexField, RealField};
+use num_traits::{One, Zero};
+
+type BF128 = BigFloat<ConstCtx<128, { RoundingMode::ToEven as u8 }>>;
+type BF256 = BigFloat<ConstCtx<256, { RoundingMode::ToEven as u8 }>>;
+type BF1024 = BigFloat<ConstCtx<1024, { RoundingMode::ToEven as u8 }>>;
+
+const EULER_GAMMA: &str = "0.57721566490153286060651209008240243104215933593992359880576723488486772677766467093694706329174674951";
+const APERY: &str = "1.20205690315959428539973816151144999076498629234049888179227155534183820578631309018645587360933525814";
+
+/// Asserts that `a` and `b` agree to all but the last `slack` bits of the context.
+fn assert_close<CTX: astro_nalgebra::BigFloatCtx + 'static>(
+    a: BigFloat<CTX>,
+    b: BigFloat<CTX>,
+    slack: i32,
+) {
+    let bits = CTX::get_prec() as i32 - slack;
+    let tol = b.clone().abs() * BigFloat::<CTX>::from(2.0).powi(-bits);
+    assert!(
+        (a.clone() - b.clone()).abs() <= tol,
+        "{} is not close to {}",
+        a.to_shortest_string(),
+        b.to_shortest_string()
+    );
+}
+
+fn bf<T: std::str::FromStr>(s: &str) -> T
+where
+    T::Err: std::fmt::Debug,
+{
+    s.parse().unwrap()
+}
+
+#[test]
+fn test_gamma() {
+    let sqrt_pi = BF256::pi().sqrt();
+    assert_close(BF256::from(0.5).gamma(), sqrt_pi.clone(), 4);
+    assert_close(
+        BF1024::from(0.5).gamma() * BF1024::from(0.5).gamma(),
+        BF1024::pi(),
+        4,
+    );
+    // gamma(-5/2) = -8 sqrt(pi) / 15
+    assert_close(
+        BF256::from(-2.5).gamma(),
+        -sqrt_pi.clone() * BF256::from(8.0) / BF256::from(15.0),
+        4,
+    );
+    // gamma(1/3) gamma(2/3) = 2 pi / sqrt(3)
+    let third = BF256::one() / BF256::from(3.0);
+    let expected = BF256::two_pi() / BF256::from(3.0).sqrt();
+    assert_close(
+        third.gamma() * (BF256::one() - third.clone()).gamma(),
+        expected,
+        4,
+    );
+    // Duplication formula gamma(x) gamma(x + 1/2) = 2^(1 - 2x) sqrt(pi) gamma(2x)
+    for x in ["50.25", "0.1", "3.75", "-7.3"] {
+        let x: BF256 = bf(x);
+        let lhs = x.gamma() * (x.clone() + BF256::from(0.5)).gamma();
+        let two_x = x.clone() * BF256::from(2.0);
+        let rhs =
+            BF256::from(2.0).powf(BF256::one() - two_x.clone()) * sqrt_pi.clone() * two_x.gamma();
+        assert_close(lhs, rhs, 8);
+    }
+    // Exact integers, in the default rounding mode that truncates
+    type BF64 = BigFloat<ConstCtx<64>>;
+    assert_eq!(BF64::from(21.0).gamma(), BF64::from(2432902008176640000.0));
+    assert_eq!(BF256::from(1.0).gamma(), BF256::one());
+    let fact_30: BF256 = bf("265252859812191058636308480000000");
+    assert_eq!(BF256::from(31.0).gamma(), fact_30);
+    // gamma(x) = 1 / x - euler_gamma + O(x) near zero
+    let tiny = BF256::from(1e-50);
+    let euler: BF256 = bf(EULER_GAMMA);
+    assert_close(tiny.gamma(), tiny.clone().recip() - euler, 4);
+
+    assert!(BF256::from(-3.0).gamma().as_f64().is_nan());
+    assert_eq!(BF256::zero().gamma().as_f64(), f64::INFINITY);
+    assert_eq!(BF256::from(-0.0).gamma().as_f64(), f64::NEG_INFINITY);
+    assert_eq!(BF256::from(f64::INFINITY).gamma().as_f64(), f64::INFINITY);
+    assert!(BF256::from(f64::NEG_INFINITY).gamma().as_f64().is_nan());
+    assert!(BF256::from(f64::NAN).gamma().as_f64().is_nan());
+    // Overflows in f64, but not in astro_float's exponent range
+    let large = BF128::from(1000.5).gamma();
+    assert!(large.as_f64().is_infinite() && large.is_finite());
+}
+
+#[test]
+fn test_lgamma() {
+    let (ln, sign) = BF256::from(-0.5).lgamma();
+    assert_eq!(sign, Sign::Neg);
+    assert_close(ln, (BF256::pi().sqrt() * BF256::from(2.0)).ln(), 4);
+    let (ln, sign) = BF256::from(-1.5).lgamma();
+    assert_eq!(sign, Sign::Pos);
+    assert_close(
+        ln.exp(),
+        BF256::pi().sqrt() * BF256::from(4.0) / BF256::from(3.0),
+        4,
+    );
+    let (ln, sign) = BF256::from(2.0).lgamma();
+    assert!(ln.is_zero() && sign == Sign::Pos);
+    for x in ["0.75", "17.2", "-3.4", "123456.789"] {
+        let x: BF256 = bf(x);
+        let (ln, sign) = x.lgamma();
+        let gamma = x.gamma();
+        assert_eq!(sign == Sign::Neg, gamma.is_sign_negative());
+        assert_close(ln, gamma.abs().ln(), 8);
+    }
+    // Close to the zeros at 1 and 2, against a run at a higher precision on the same input
+    let tiny = BF256::from(2.0).powi(-230);
+    for zero in [1.0, 2.0] {
+        for x in [
+            BF256::from(zero) + tiny.clone(),
+            BF256::from(zero) - tiny.clone(),
+        ] {
+            let wide: BF1024 = x.cast();
+            let expected: BF256 = wide.lgamma().0.cast();
+            assert_close(x.lgamma().0, expected, 4);
+        }
+    }
+    // Far beyond the range where gamma overflows
+    let (ln, _) = BF256::from(1e300).lgamma();
+    let x = BF256::from(1e300);
+    assert_close(ln, x.clone() * x.clone().ln() - x, 4);
+    assert_eq!(BF256::from(-2.0).lgamma().0.as_f64(), f64::INFINITY);
+    assert_eq!(
+        BF256::zero().lgamma(),
+        (BF256::from(f64::INFINITY), Sign::Pos)
+    );
+}
+
+#[test]
+fn test_digamma() {
+    let euler: BF256 = bf(EULER_GAMMA);
+    assert_close(BF256::one().digamma(), -euler.clone(), 4);
+    // digamma(1/2) = -gamma - 2 ln 2
+    let half = -euler.clone() - BF256::ln_2() * BF256::from(2.0);
+    assert_close(BF256::from(0.5).digamma(), half.clone(), 4);
+    // digamma(-1/2) = digamma(1/2) + 2
+    assert_close(BF256::from(-0.5).digamma(), half + BF256::from(2.0), 8);
+    // digamma(x) = digamma(x + 1) - 1 / x
+    for x in ["1e-20", "0.3", "2.5", "-12.7", "1e6"] {
+        let x: BF256 = bf(x);
+        let next = (x.clone() + BF256::one()).digamma();
+        assert_close(x.digamma(), next - x.clone().recip(), 8);
+    }
+    let euler_1024: BF1024 = bf(EULER_GAMMA);
+    let diff = BF1024::one().digamma() + euler_1024;
+    assert!(diff.abs() < bf("1e-99"));
+    assert!(BF256::from(-4.0).digamma().as_f64().is_nan());
+    assert_eq!(BF256::zero().digamma().as_f64(), f64::NEG_INFINITY);
+}
+
+#[test]
+fn test_polygamma() {
+    let pi2 = BF256::pi() * BF256::pi();
+    assert_close(BF256::one().polygamma(1), pi2.clone() / BF256::from(6.0), 4);
+    assert_close(
+        BF256::from(0.5).polygamma(1),
+        pi2.clone() / BF256::from(2.0),
+        4,
+    );
+    // polygamma(1, -1/2) = polygamma(1, 1/2) + 4
+    assert_close(
+        BF256::from(-0.5).polygamma(1),
+        pi2.clone() / BF256::from(2.0) + BF256::from(4.0),
+        4,
+    );
+    let apery: BF256 = bf(APERY);
+    assert_close(BF256::one().polygamma(2), apery * BF256::from(-2.0), 4);
+    assert_close(
+        BF256::one().polygamma(3),
+        pi2.clone() * pi2.clone() / BF256::from(15.0),
+        4,
+    );
+    // polygamma(n, x) = polygamma(n, x + 1) - (-1)^n n! / x^(n + 1)
+    for n in [1, 2, 5, 12] {
+        let fact = BF256::from(n as f64).factorial();
+        for x in ["0.3", "2.5", "-3.7", "40.1"] {
+            let x: BF256 = bf(x);
+            let term = fact.clone() / x.clone().powi(n + 1);
+            let term = if n % 2 == 0 { term } else { -term };
+            let next = (x.clone() + BF256::one()).polygamma(n as u32);
+            assert_close(x.polygamma(n as u32), next - term, 12);
+        }
+    }
+    assert_eq!(BF256::from(-1.0).polygamma(1).as_f64(), f64::INFINITY);
+    assert!(BF256::from(-1.0).polygamma(2).as_f64().is_nan());
+    assert_eq!(BF256::from(f64::INFINITY).polygamma(3), BF256::zero());
+}
+
+#[test]
+fn test_beta_factorial_binomial() {
+    let half = BF256::from(0.5);
+    assert_close(half.beta(&half), BF256::pi(), 4);
+    assert_eq!(
+        BF256::from(2.0).beta(&BF256::from(3.0)),
+        BF256::one() / BF256::from(12.0)
+    );
+    // beta(x, y) = gamma(x) gamma(y) / gamma(x + y)
+    for (x, y) in [("0.3", "4.5"), ("-2.5", "1.25"), ("100.5", "3.75")] {
+        let (x, y): (BF256, BF256) = (bf(x), bf(y));
+        let expected = x.gamma() * y.gamma() / (x.clone() + y.clone()).gamma();
+        assert_close(x.beta(&y), expected, 8);
+    }
+    // Tiny results that would underflow in f64
+    let tiny = BF256::from(1000.5).beta(&BF256::from(1000.25));
+    assert!(tiny.as_f64() == 0.0 && !tiny.is_zero());
+    assert!(BF256::from(-1.0).beta(&half).as_f64().is_nan());
+    assert!(BF256::from(-0.5).beta(&BF256::from(-0.5)).is_zero());
+
+    assert_eq!(BF256::zero().factorial(), BF256::one());
+    assert_eq!(BF256::from(-0.0).factorial(), BF256::one());
+    let fact_100: BF256 = bf("93326215443944152681699238856266700490715968264381621468592963895217599993229915608941463976156518286253697920827223758251185210916864000000000000000000000000");
+    assert_eq!(BF256::from(100.0).factorial(), fact_100);
+    assert_close(
+        BF256::from(0.5).factorial(),
+        BF256::pi().sqrt() / BF256::from(2.0),
+        4,
+    );
+    assert!(BF256::from(-1.0).factorial().as_f64().is_nan());
+
+    let binomial = |n: f64, k: f64| BF256::from(n).binomial(&BF256::from(k));
+    assert_eq!(binomial(10.0, 3.0), BF256::from(120.0));
+    assert_eq!(binomial(10.0, 11.0), BF256::zero());
+    assert_eq!(binomial(10.0, -1.0), BF256::zero());
+    assert_eq!(binomial(-1.0, 5.0), BF256::from(-1.0));
+    assert_eq!(binomial(-3.0, 2.0), BF256::from(6.0));
+    assert_eq!(binomial(0.5, 2.0), BF256::from(-0.125));
+    let big: BF256 = bf("100891344545564193334812497256");
+    assert_eq!(binomial(100.0, 50.0), big);
+    // binomial(5, 5/2) = 5! / gamma(7/2)^2 = 512 / (15 pi)
+    assert_close(
+        binomial(5.0, 2.5),
+        BF256::from(512.0) / (BF256::from(15.0) * BF256::pi()),
+        8,
+    );
+    assert!(binomial(2.5, 4.5).is_zero());
+    // binomial(0, 1/2) = 1 / (gamma(3/2) gamma(1/2)) = 2 / pi, also for a negative zero
+    assert_close(binomial(0.0, 0.5), BF256::from(2.0) / BF256::pi(), 8);
+    assert_eq!(binomial(-0.0, 0.5), binomial(0.0, 0.5));
+    assert!(binomial(-3.0, 0.5).as_f64().is_nan());
+}
+
+#[test]
+fn test_erf() {
+    let erf_1: BF256 = bf("0.84270079294971486934122063508260925929606699796630290845993789783471725409601084126198332534814488845");
+    assert_close(BF256::one().erf(), erf_1.clone(), 4);
+    assert_close(BF256::one().erfc(), BF256::one() - erf_1, 4);
+    let third = BF1024::one() / BF1024::from(3.0);
+    let erfc_third: BF1024 = bf("0.63735188823393706659182135985213412030785840962746276075998140594254073063928971799335057136222473115");
+    assert!((third.erfc() - erfc_third).abs() < bf("1e-99"));
+    // erf is odd and erf(x) + erfc(x) = 1
+    for x in ["1e-200", "0.01", "0.7", "2.5", "-3.25", "9.5"] {
+        let x: BF256 = bf(x);
+        assert_eq!((-x.clone()).erf(), -x.erf());
+        assert_close(x.erf() + x.erfc(), BF256::one(), 4);
+    }
+    // erf(x) is about 2x / sqrt(pi) for tiny x
+    let tiny = BF256::from(1e-200);
+    assert_close(
+        tiny.erf(),
+        tiny.clone() * BF256::from(2.0) / BF256::pi().sqrt(),
+        4,
+    );
+    assert_eq!(
+        BF256::from(-0.0).erf().as_f64().to_bits(),
+        (-0.0f64).to_bits()
+    );
+    assert_eq!(BF256::from(f64::INFINITY).erf(), BF256::one());
+    assert!(BF256::from(f64::NAN).erf().as_f64().is_nan());
+}
+
+#[test]
+fn test_erfc_tail() {
+    let erfc_10: BF256 = bf("2.0884875837625447570007862949577886115608181193211637270122137139381746958334402906107663842857235539815e-45");
+    assert_close(BF256::from(10.0).erfc(), erfc_10, 4);
+    let erfc_100: BF256 = bf("6.4059614249217320390213391485863941482144143994603380577671076502489025548295058312279458665798714970979e-4346");
+    assert_close(BF256::from(100.0).erfc(), erfc_100, 4);
+    // The asymptotic series at 256 bits agrees with the cancelling series at 1024 bits
+    for x in ["18.5", "20", "25.75"] {
+        let high: BF256 = bf(&bf::<BF1024>(x).erfc().to_string());
+        assert_close(bf::<BF256>(x).erfc(), high, 4);
+    }
+    let erfc_neg_3: BF256 = bf("1.9999779095030014145586272238704176796201522929126007503427610451570575433163798677321837453491846837");
+    assert_close(BF256::from(-3.0).erfc(), erfc_neg_3, 4);
+    // Underflows only below the exponent range
+    assert!(BF128::from(1e6).erfc().is_zero());
+    assert_eq!(BF256::from(f64::INFINITY).erfc(), BF256::zero());
+    assert_eq!(BF256::from(f64::NEG_INFINITY).erfc(), BF256::from(2.0));
+
+    // erfcx(x) = exp(x^2) erfc(x)
+    let erfcx: BF256 = bf("5.6418958354775628694525850364303380440935254628996107563638551666739538005817329905135986664707107451e-11");
+    assert_close(BF256::from(1e10).erfcx(), erfcx, 4);
+    let erfcx_neg_5: BF256 = bf("144009798674.66104041058963430588210374395531457620161061062192090634556203702638019427056973785689114");
+    assert_close(BF256::from(-5.0).erfcx(), erfcx_neg_5, 4);
+    for x in ["0.3", "4.5", "30"] {
+        let x: BF256 = bf(x);
+        let expected = (x.clone() * x.clone()).exp() * x.erfc();
+        assert_close(x.erfcx(), expected, 8);
+    }
+    assert_eq!(BF256::zero().erfcx(), BF256::one());
+    assert_eq!(
+        BF256::from(f64::NEG_INFINITY).erfcx().as_f64(),
+        f64::INFINITY
+    );
+}
+
+#[test]
+fn test_erfc_underflow() {
+    // erfc(x) leaves the exponent range just above x = 38581.3716, where it is 2^EXPONENT_MIN
+    let erfc_below: BF256 = bf("3.0222366943048212165345284024408620931351475790011240518296048344734179680980860524216568031961917229e-646456981");
+    assert_close(bf::<BF256>("38581.37109375").erfc(), erfc_below, 4);
+    // Beyond it the result is a positive zero
+    for x in ["38581.375", "1e10"] {
+        let erfc = bf::<BF256>(x).erfc();
+        assert!(erfc.is_zero() && erfc.is_sign_positive(), "{}", x);
+    }
+    let cdf = BF256::from(-1e10).cdf();
+    assert!(cdf.is_zero() && cdf.is_sign_positive());
+}
+
+#[test]
+fn test_erf_inv() {
+    for y in ["1e-300", "0.001", "0.3", "0.5", "0.75", "0.999", "-0.9"] {
+        let y: BF256 = bf(y);
+        assert_close(y.erf_inv().erf(), y.clone(), 8);
+    }
+    for y in ["1e-10", "0.2", "0.5", "0.9", "1.5", "1.99999"] {
+        let y: BF256 = bf(y);
+        assert_close(y.erfc_inv().erfc(), y.clone(), 8);
+    }
+    // Deep in the tail, below the range of f64
+    let expected: BF256 = bf("47.938946404264510689915788168577822684327013804057085048925431403263288912100174422866224614319521307");
+    assert_close(bf::<BF256>("1e-1000").erfc_inv(), expected, 4);
+    let expected: BF256 = bf("479.84556210095724064336759204825962037985203417002257036826764944151781065753163120417335214688399267");
+    assert_close(bf::<BF256>("1e-100000").erfc_inv(), expected, 4);
+    // 1 - 1e-50 is exact enough at 1024 bits to resolve the tail
+    let close_to_one = BF1024::one() - bf::<BF1024>("1e-50");
+    let expected: BF1024 = bf("10.592090169527365189021663925329799115594206455417099125884064401196710442891340795691275833203514286356");
+    assert!((close_to_one.erf_inv() - expected).abs() < bf("1e-98"));
+
+    assert_eq!(BF256::zero().erf_inv(), BF256::zero());
+    assert_eq!(BF256::one().erf_inv().as_f64(), f64::INFINITY);
+    assert!(BF256::from(1.5).erf_inv().as_f64().is_nan());
+    assert_eq!(BF256::one().erfc_inv(), BF256::zero());
+    assert_eq!(BF256::zero().erfc_inv().as_f64(), f64::INFINITY);
+    assert_eq!(BF256::from(2.0).erfc_inv().as_f64(), f64::NEG_INFINITY);
+    assert!(BF256::from(-0.5).erfc_inv().as_f64().is_nan());
+    assert!(BF256::from(2.5).erfc_inv().as_f64().is_nan());
+}
+
+#[test]
+fn test_normal() {
+    assert_eq!(BF256::zero().cdf(), BF256::from(0.5));
+    let cdf_40: BF256 = bf("3.6558935409150297037489858026882836650539446199773726249877572956765948328544401104036208733081655831e-350");
+    assert_close(BF256::from(-40.0).cdf(), cdf_40, 4);
+    assert_close(
+        BF256::from(1.5).cdf() + BF256::from(-1.5).cdf(),
+        BF256::one(),
+        4,
+    );
+    let quantile: BF256 = bf("1.9599639845400542355245944305205515279555500778695483984769526463616352741448826677982547094928142060");
+    assert_close(bf::<BF256>("0.975").quantile(), quantile.clone(), 4);
+    assert_close(bf::<BF256>("0.025").quantile(), -quantile, 4);
+    let quantile: BF256 = bf("-37.047096299361199237222962507860436844345288438011942928838306831084081960693975411653447780900130022");
+    assert_close(bf::<BF256>("1e-300").quantile(), quantile, 4);
+    for p in ["1e-20", "0.1", "0.6", "0.999"] {
+        let p: BF256 = bf(p);
+        assert_close(p.quantile().cdf(), p.clone(), 8);
+    }
+    let half = BF256::from(0.5).quantile();
+    assert!(half.is_zero() && half.is_sign_positive());
+    assert_eq!(BF256::zero().quantile().as_f64(), f64::NEG_INFINITY);
+    assert_eq!(BF256::one().quantile().as_f64(), f64::INFINITY);
+    assert!(BF256::from(1.1).quantile().as_f64().is_nan());
+    assert_eq!(BF256::from(f64::NEG_INFINITY).cdf(), BF256::zero());
+}
+
+#[test]
+fn test_bessel_j_y() {
+    let order = |nu: f64| BF256::from(nu);
+    let j0_1: BF256 = bf("0.76519768655796655144971752610266322090927428975532524186154754911927891221527244016718060009891563397");
+    assert_close(BF256::one().bessel_j(&order(0.0)), j0_1, 4);
+    let expected: BF256 = bf("0.14120285879928212035620944184483666017513505482005306176324233447338498841886500398197560259705636055");
+    assert_close(BF256::from(30.0).bessel_j(&order(2.5)), expected, 8);
+    // J_-n(-x) = J_n(x)
+    let expected: BF256 = bf("-0.21660039103911352476668900351596372171684342357695992677721471324122709828216197100608103326796437527");
+    assert_close(BF256::from(2.5).bessel_j(&order(-3.0)), expected.clone(), 4);
+    assert_close(BF256::from(-2.5).bessel_j(&order(3.0)), expected, 4);
+    // Asymptotic expansion
+    let expected: BF256 = bf("-0.00072596835681376304185252338170849845577591612529459895370157474474904725674498443491912928762453586776");
+    assert_close(BF256::from(1e6).bessel_j(&order(1.0)), expected, 24);
+    // J_1/2(x) = sqrt(2 / (pi x)) sin(x), for tiny x
+    let tiny = BF256::from(1e-30);
+    let expected = (BF256::from(2.0) / (BF256::pi() * tiny.clone())).sqrt() * tiny.clone().sin();
+    assert_close(tiny.bessel_j(&order(0.5)), expected, 4);
+
+    let y0_1: BF256 = bf("0.088256964215676957982926766023515162827817523090675546711043847611999789323513371301077200359219936802");
+    assert_close(BF256::one().bessel_y(&order(0.0)), y0_1, 8);
+    let expected: BF256 = bf("-5093021.8417137366728474504426627472081607438010169108532386857402993938183958763333998066467309127851");
+    assert_close(BF256::from(0.01).bessel_y(&order(3.0)), expected, 4);
+    let expected: BF256 = bf("-0.13766989672295984566034621379954528467788935534485590636189542976588871320845120983945035027388923014");
+    assert_close(BF256::from(7.0).bessel_y(&order(0.25)), expected, 8);
+    // Close to an integer order, where the reflection formula cancels
+    let near_three = BF256::from(3.0) + BF256::from(2.0).powi(-30);
+    let expected: BF256 = bf("-1.1277837775657685492156189073206171525222299359661074412232796092802662412068152160809032570062390569");
+    assert_close(BF256::from(2.0).bessel_y(&near_three), expected, 4);
+    // Wronskian J_(nu + 1) Y_nu - J_nu Y_(nu + 1) = 2 / (pi x)
+    for (x, nu) in [(0.75, 0.0), (12.5, 2.0), (300.0, 1.5), (40.0, -0.3)] {
+        let (x, nu) = (BF1024::from(x), BF1024::from(nu));
+        let nu_1 = nu.clone() + BF1024::one();
+        let wronskian = x.bessel_j(&nu_1) * x.bessel_y(&nu) - x.bessel_j(&nu) * x.bessel_y(&nu_1);
+        assert_close(wronskian, BF1024::from(2.0) / (BF1024::pi() * x), 16);
+    }
+
+    assert_eq!(BF256::zero().bessel_j(&order(0.0)), BF256::one());
+    assert!(BF256::zero().bessel_j(&order(2.0)).is_zero());
+    assert_eq!(
+        BF256::zero().bessel_j(&order(-1.5)).as_f64(),
+        f64::NEG_INFINITY
+    );
+    assert_eq!(
+        BF256::zero().bessel_y(&order(1.0)).as_f64(),
+        f64::NEG_INFINITY
+    );
+    assert!(BF256::zero().bessel_y(&order(-0.5)).is_zero());
+    assert!(BF256::from(f64::INFINITY).bessel_j(&order(1.0)).is_zero());
+    assert!(BF256::from(-1.0).bessel_j(&order(0.5)).as_f64().is_nan());
+    assert!(BF256::from(-1.0).bessel_y(&order(1.0)).as_f64().is_nan());
+    assert!(BF256::one().bessel_j(&order(f64::NAN)).as_f64().is_nan());
+}
+
+#[test]
+fn test_bessel_i_k() {
+    let order = |nu: f64| BF256::from(nu);
+    let expected: BF256 = bf("1.5906368546373290633822544249996662479544781594955366471322879846085450375353611851161221475942289252");
+    assert_close(BF256::from(2.0).bessel_i(&order(1.0)), expected.clone(), 4);
+    assert_close(BF256::from(-2.0).bessel_i(&order(-1.0)), -expected, 4);
+    let expected: BF256 = bf("4539800013178388791190949248718982123659906897207568047149057727.5941235336524133387693131729471071847");
+    assert_close(BF256::from(150.0).bessel_i(&order(0.5)), expected, 8);
+    let expected: BF256 = bf("2.1117761936354068458717783166438303118942513858960003397699518901557906260748622797944739566546241198");
+    assert_close(BF256::one().bessel_i(&order(-2.5)), expected, 4);
+
+    let expected: BF256 = bf("0.42102443824070833333562737921260903613621974822666047229896955145521267813810183909212513954736530451");
+    assert_close(BF256::one().bessel_k(&order(0.0)), expected, 8);
+    // K is far smaller than the terms of its series
+    let expected: BF256 = bf("4.750225303888640204670256174324373061967038356380980944614206585367754560167366804889805266935770066e-45");
+    assert_close(
+        BF256::from(100.0).bessel_k(&order(2.0)),
+        expected.clone(),
+        8,
+    );
+    assert_close(BF256::from(100.0).bessel_k(&order(-2.0)), expected, 8);
+    let expected: BF256 = bf("3.994565679229219859221428612993929076710210858963816262859974420005145113170654679278573493881851e-219");
+    assert_close(BF256::from(500.0).bessel_k(&order(0.75)), expected, 8);
+    // Wronskian I_nu K_(nu + 1) + I_(nu + 1) K_nu = 1 / x
+    for (x, nu) in [(0.75, 0.0), (12.5, 2.0), (60.0, 1.5), (40.0, 0.3)] {
+        let (x, nu) = (BF1024::from(x), BF1024::from(nu));
+        let nu_1 = nu.clone() + BF1024::one();
+        let wronskian = x.bessel_i(&nu) * x.bessel_k(&nu_1) + x.bessel_i(&nu_1) * x.bessel_k(&nu);
+        assert_close(wronskian, BF1024::one() / x, 16);
+    }
+
+    assert_eq!(BF256::zero().bessel_i(&order(0.0)), BF256::one());
+    assert_eq!(BF256::zero().bessel_k(&order(1.0)).as_f64(), f64::INFINITY);
+    assert_eq!(
+        BF256::from(f64::NEG_INFINITY)
+            .bessel_i(&order(3.0))
+            .as_f64(),
+        f64::NEG_INFINITY
+    );
+    assert!(BF256::from(f64::INFINITY).bessel_k(&order(3.0)).is_zero());
+    // K_nu(x) underflows to a positive zero
+    let k = BF256::from(1e20).bessel_k(&order(0.0));
+    assert!(k.is_zero() && k.is_sign_positive());
+    assert!(BF256::from(-1.0).bessel_k(&order(0.0)).as_f64().is_nan());
+}
+
+#[test]
+fn test_bessel_moderate_order() {
+    // Arguments between the order and its square step through the orders by recurrence instead
+    // of summing the power series, which takes minutes there
+    let start = std::time::Instant::now();
+    let x = BF256::from(20000.0);
+    let expected: BF256 =
+        bf("-0.0027790293374498781496539280062709855128322430085918447413049923495086223601740155");
+    assert_close(x.bessel_j(&BF256::from(300.0)), expected, 8);
+    let expected: BF256 = bf(
+        "1.9390347757564697848284288756062841124043268418416010761403021500186998351324928e-2831",
+    );
+    assert_close(x.bessel_j(&BF256::from(30000.0)), expected, 8);
+    let expected: BF256 = bf(
+        "1.0839868159416375499162853693156100606370872027800253093553015753023197480796403e-8687",
+    );
+    assert_close(x.bessel_k(&BF256::from(300.0)), expected, 8);
+    for nu in [300.5, -300.5, 5000.0] {
+        let nu = BF256::from(nu);
+        let nu_1 = nu.clone() + BF256::one();
+        let wronskian = x.bessel_j(&nu_1) * x.bessel_y(&nu) - x.bessel_j(&nu) * x.bessel_y(&nu_1);
+        assert_close(wronskian, BF256::from(2.0) / (BF256::pi() * x.clone()), 16);
+        let wronskian = x.bessel_i(&nu) * x.bessel_k(&nu_1) + x.bessel_i(&nu_1) * x.bessel_k(&nu);
+        assert_close(wronskian, BF256::one() / x.clone(), 16);
+    }
+    assert!(start.elapsed().as_secs() < 20);
+}
+
+#[test]
+fn test_bessel_module() {
+    use astro_nalgebra::special::bessel;
+    for (nu, x) in [(0.0, 1.0), (2.5, 0.75), (-3.0, 40.0), (0.5, 200.0)] {
+        let (nu, x) = (BF256::from(nu), BF256::from(x));
+        assert_eq!(bessel::j(&nu, &x), x.bessel_j(&nu));
+        assert_eq!(bessel::y(&nu, &x), x.bessel_y(&nu));
+        assert_eq!(bessel::i(&nu, &x), x.bessel_i(&nu));
+        assert_eq!(bessel::k(&nu, &x), x.bessel_k(&nu));
+        assert_eq!(bessel::airy_ai(&-x.clone()), (-x.clone()).airy_ai());
+        assert_eq!(bessel::airy_bi(&x), x.airy_bi());
+    }
+}
+
+#[test]
+fn test_airy() {
+    let ai_0: BF256 = bf("0.35502805388781723926006318600418317639797917419917724058332651030081004245012671295717424605404027169");
+    assert_close(BF256::zero().airy_ai(), ai_0, 4);
+    let expected: BF256 = bf("0.00010834442813607441734986502503345980479577783479688939133512942516197717092239060602078678571281610542");
+    assert_close(BF256::from(5.0).airy_ai(), expected, 8);
+    let expected: BF256 = bf("9.1536243084526844165812861520204702230141004593025512849399437388240405385181510865763415186380351768e-821");
+    assert_close(BF256::from(200.0).airy_ai(), expected, 8);
+    let expected: BF256 = bf("0.055971895773019918842191826756134474703779789057122464434147248380475867037669079472007242094687381458");
+    assert_close(BF256::from(-1000.0).airy_ai(), expected, 24);
+    let expected: BF256 = bf("-0.19828962637492654322064485457248835669880749583246280971109677601405966613201070907023174283486238836");
+    assert_close(BF256::from(-3.0).airy_bi(), expected, 8);
+    let expected: BF256 = bf("21037650496511038144947890.143998843924295089896415597641964826738199674213376167896819113038264123506");
+    assert_close(BF256::from(20.0).airy_bi(), expected, 8);
+    // Wronskian Ai(x) Bi'(x) - Ai'(x) Bi(x) = 1 / pi, with central differences for the
+    // derivatives
+    for x in [0.5, 3.0, -2.0, -30.0] {
+        let x = BF1024::from(x);
+        let h = BF1024::from(1e-100);
+        let ai_d = ((x.clone() + h.clone()).airy_ai() - (x.clone() - h.clone()).airy_ai())
+            / (BF1024::from(2.0) * h.clone());
+        let bi_d = ((x.clone() + h.clone()).airy_bi() - (x.clone() - h.clone()).airy_bi())
+            / (BF1024::from(2.0) * h.clone());
+        let wronskian = x.airy_ai() * bi_d - ai_d * x.airy_bi();
+        assert!((wronskian * BF1024::pi() - BF1024::one()).abs() < bf("1e-180"));
+    }
+
+    assert!(BF256::from(f64::INFINITY).airy_ai().is_zero());
+    let ai = BF256::from(1e15).airy_ai();
+    assert!(ai.is_zero() && ai.is_sign_positive());
+    assert!(BF256::from(f64::NEG_INFINITY).airy_bi().is_zero());
+    assert_eq!(BF256::from(f64::INFINITY).airy_bi().as_f64(), f64::INFINITY);
+    assert!(BF256::from(f64::NAN).airy_ai().as_f64().is_nan());
+}
+
+#[test]
+fn test_zeta() {
+    assert_close(BF256::from(3.0).zeta(), bf(APERY), 4);
+    // zeta(2k) = (-1)^(k + 1) B_2k (2 pi)^2k / (2 (2k)!)
+    let pi2 = BF1024::pi() * BF1024::pi();
+    assert_close(
+        BF1024::from(4.0).zeta(),
+        pi2.clone() * pi2 / BF1024::from(90.0),
+        8,
+    );
+    let expected: BF256 = bf("-1.4603545088095868128894991525152980124672293310125814905428860878255305294745006252764193754633568195");
+    assert_close(BF256::from(0.5).zeta(), expected, 8);
+    // Functional equation
+    let expected: BF256 = bf("0.0040383564392060737751949945928570066703952794379812273547993677283682965570647400330250026382856924102");
+    assert_close(BF256::from(-7.25).zeta(), expected, 8);
+    let expected: BF256 = bf("-7261200880360671630367728151070684723223503116479284967952558857720444396482459.3628833410386808445061");
+    assert_close(BF256::from(-101.0).zeta(), expected, 8);
+    // Close to the pole, zeta(1 + h) = 1 / h + gamma + O(h)
+    let expected: BF256 = bf("1048576.5772157343441301191277510751624133516154559268649520475446811418038219388139928229877122885885");
+    assert_close(
+        (BF256::one() + BF256::from(2.0).powi(-20)).zeta(),
+        expected,
+        8,
+    );
+    assert_eq!(BF256::from(300.0).zeta(), BF256::one());
+
+    assert_eq!(BF256::zero().zeta(), BF256::from(-0.5));
+    assert!(BF256::from(-2.0).zeta().is_zero());
+    assert!(BF256::one().zeta().as_f64().is_nan());
+    assert_eq!(BF256::from(f64::INFINITY).zeta(), BF256::one());
+    assert!(BF256::from(f64::NEG_INFINITY).zeta().as_f64().is_nan());
+}
+
+#[test]
+fn test_hurwitz_zeta_eta() {
+    let expected: BF256 = bf("3163.1034212176388441363702884631077979811971238146979737517401689499282776231604092239360656172968233");
+    assert_close(
+        BF256::from(3.5).hurwitz_zeta(&BF256::from(0.1)),
+        expected,
+        8,
+    );
+    let expected: BF256 = bf("-9.3799662035037145437911517813521878769285140791740423095895751126853729367446795786323564836598502442");
+    assert_close(
+        BF256::from(-2.5).hurwitz_zeta(&BF256::from(3.25)),
+        expected,
+        8,
+    );
+    let expected: BF256 = bf("-108.21740207044628216577478309087419722588945637944969904474841001681254301525358244205020168269014599");
+    assert_close(
+        BF256::from(-20.5).hurwitz_zeta(&BF256::from(0.75)),
+        expected,
+        8,
+    );
+    let expected: BF256 = bf("0.00100050016666663333335714282380959956684645471311312101832102394189276518956371934719126249616399122");
+    assert_close(
+        BF256::from(2.0).hurwitz_zeta(&BF256::from(1000.0)),
+        expected,
+        8,
+    );
+    // zeta(s, 1) = zeta(s) and zeta(-n, a) = -B_(n + 1)(a) / (n + 1)
+    assert_close(BF256::from(3.0).hurwitz_zeta(&BF256::one()), bf(APERY), 4);
+    let a = BF256::from(0.3);
+    let bernoulli_2 = a.clone() * a.clone() - a.clone() + BF256::from(1.0) / BF256::from(6.0);
+    assert_close(
+        BF256::from(-1.0).hurwitz_zeta(&a),
+        -bernoulli_2 / BF256::from(2.0),
+        8,
+    );
+    // Far below zero, where the Euler-Maclaurin tail would need thousands of Bernoulli numbers,
+    // zeta(s, 1) = zeta(s) and zeta(s, 1/2) = (2^s - 1) zeta(s)
+    for s in [-3000.5, -1000.5, -300.25] {
+        let s = BF256::from(s);
+        let zeta = s.clone().zeta();
+        assert_close(s.clone().hurwitz_zeta(&BF256::one()), zeta.clone(), 8);
+        let factor = BF256::from(2.0).powf(s.clone()) - BF256::one();
+        assert_close(s.hurwitz_zeta(&BF256::from(0.5)), factor * zeta, 16);
+    }
+    // The first terms of the sum are taken off for a > 1, zeta(s, 3) = zeta(s) - 1 - 2^-s
+    let s = BF256::from(-500.5);
+    let expected = s.clone().zeta() - BF256::one() - BF256::from(2.0).powf(-s.clone());
+    assert_close(s.hurwitz_zeta(&BF256::from(3.0)), expected, 8);
+    assert!(BF256::from(2.0)
+        .hurwitz_zeta(&BF256::zero())
+        .as_f64()
+        .is_nan());
+    assert!(BF256::from(2.0)
+        .hurwitz_zeta(&BF256::from(-0.5))
+        .as_f64()
+        .is_nan());
+    assert!(BF256::one()
+        .hurwitz_zeta(&BF256::from(0.5))
+        .as_f64()
+        .is_nan());
+    assert_eq!(
+        BF256::from(f64::INFINITY)
+            .hurwitz_zeta(&BF256::from(0.5))
+            .as_f64(),
+        f64::INFINITY
+    );
+
+    let expected: BF256 = bf("0.60489864342163037024726591423595549975976254513024738037854664808218725349506035732740395691834955438");
+    assert_close(BF256::from(0.5).eta(), expected, 8);
+    assert_close(BF256::from(-3.0).eta(), BF256::from(-0.125), 8);
+    // Close to one, where 1 - 2^(1 - s) cancels
+    let expected: BF256 = bf("0.69314718070883482842323619991408637121430983120427308405020114770013900020071697140277786491015976501");
+    assert_close(
+        (BF256::one() + BF256::from(2.0).powi(-30)).eta(),
+        expected,
+        8,
+    );
+    assert_close(BF256::one().eta(), BF256::from(2.0).ln(), 4);
+    assert!(BF256::from(-4.0).eta().is_zero());
+}
+
+#[test]
+fn test_polylog() {
+    let expected: BF256 = bf("0.25846139579657330528800012987367261202162535352798804747584081415085729951564012178712996582491501704");
+    assert_close(BF256::from(0.25).polylog(3), expected, 8);
+    // Inversion formula
+    let expected: BF256 = bf("-266.73983959066812696455823459634679865798034899673617399449442985224547082818501961073965412628419646");
+    assert_close(BF256::from(-1e10).polylog(2), expected, 8);
+    // Series in ln(x) close to one
+    let expected: BF256 = bf("1.0369277551423855590846995130379698012639702229475319786568914157554411159791274370438526963004025986");
+    assert_close(
+        (BF256::one() - BF256::from(2.0).powi(-40)).polylog(5),
+        expected,
+        8,
+    );
+    // Duplication formula
+    let expected: BF256 = bf("-0.71909733866082268084699543785789937537727087135408519879636127986242936946965213807148215939735087353");
+    assert_close(BF256::from(-0.75).polylog(4), expected, 8);
+    let expected: BF256 = bf("-0.90154267736969571404980362113358749307373971925537416134420366650637865433973481763984190520700144361");
+    assert_close(BF256::from(-1.0).polylog(3), expected, 8);
+    // Li_2(1) = pi^2 / 6 and Li_1(x) = -ln(1 - x)
+    let pi = BF256::pi();
+    assert_close(
+        BF256::one().polylog(2),
+        pi.clone() * pi / BF256::from(6.0),
+        4,
+    );
+    assert_close(BF256::from(0.75).polylog(1), -BF256::from(0.25).ln(), 8);
+    // Li_-3(x) = x (1 + 4x + x^2) / (1 - x)^4
+    assert_close(
+        BF256::from(-0.5).polylog(-3),
+        BF256::from(2.0) / BF256::from(27.0),
+        4,
+    );
+    assert_eq!(BF256::from(2.0).polylog(0), BF256::from(-2.0));
+    // Large orders, which at 1024 bits still come from the Eulerian numbers
+    for x in [0.5, -0.5, -0.999, 3.0, -7.0, 1e-60] {
+        let expected: BF256 = BF1024::from(x).polylog(-150).cast();
+        assert_close(BF256::from(x).polylog(-150), expected, 8);
+    }
+    // Li_-m(-1) = (2^(m + 1) - 1) zeta(-m)
+    let m = BF256::from(20001.0);
+    let factor = BF256::from(2.0).powf(m.clone() + BF256::one()) - BF256::one();
+    assert_close(BF256::from(-1.0).polylog(-20001), factor * (-m).zeta(), 16);
+    assert_eq!(BF256::from(0.5).polylog(i32::MIN).as_f64(), f64::INFINITY);
+
+    assert!(BF256::from(1.5).polylog(2).as_f64().is_nan());
+    assert_eq!(BF256::one().polylog(1).as_f64(), f64::INFINITY);
+    assert_eq!(BF256::one().polylog(-1).as_f64(), f64::INFINITY);
+    assert!(BF256::one().polylog(0).as_f64().is_nan());
+    assert_eq!(
+        BF256::from(f64::NEG_INFINITY).polylog(3).as_f64(),
+        f64::NEG_INFINITY
+    );
+    assert!(BF256::from(-0.0).polylog(2).is_zero());
+}
+
+#[test]
+fn test_bernoulli_cache() {
+    // The Bernoulli numbers cached by each context are extended and refined as functions need
+    // more of them or a higher working precision, which must not change the results
+    type BF256Up = BigFloat<ConstCtx<256, { RoundingMode::Up as u8 }>>;
+    let lgamma = BF256::from(0.3).lgamma().0;
+    let zeta = BF256::from(3.0).zeta();
+    let hurwitz = BF256::from(-20.5).hurwitz_zeta(&BF256::from(0.75));
+    assert_eq!(BF256::from(0.3).lgamma().0, lgamma);
+    assert_eq!(BF256::from(3.0).zeta(), zeta);
+    assert_eq!(BF256::from(-20.5).hurwitz_zeta(&BF256::from(0.75)), hurwitz);
+    // Contexts sharing a precision but not a rounding mode keep separate caches
+    let up: BF256 = BF256Up::from(3.0).zeta().cast();
+    assert!(up >= zeta);
+    assert_close(up, zeta.clone(), 4);
+    let wide: BF256 = BF1024::from(3.0).zeta().cast();
+    assert_close(wide, zeta.clone(), 2);
+    assert_eq!(BF256::from(3.0).zeta(), zeta);
+}
+
+#[test]
+fn test_lambert_w() {
+    let omega: BF256 = bf("0.56714329040978387299996866221035554975381578718651250813513107922304579308668456669321944696");
+    assert_close(BF256::one().lambert_w0(), omega, 2);
+    let expected: BF256 = bf("224.84310644511850155520560978898649045827206834428476088975969082265118673803277040898159043");
+    assert_close(BF256::from(1e100).lambert_w0(), expected, 2);
+    let expected: BF256 = bf("-0.48940222718021493356502150257712126431602586599308057756124148629775377556869381593878816061");
+    assert_close(BF256::from(-0.3).lambert_w0(), expected, 2);
+    let tiny = BF256::from(2.0).powi(-200);
+    // W0(x) = x - x^2 + O(x^3)
+    assert_close(
+        tiny.clone().lambert_w0(),
+        tiny.clone() - tiny.clone() * tiny,
+        2,
+    );
+    let expected: BF256 = bf("-1.7813370234216276963458442513138613956359730948218345472810192144469927726894782643856382341");
+    assert_close(BF256::from(-0.3).lambert_wm1(), expected, 2);
+    let expected: BF256 = bf("-697.32277629546016097031244781761074375251961085527581838497001064759730848581578358460956003");
+    assert_close(BF256::from(-1e-300).lambert_wm1(), expected, 2);
+    // w exp(w) = x on both branches
+    let x = BF1024::from(10.0);
+    let w = x.lambert_w0();
+    assert_close(w.clone() * w.exp(), x, 4);
+    let x = BF1024::from(-0.1);
+    let w = x.lambert_wm1();
+    assert!(w < -BF1024::one());
+    assert_close(w.clone() * w.exp(), x, 4);
+
+    assert!(BF256::zero().lambert_w0().is_zero());
+    assert_eq!(
+        BF256::from(f64::INFINITY).lambert_w0().as_f64(),
+        f64::INFINITY
+    );
+    assert!(BF256::from(f64::NEG_INFINITY)
+        .lambert_w0()
+        .as_f64()
+        .is_nan());
+    assert_eq!(BF256::zero().lambert_wm1().as_f64(), f64::NEG_INFINITY);
+    assert!(BF256::from(0.5).lambert_wm1().as_f64().is_nan());
+    assert!(BF256::from(f64::NEG_INFINITY)
+        .lambert_wm1()
+        .as_f64()
+        .is_nan());
+}
+
+#[test]
+fn test_lambert_w_branch_point() {
+    // The closest f64 to -1/e is below it, and the next one is above it
+    let below = BF256::from(-0.36787944117144233);
+    assert!(below.lambert_w0().as_f64().is_nan());
+    assert!(below.lambert_wm1().as_f64().is_nan());
+    let above = BF256::from(-0.3678794411714423);
+    let expected: BF256 = bf("-0.99999998469574587150482869393934215071774977089378396421480402577080659786436544064317156296");
+    assert_close(above.clone().lambert_w0(), expected, 2);
+    let expected: BF256 = bf("-1.0000000153042542846419691855751738032692391020426383199661353526688124099398884676732256701");
+    assert_close(above.lambert_wm1(), expected, 2);
+
+    // -1/e rounded to the context, then moved into the domain by far less than its precision
+    // would resolve without accounting for the cancellation in 1 + e x
+    let inv_e = -BF256::one().exp().recip();
+    let x = inv_e + BF256::from(2.0).powi(-200);
+    let expected: BF256 = bf("-0.99999999999999999999999999999816065721802416840712467108188486036130573862981315614690229019");
+    assert_close(x.clone().lambert_w0(), expected, 2);
+    let expected: BF256 = bf("-1.0000000000000000000000000000018393427819758315928753289181173950932739990978892479743341958");
+    assert_close(x.lambert_wm1(), expected, 2);
+    let inv_e = -BF1024::one().exp().recip();
+    let x = inv_e + BF1024::from(2.0).powi(-1000);
+    let expected: BF1024 = bf("-0.99999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999928769760364146934295029666033989027634603913937400783157688583164222950356668784545756345591096868785318927577045198264742276639251585940880830727836440249817394463137649948");
+    assert_close(x.clone().lambert_w0(), expected, 4);
+    let expected: BF1024 = bf("-1.0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000007123023963585306570497033396601097236539608606259921684231141683577704964333121545424365440890313121468107242295480173525772336074841405911916927216359357516286274388289942");
+    assert_close(x.lambert_wm1(), expected, 4);
+}